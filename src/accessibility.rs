@@ -0,0 +1,62 @@
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use objc2_foundation::NSString;
+use objc2_ui_kit::UIAccessibilityIdentification as _;
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Accessibility identifiers of a window's native views, used by UI testing frameworks such as
+/// XCUITest to locate them.
+///
+/// The identifiers are not shown to the user, nor read by VoiceOver. Add this to the entity of
+/// the [`Window`](bevy_window::Window).
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AccessibilityIdentifiers {
+    /// The identifier of the `UIWindow`.
+    pub window: Option<String>,
+    /// The identifier of Bevy's view inside the window.
+    pub view: Option<String>,
+}
+
+/// Propagate changes to [`AccessibilityIdentifiers`] to the native views.
+pub fn update_accessibility_identifiers(
+    identifiers: Query<(Entity, Ref<AccessibilityIdentifiers>)>,
+    mut removed: RemovedComponents<AccessibilityIdentifiers>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, identifiers) in &identifiers {
+        // Also update when the window was only just registered with UIKit.
+        if !identifiers.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(uikit_window) = uikit_windows.get(entity, mtm) {
+            trace!(?entity, ?identifiers, "setting accessibility identifiers");
+            let window = identifiers.window.as_deref().map(NSString::from_str);
+            uikit_window
+                .uiwindow
+                .setAccessibilityIdentifier(window.as_deref());
+            if let Some(view) = uikit_window.view() {
+                let view_identifier = identifiers.view.as_deref().map(NSString::from_str);
+                view.setAccessibilityIdentifier(view_identifier.as_deref());
+            }
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(uikit_window) = uikit_windows.get(entity, mtm) {
+            uikit_window.uiwindow.setAccessibilityIdentifier(None);
+            if let Some(view) = uikit_window.view() {
+                view.setAccessibilityIdentifier(None);
+            }
+        }
+    }
+}
@@ -1,31 +1,52 @@
 #![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
 use std::cell::{Cell, RefCell, RefMut};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::time::SystemTime;
 
 use bevy_app::{App, AppExit, PluginsState};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::message::{Message, MessageReader};
 use bevy_ecs::query::{QuerySingleError, With};
+use bevy_ecs::world::World;
 use bevy_tasks::tick_global_task_pools_on_main_thread;
-use bevy_window::{PrimaryWindow, Window, WindowCreated, WindowEvent};
+use bevy_window::{
+    PrimaryWindow, Window, WindowActivate, WindowBackground, WindowCreated, WindowDeactivate,
+    WindowEvent, WindowForeground,
+};
 use dispatch2::MainThreadBound;
 use objc2::rc::{Allocated, Retained};
 use objc2::runtime::AnyObject;
-use objc2::{available, define_class, msg_send, ClassType, MainThreadMarker, MainThreadOnly};
-use objc2_core_foundation::{kCFRunLoopDefaultMode, CFRunLoop};
+use objc2::{
+    available, define_class, msg_send, ClassType, DefinedClass as _, MainThreadMarker,
+    MainThreadOnly, Message as _,
+};
+use objc2_core_foundation::{
+    kCFRunLoopCommonModes, kCFRunLoopDefaultMode, CFRunLoop, CFRunLoopActivity, CFRunLoopObserver,
+};
 use objc2_foundation::{
-    ns_string, NSDictionary, NSObject, NSObjectProtocol, NSSet, NSString, NSURL,
+    ns_string, NSDictionary, NSNumber, NSObject, NSObjectProtocol, NSSet, NSString, NSURL,
 };
-#[allow(deprecated)]
-use objc2_ui_kit::UIApplicationOpenURLOptionsKey;
 use objc2_ui_kit::{
     UIApplication, UIApplicationDelegate, UIApplicationLaunchOptionsKey, UISceneConfiguration,
     UISceneConnectionOptions, UISceneSession, UIWindow,
 };
-use tracing::{error, trace, warn};
+#[allow(deprecated)]
+use objc2_ui_kit::{UIApplicationOpenURLOptionsKey, UIApplicationOpenURLOptionsOpenInPlaceKey};
+use tracing::{error, info, trace, warn};
 
+use crate::deep_link::{open_deep_link, DeepLink};
+use crate::launch::{record_first_frame, LaunchTimings};
+use crate::launch_arguments::trace_callbacks;
+use crate::open_file::open_file;
 use crate::scene_delegate::SceneDelegate;
-use crate::windows::{setup_window, WorldHelper};
-use crate::UIKitWindows;
+use crate::scene_session::SceneSessionsDiscarded;
+use crate::signpost::signpost_interval;
+use crate::view::become_first_responder;
+use crate::window_schedule::WindowSchedule;
+use crate::windows::{flush_window_resizes, setup_window, WorldHelper};
+use crate::{RenderPaused, UIKitSettings, UIKitWindows};
 
 /// The [`App::runner`] for the [`UIKitPlugin`](crate::UIKitPlugin) plugin.
 pub fn uikit_runner(mut app: App) -> AppExit {
@@ -34,7 +55,7 @@ pub fn uikit_runner(mut app: App) -> AppExit {
     trace!("polling plugins until they're ready");
     // TODO: Is this sufficient for making plugins ready?
     while app.plugins_state() == PluginsState::Adding {
-        tick_global_task_pools_on_main_thread(); // TODO: Poll this elsewhere too?
+        tick_global_task_pools_on_main_thread();
     }
     if app.plugins_state() == PluginsState::Ready {
         app.finish();
@@ -42,12 +63,37 @@ pub fn uikit_runner(mut app: App) -> AppExit {
     }
     assert_eq!(app.plugins_state(), PluginsState::Cleaned);
 
+    // `bevy_tasks`' main thread executors are otherwise only ticked above, so keep them making
+    // progress by ticking them whenever the main run loop is about to sleep. Tasks woken from
+    // other threads don't wake the run loop though, so they may have to wait for the next event
+    // from UIKit, unless a `DisplayLink` ticks them every frame.
+    let tick_task_pools =
+        block2::RcBlock::new(|_: *mut CFRunLoopObserver, _: CFRunLoopActivity| {
+            guard_callback("tick task pools", tick_global_task_pools_on_main_thread);
+        });
+    // SAFETY: The block is only called on the main thread, since that's where it is added.
+    let observer = unsafe {
+        CFRunLoopObserver::with_handler(
+            None,
+            CFRunLoopActivity::BeforeWaiting.0,
+            true,
+            0,
+            Some(&tick_task_pools),
+        )
+    }
+    .expect("failed creating run loop observer");
+    // SAFETY: The static is a valid `CFRunLoopMode`.
+    let mode = unsafe { kCFRunLoopCommonModes };
+    // The run loop retains the observer.
+    CFRunLoop::main()
+        .unwrap()
+        .add_observer(Some(&observer), mode);
+
     trace!("starting UIApplicationMain");
 
-    // Store the application in a static. `UIApplicationMain` does not give us
-    // any other way of passing it onwards.
-    let previous_app = APP_STATE.get(mtm).replace(Some(app));
-    if previous_app.is_some() {
+    // Hand the application over to the delegate, which takes it when initialized.
+    let previous = PENDING_APP.get(mtm).replace(PendingApp::Pending(app));
+    if !matches!(previous, PendingApp::NotStarted) {
         panic!("tried to run `uikit_runner` twice");
     }
 
@@ -71,31 +117,139 @@ pub fn disallow_app_exit(mut exit_messages: MessageReader<AppExit>) {
     }
 }
 
-/// The application can be in the following states:
-/// - Not registered / deinitialized (None).
-/// - Present (Some(handler)).
-/// - In use (RefCell borrowed).
-type AppState = RefCell<Option<App>>;
-
-static APP_STATE: MainThreadBound<AppState> = {
+/// The application waiting to be taken by [`ApplicationDelegate`] when it is initialized.
+///
+/// `UIApplicationMain` creates the delegate itself, so it does not give us any other way of passing
+/// the application onwards.
+static PENDING_APP: MainThreadBound<Cell<PendingApp>> = {
     // SAFETY: Creating marker in a `const` context,
     // where there is no concept of the main thread.
     let mtm = unsafe { MainThreadMarker::new_unchecked() };
-    MainThreadBound::new(RefCell::new(None), mtm)
+    MainThreadBound::new(Cell::new(PendingApp::NotStarted), mtm)
 };
 
-/// Get the [`App`].
+/// The state of [`PENDING_APP`].
 ///
-/// # Panics
+/// The application is remembered as taken, such that running `uikit_runner` again is still
+/// diagnosed once the delegate owns the first application.
+enum PendingApp {
+    NotStarted,
+    Pending(App),
+    Taken,
+}
+
+/// [Update](App::update) the application, instrumented with a signpost interval.
+///
+/// Input buffered by [`send_input_message`] and coalesced window resizes are delivered before the
+/// update.
+pub(crate) fn update_app(app: &mut App) {
+    let _interval = signpost_interval!("App::update");
+    let mtm = MainThreadMarker::new().expect("the application is updated on the main thread");
+    ApplicationDelegate::get(mtm)
+        .ivars()
+        .needs_full_update
+        .set(false);
+    flush_pending_input(app);
+    flush_window_resizes(app.world_mut());
+    #[cfg(feature = "panic-hook")]
+    let _poison = crate::panic_hook::PoisonOnUnwind;
+    app.update();
+    record_first_frame(app.world_mut());
+}
+
+/// Update the application in response to a callback concerning a single window.
+///
+/// Runs only the window's [`WindowSchedule`] if it has one, and otherwise does a full
+/// [`update_app`]. Windows with [`RenderPaused`] are always fully updated.
+pub(crate) fn update_app_for_window(app: &mut App, window: Option<Entity>) {
+    let Some(label) = window
+        // Paused windows are resumed by the main schedule, which may not run otherwise while the
+        // display link is paused along with every window.
+        .filter(|window| app.world().get::<RenderPaused>(*window).is_none())
+        .and_then(|window| app.world().get::<WindowSchedule>(window))
+        .map(|schedule| schedule.label)
+    else {
+        update_app(app);
+        return;
+    };
+    let _interval = signpost_interval!("WindowSchedule");
+    flush_pending_input(app);
+    flush_window_resizes(app.world_mut());
+    if let Err(err) = app.world_mut().try_run_schedule(label) {
+        warn!(?window, %err, "failed running window schedule");
+    }
+}
+
+/// Deliver the input buffered by [`send_input_message`].
+fn flush_pending_input(app: &mut App) {
+    let mtm = MainThreadMarker::new().expect("the application is updated on the main thread");
+    let pending_input = ApplicationDelegate::get(mtm).ivars().pending_input.take();
+    for f in pending_input {
+        f(app.world_mut());
+    }
+}
+
+/// Run a callback from UIKit, such that neither Rust panics nor Objective-C exceptions unwind
+/// across the boundary into UIKit's frames, which are not prepared for that.
+///
+/// Instead, the failure is logged with the name of the callback, and the process is aborted.
+///
+/// With the `panic-hook` feature, this also aborts if a panic escaped into the application before.
+pub(crate) fn guard_callback<R>(name: &str, f: impl FnOnce() -> R) -> R {
+    if trace_callbacks() {
+        info!(callback = name, "UIKit callback");
+    }
+
+    // A panic escaped into the application while it was running, so don't re-enter it.
+    #[cfg(feature = "panic-hook")]
+    if crate::panic_hook::is_poisoned() {
+        error!(callback = name, "application panicked earlier, aborting");
+        process::abort()
+    }
+
+    let result = objc2::exception::catch(AssertUnwindSafe(|| {
+        panic::catch_unwind(AssertUnwindSafe(f))
+    }));
+    match result {
+        Ok(Ok(value)) => value,
+        Ok(Err(payload)) => {
+            #[cfg(feature = "panic-hook")]
+            crate::panic_hook::poison();
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(|s| &**s))
+                .unwrap_or("Box<dyn Any>");
+            error!(
+                callback = name,
+                message, "panicked inside UIKit callback, aborting"
+            );
+            process::abort()
+        }
+        Err(exception) => {
+            #[cfg(feature = "panic-hook")]
+            crate::panic_hook::poison();
+            error!(
+                callback = name,
+                ?exception,
+                "uncaught Objective-C exception inside UIKit callback, aborting"
+            );
+            process::abort()
+        }
+    }
+}
+
+/// Call into UIKit, catching any Objective-C exception that it may throw.
 ///
-/// Panics if:
-/// - The application is already in use (possibly a re-entrant call?).
-/// - The application wasn't initialized.
-#[track_caller]
-pub(crate) fn access_app(mtm: MainThreadMarker) -> RefMut<'static, App> {
-    RefMut::map(APP_STATE.get(mtm).borrow_mut(), |app| {
-        app.as_mut().expect("application was not initialized")
-    })
+/// The exception is logged with the given description, and `None` is returned.
+pub(crate) fn catch_exception<R>(description: &str, f: impl FnOnce() -> R) -> Option<R> {
+    match objc2::exception::catch(AssertUnwindSafe(f)) {
+        Ok(value) => Some(value),
+        Err(exception) => {
+            error!(?exception, "{description}");
+            None
+        }
+    }
 }
 
 fn queue_closure(_mtm: MainThreadMarker, closure: impl FnOnce() + 'static) {
@@ -105,7 +259,7 @@ fn queue_closure(_mtm: MainThreadMarker, closure: impl FnOnce() + 'static) {
     let closure = Cell::new(Some(closure));
     let block = block2::RcBlock::new(move || {
         if let Some(closure) = closure.take() {
-            closure()
+            guard_callback("queued closure", closure)
         } else {
             error!("tried to execute queued closure on main thread twice");
         }
@@ -118,46 +272,158 @@ fn queue_closure(_mtm: MainThreadMarker, closure: impl FnOnce() + 'static) {
     unsafe { CFRunLoop::perform_block(&run_loop, Some(mode), Some(&block)) }
 }
 
-/// Send a message to the application, and [update](App::update) it once afterwards to ensure the
-/// message was processed.
+/// Run a closure with access to the application's [`World`], and [update](App::update) it once
+/// afterwards to ensure any changes were processed.
 ///
 /// Tries to do this synchronously if the application is not in use, but will fall back to
-/// scheduling the message to be sent later if it was.
-pub(crate) fn send_message(mtm: MainThreadMarker, message: impl Message) {
-    if let Ok(mut app) = APP_STATE.get(mtm).try_borrow_mut() {
+/// scheduling the closure to be run later if it was.
+pub(crate) fn with_world(mtm: MainThreadMarker, f: impl FnOnce(&mut World) + 'static) {
+    let delegate = ApplicationDelegate::get(mtm);
+    if let Ok(mut app) = delegate.ivars().app.try_borrow_mut() {
         let app = app.as_mut().expect("application was not initialized");
-        app.world_mut().write_message(message);
-        app.update();
+        guard_callback("with_world", || {
+            let _interval = signpost_interval!("deliver event");
+            f(app.world_mut());
+            update_app(app);
+        });
     } else {
-        trace!("re-entrant access of App, scheduling message for later");
+        trace!("re-entrant access of App, scheduling closure for later");
         queue_closure(mtm, move || {
-            let mut app = access_app(mtm);
-            app.world_mut().write_message(message);
-            app.update();
+            let _interval = signpost_interval!("deliver event");
+            let delegate = ApplicationDelegate::get(mtm);
+            let mut app = delegate.app();
+            f(app.world_mut());
+            update_app(&mut app);
         });
     }
 }
 
+/// Update the application for a new display frame, unless it's already in use.
+///
+/// Unlike [`with_world`], the update is skipped instead of scheduled for later if the application
+/// is in use, as the next frame will update it anyway.
+pub(crate) fn update_app_for_frame(mtm: MainThreadMarker) {
+    let delegate = ApplicationDelegate::get(mtm);
+    let Ok(mut app) = delegate.ivars().app.try_borrow_mut() else {
+        trace!("re-entrant access of App, skipping frame");
+        return;
+    };
+    if let Some(app) = app.as_mut() {
+        update_app(app);
+    }
+}
+
+/// Send a message to the application, and [update](App::update) it once afterwards to ensure the
+/// message was processed.
+pub(crate) fn send_message(mtm: MainThreadMarker, message: impl Message) {
+    with_world(mtm, move |world| {
+        world.write_message(message);
+    });
+}
+
 pub(crate) fn send_window_message(
     mtm: MainThreadMarker,
     message: impl Into<WindowEvent> + Message + Clone,
 ) {
-    if let Ok(mut app) = APP_STATE.get(mtm).try_borrow_mut() {
-        let app = app.as_mut().expect("application was not initialized");
-        app.world_mut().send_window_message(message);
-        app.update();
+    with_world(mtm, move |world| world.send_window_message(message));
+}
+
+/// Send an input message to the application.
+///
+/// If [`UIKitSettings::batched_input`] is enabled, the message is buffered, and delivered at the
+/// start of the next update. Otherwise, this is the same as [`send_message`].
+pub(crate) fn send_input_message(mtm: MainThreadMarker, message: impl Message) {
+    send_input(mtm, move |world| {
+        world.write_message(message);
+    });
+}
+
+/// Deliver input to the application in a single world access.
+///
+/// Like [`send_input_message`], this is buffered if [`UIKitSettings::batched_input`] is enabled,
+/// and otherwise updates the application once, regardless of how many messages `f` writes.
+pub(crate) fn send_input(mtm: MainThreadMarker, f: impl FnOnce(&mut World) + 'static) {
+    let delegate = ApplicationDelegate::get(mtm);
+    let batched_input = delegate.ivars().app.try_borrow().is_ok_and(|app| {
+        app.as_ref()
+            .is_some_and(|app| app.world().resource::<UIKitSettings>().batched_input)
+    });
+    if batched_input {
+        delegate.buffer_input(Box::new(f));
     } else {
-        trace!("re-entrant access of App, scheduling message for later");
-        queue_closure(mtm, move || {
-            let mut app = access_app(mtm);
-            app.world_mut().send_window_message(message);
-            app.update();
-        });
+        with_world(mtm, f);
     }
 }
 
-#[derive(Debug)]
-pub(crate) struct Ivars {}
+/// Buffer a message until the next update, without causing an update.
+///
+/// Used for messages that are sent too often to update the application for each of them, and that
+/// are fine to deliver with whatever update happens next.
+pub(crate) fn buffer_message(mtm: MainThreadMarker, message: impl Message) {
+    let delegate = ApplicationDelegate::get(mtm);
+    delegate
+        .ivars()
+        .pending_input
+        .borrow_mut()
+        .push(Box::new(move |world| {
+            world.write_message(message);
+        }));
+}
+
+/// Run [`UIKitSettings::touch_schedule`] with the input buffered so far.
+///
+/// Does nothing unless input is batched, as the touches are otherwise delivered with a full update
+/// right away.
+pub(crate) fn run_touch_schedule(mtm: MainThreadMarker) {
+    let delegate = ApplicationDelegate::get(mtm);
+    let Ok(mut app) = delegate.ivars().app.try_borrow_mut() else {
+        // The application is being updated, and will see the touches in the next update.
+        return;
+    };
+    let Some(app) = app.as_mut() else {
+        return;
+    };
+    let settings = app.world().resource::<UIKitSettings>();
+    let Some(label) = settings.touch_schedule.filter(|_| settings.batched_input) else {
+        return;
+    };
+
+    let _interval = signpost_interval!("touch schedule");
+    flush_pending_input(app);
+    if let Err(err) = app.world_mut().try_run_schedule(label) {
+        warn!(%err, "failed running touch schedule");
+    }
+    // Make the already scheduled flush do a full update, such that the rest of the application
+    // still sees the touches.
+    delegate.ivars().needs_full_update.set(true);
+}
+
+type PendingInput = Box<dyn FnOnce(&mut World)>;
+
+/// The application can be in the following states:
+/// - Not registered / deinitialized (None).
+/// - Present (Some(handler)).
+/// - In use (RefCell borrowed).
+pub(crate) struct Ivars {
+    app: RefCell<Option<App>>,
+    /// Input buffered by [`send_input_message`].
+    pending_input: RefCell<Vec<PendingInput>>,
+    /// Whether an update has been scheduled to flush the pending input.
+    flush_scheduled: Cell<bool>,
+    /// Whether input was delivered by [`run_touch_schedule`], but not yet in a full update.
+    needs_full_update: Cell<bool>,
+}
+
+impl fmt::Debug for Ivars {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ivars")
+            .field("app", &self.app)
+            .field("pending_input", &self.pending_input.borrow().len())
+            .field("flush_scheduled", &self.flush_scheduled)
+            .field("needs_full_update", &self.needs_full_update)
+            .finish()
+    }
+}
 
 define_class!(
     #[unsafe(super(NSObject))]
@@ -173,7 +439,21 @@ define_class!(
     impl ApplicationDelegate {
         #[unsafe(method_id(init))]
         fn init(this: Allocated<Self>) -> Retained<Self> {
-            let this = this.set_ivars(Ivars {});
+            // SAFETY: The class is `MainThreadOnly`, so it can only be allocated on the main thread.
+            let mtm = unsafe { MainThreadMarker::new_unchecked() };
+            let app = match PENDING_APP.get(mtm).replace(PendingApp::Taken) {
+                PendingApp::Pending(app) => Some(app),
+                PendingApp::NotStarted | PendingApp::Taken => {
+                    warn!("application delegate initialized without an App, use `uikit_runner`");
+                    None
+                }
+            };
+            let this = this.set_ivars(Ivars {
+                app: RefCell::new(app),
+                pending_input: RefCell::new(Vec::new()),
+                flush_scheduled: Cell::new(false),
+                needs_full_update: Cell::new(false),
+            });
             unsafe { msg_send![super(this), init] }
         }
     }
@@ -194,18 +474,24 @@ define_class!(
             _application: &UIApplication,
             launch_options: Option<&NSDictionary<UIApplicationLaunchOptionsKey, AnyObject>>,
         ) -> bool {
-            trace!(
-                ?launch_options,
-                "application:willFinishLaunchingWithOptions:"
-            );
-
-            // Run the App once (should end up calling the `Startup` events).
-            // TODO: Avoid running the `Update` events here too (as that's
-            // probably too soon)?
-            let mut app = access_app(self.mtm());
-            app.update();
-
-            true
+            guard_callback("application:willFinishLaunchingWithOptions:", || {
+                trace!(
+                    ?launch_options,
+                    "application:willFinishLaunchingWithOptions:"
+                );
+
+                let mut app = self.app();
+                app.world_mut()
+                    .resource_mut::<LaunchTimings>()
+                    .will_finish_launching = Some(SystemTime::now());
+
+                // Run the App once (should end up calling the `Startup` events).
+                // TODO: Avoid running the `Update` events here too (as that's
+                // probably too soon)?
+                update_app(&mut app);
+
+                true
+            })
         }
 
         #[unsafe(method(application:didFinishLaunchingWithOptions:))]
@@ -214,89 +500,123 @@ define_class!(
             _application: &UIApplication,
             launch_options: Option<&NSDictionary<UIApplicationLaunchOptionsKey, AnyObject>>,
         ) -> bool {
-            trace!(
-                ?launch_options,
-                "application:didFinishLaunchingWithOptions:"
-            );
-
-            let mut app = access_app(self.mtm());
-            // TODO: Run app.update here?
-
-            // Scenes are only available on iOS 13.0 and above, so if not available, act roughly
-            // as-if `scene:willConnectToSession:options:` was called, and initialize the primary
-            // window.
-            if cfg!(feature = "no-scene")
-                || !available!(ios = 13.0, tvos = 13.0, visionos = 1.0, ..)
-            {
-                let world = app.world_mut();
-                let query = world
-                    .query_filtered::<(Entity, &Window), With<PrimaryWindow>>()
-                    .single(&world);
-                let (entity, uikit_window) = match query {
-                    Ok((entity, window)) => {
-                        trace!("initializing primary window");
-                        // If the user provided a primary window, initialize that.
-                        let uikit_window = setup_window(None, entity, window, self.mtm());
-                        (entity, uikit_window)
-                    }
-                    Err(QuerySingleError::NoEntities(_)) => {
-                        trace!("creating primary window");
-                        // If there was no primary window, let's create it ourselves.
-                        let entity = world.spawn((Window::default(), PrimaryWindow));
-                        let window = entity.get::<Window>().unwrap();
-                        let uikit_window = setup_window(None, entity.id(), window, self.mtm());
-                        (entity.id(), uikit_window)
-                    }
-                    Err(e) => panic!("failed fetching primary window: {e}"),
-                };
-
-                world
-                    .non_send_resource_mut::<UIKitWindows>()
-                    .insert(entity, uikit_window);
-                world.send_window_message(WindowCreated { window: entity });
-                // Intentional update, to preserve the amount of updates regardless of using scenes.
-                app.update();
-            }
-
-            true
+            guard_callback("application:didFinishLaunchingWithOptions:", || {
+                trace!(
+                    ?launch_options,
+                    "application:didFinishLaunchingWithOptions:"
+                );
+
+                let mut app = self.app();
+                // TODO: Run app.update here?
+
+                // Scenes are only available on iOS 13.0 and above, so if not available, act roughly
+                // as-if `scene:willConnectToSession:options:` was called, and initialize the primary
+                // window.
+                if cfg!(feature = "no-scene")
+                    || !available!(ios = 13.0, tvos = 13.0, visionos = 1.0, ..)
+                {
+                    let world = app.world_mut();
+                    let settings = world.resource::<UIKitSettings>().clone();
+                    let query = world
+                        .query_filtered::<(Entity, &Window), With<PrimaryWindow>>()
+                        .single(&world);
+                    let (entity, uikit_window) = match query {
+                        Ok((entity, window)) => {
+                            trace!("initializing primary window");
+                            // If the user provided a primary window, initialize that.
+                            let uikit_window =
+                                setup_window(None, entity, window, &settings, self.mtm());
+                            (entity, uikit_window)
+                        }
+                        Err(QuerySingleError::NoEntities(_)) => {
+                            trace!("creating primary window");
+                            // If there was no primary window, let's create it ourselves.
+                            let entity = settings.spawn_primary_window(world);
+                            let window = world.get::<Window>(entity).unwrap();
+                            let uikit_window =
+                                setup_window(None, entity, window, &settings, self.mtm());
+                            (entity, uikit_window)
+                        }
+                        Err(e) => panic!("failed fetching primary window: {e}"),
+                    };
+
+                    world
+                        .resource_mut::<UIKitWindows>()
+                        .insert(entity, uikit_window);
+                    world.send_window_message(WindowCreated { window: entity });
+                    // Intentional update, to preserve the amount of updates regardless of using scenes.
+                    update_app(&mut app);
+                }
+
+                true
+            })
         }
 
         // Only called when not using scenes.
         #[unsafe(method(applicationWillEnterForeground:))]
         fn applicationWillEnterForeground(&self, _application: &UIApplication) {
-            trace!("applicationWillEnterForeground:");
+            guard_callback("applicationWillEnterForeground:", || {
+                trace!("applicationWillEnterForeground:");
+                self.send_primary_window_message(|window| WindowForeground { window });
+            })
         }
 
         // Only called when not using scenes.
         #[unsafe(method(applicationDidBecomeActive:))]
         fn applicationDidBecomeActive(&self, _application: &UIApplication) {
-            trace!("applicationDidBecomeActive:");
+            guard_callback("applicationDidBecomeActive:", || {
+                trace!("applicationDidBecomeActive:");
+                self.send_primary_window_message(|window| WindowActivate { window });
+
+                let app = self.app();
+                let world = app.world();
+                if !world.resource::<UIKitSettings>().automatic_first_responder {
+                    return;
+                }
+                let uiwindow = primary_window(world)
+                    .and_then(|entity| world.resource::<UIKitWindows>().get(entity, self.mtm()))
+                    .map(|uikit_window| uikit_window.uiwindow.clone());
+                // Release the app, becoming first responder emits messages.
+                drop(app);
+                if let Some(uiwindow) = uiwindow {
+                    become_first_responder(&uiwindow);
+                }
+            })
         }
 
         // Only called when not using scenes.
         #[unsafe(method(applicationWillResignActive:))]
         fn applicationWillResignActive(&self, _application: &UIApplication) {
-            trace!("applicationWillResignActive:");
+            guard_callback("applicationWillResignActive:", || {
+                trace!("applicationWillResignActive:");
+                self.send_primary_window_message(|window| WindowDeactivate { window });
+            })
         }
 
         // Only called when not using scenes.
         #[unsafe(method(applicationDidEnterBackground:))]
         fn applicationDidEnterBackground(&self, _application: &UIApplication) {
-            trace!("applicationDidEnterBackground:");
+            guard_callback("applicationDidEnterBackground:", || {
+                trace!("applicationDidEnterBackground:");
+                self.send_primary_window_message(|window| WindowBackground { window });
+            })
         }
 
         #[unsafe(method(applicationWillTerminate:))]
         fn applicationWillTerminate(&self, _application: &UIApplication) {
-            trace!("applicationWillTerminate:");
-
-            let app = APP_STATE
-                .get(self.mtm())
-                .borrow_mut()
-                .take()
-                .expect("application was not initialized");
-            // `Drop` the `App` to cleanly shut down Bevy's state.
-            // TODO: Emit a message too?
-            let _: App = app;
+            guard_callback("applicationWillTerminate:", || {
+                trace!("applicationWillTerminate:");
+
+                let app = self
+                    .ivars()
+                    .app
+                    .borrow_mut()
+                    .take()
+                    .expect("application was not initialized");
+                // `Drop` the `App` to cleanly shut down Bevy's state.
+                // TODO: Emit a message too?
+                let _: App = app;
+            })
         }
 
         //
@@ -308,7 +628,7 @@ define_class!(
             trace!("applicationDidReceiveMemoryWarning:");
         }
 
-        // TODO: Called when using scenes or not?
+        // Only called when not using scenes, otherwise `scene:openURLContexts:` is used.
         #[unsafe(method(application:openURL:options:))]
         #[allow(deprecated)]
         fn application_openURL_options(
@@ -317,9 +637,27 @@ define_class!(
             url: &NSURL,
             options: &NSDictionary<UIApplicationOpenURLOptionsKey, AnyObject>,
         ) -> bool {
-            trace!(?url, ?options, "application:openURL:options:");
-            // TODO: Handle URL opening
-            false
+            guard_callback("application:openURL:options:", || {
+                trace!(?url, ?options, "application:openURL:options:");
+                // SAFETY: The static is a valid `NSString`.
+                let in_place = options
+                    .objectForKey(unsafe { UIApplicationOpenURLOptionsOpenInPlaceKey })
+                    .and_then(|value| value.downcast::<NSNumber>().ok())
+                    .is_some_and(|value| value.as_bool());
+
+                let mut app = self.app();
+                let world = app.world_mut();
+                let window = primary_window(world);
+                let mut handled = open_file(world, window, url, in_place);
+                if !handled {
+                    if let Some(link) = DeepLink::from_url(url) {
+                        open_deep_link(world, window, link, false);
+                        handled = true;
+                    }
+                }
+                update_app(&mut app);
+                handled
+            })
         }
 
         // Scenes
@@ -363,7 +701,11 @@ define_class!(
             scene_sessions: &NSSet<UISceneSession>,
         ) {
             trace!(?scene_sessions, "application:didDiscardSceneSessions:");
-            // TODO: State restoration based on UISceneSession.
+            let sessions = scene_sessions
+                .iter()
+                .map(|session| session.persistentIdentifier().to_string())
+                .collect();
+            send_message(self.mtm(), SceneSessionsDiscarded { sessions });
         }
 
         // Storyboarding
@@ -383,3 +725,82 @@ define_class!(
         // TODO: Expose other UIApplicationDelegate events to the user?
     }
 );
+
+impl ApplicationDelegate {
+    /// Get the application delegate, which owns the [`App`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the application's delegate is not ours.
+    #[track_caller]
+    pub(crate) fn get(mtm: MainThreadMarker) -> Retained<Self> {
+        let delegate = UIApplication::sharedApplication(mtm)
+            .delegate()
+            .expect("application delegate was not set");
+        let delegate: &AnyObject = (*delegate).as_ref();
+        delegate
+            .downcast_ref::<Self>()
+            .expect("application delegate was not `BevyApplicationDelegate`")
+            .retain()
+    }
+
+    /// Get the [`App`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - The application is already in use (possibly a re-entrant call?).
+    /// - The application wasn't initialized.
+    #[track_caller]
+    pub(crate) fn app(&self) -> RefMut<'_, App> {
+        RefMut::map(self.ivars().app.borrow_mut(), |app| {
+            app.as_mut().expect("application was not initialized")
+        })
+    }
+
+    /// Buffer input until the next update, and make sure that such an update happens once the
+    /// current run loop iteration is done processing events.
+    fn buffer_input(&self, f: PendingInput) {
+        self.ivars().pending_input.borrow_mut().push(f);
+        if self.ivars().flush_scheduled.replace(true) {
+            return;
+        }
+        let mtm = self.mtm();
+        queue_closure(mtm, move || {
+            let delegate = ApplicationDelegate::get(mtm);
+            delegate.ivars().flush_scheduled.set(false);
+            if delegate.ivars().pending_input.borrow().is_empty()
+                && !delegate.ivars().needs_full_update.get()
+            {
+                // Already flushed by an update in the meantime.
+                return;
+            }
+            let _interval = signpost_interval!("deliver event");
+            let mut app = delegate.app();
+            update_app(&mut app);
+        });
+    }
+
+    /// Send a lifecycle message for the primary window.
+    ///
+    /// When not using scenes, the application's lifecycle is the primary window's lifecycle, so
+    /// this makes the messages consistent with the ones emitted by the scene delegate.
+    fn send_primary_window_message<M: Into<WindowEvent> + Message + Clone>(
+        &self,
+        message: impl FnOnce(Entity) -> M,
+    ) {
+        let mut app = self.app();
+        let world = app.world_mut();
+        if let Some(window) = primary_window(world) {
+            world.send_window_message(message(window));
+        }
+        update_app(&mut app);
+    }
+}
+
+fn primary_window(world: &World) -> Option<Entity> {
+    world
+        .try_query_filtered::<Entity, With<PrimaryWindow>>()?
+        .single(world)
+        .ok()
+}
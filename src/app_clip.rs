@@ -0,0 +1,25 @@
+use bevy_ecs::{entity::Entity, message::Message};
+use objc2_foundation::{NSUserActivity, NSUserActivityTypeBrowsingWeb};
+
+/// The application was invoked as an App Clip, with the given invocation URL.
+///
+/// This is emitted both when a scene is connected with an invocation, and when an invocation is
+/// continued in an already running scene. Note that universal links opened in the full
+/// application are delivered in exactly the same way, so this message is emitted for those too.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct AppClipInvocation {
+    /// The window whose scene received the invocation.
+    pub window: Entity,
+    /// The invocation URL, as configured in App Store Connect or the App Clip Code.
+    pub url: String,
+}
+
+/// Extract the invocation URL from a user activity, if it is an App Clip invocation.
+pub(crate) fn invocation_url(activity: &NSUserActivity) -> Option<String> {
+    // SAFETY: The static is a valid `NSString`.
+    if &*activity.activityType() != unsafe { NSUserActivityTypeBrowsingWeb } {
+        return None;
+    }
+    let url = activity.webpageURL()?;
+    Some(url.absoluteString()?.to_string())
+}
@@ -0,0 +1,261 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use std::cell::RefCell;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    message::Message,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_math::Rect;
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{define_class, msg_send, sel, DefinedClass as _, MainThreadMarker, MainThreadOnly};
+use objc2_foundation::{NSObject, NSObjectProtocol, NSString};
+use objc2_ui_kit::{
+    UIControlEvents, UITextContentType, UITextContentTypeEmailAddress,
+    UITextContentTypeNewPassword, UITextContentTypeOneTimeCode, UITextContentTypePassword,
+    UITextContentTypeUsername, UITextField, UITextInputTraits, UIView,
+};
+use tracing::trace;
+
+use crate::app::send_message;
+use crate::scribble::rect_to_uikit;
+use crate::view::View;
+use crate::{main_thread_marker, UIKitWindows};
+
+/// The kind of content of an [`AutofillField`], which decides what the system offers to fill in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextContentType {
+    /// The username or account name of a login.
+    Username,
+    /// An existing password, which can be filled in from the user's saved passwords.
+    Password,
+    /// A new password, for which the system can suggest a strong password.
+    NewPassword,
+    /// A one-time code, which can be filled in from a received text message or email.
+    OneTimeCode,
+    /// An email address.
+    EmailAddress,
+}
+
+impl TextContentType {
+    fn to_uikit(self) -> &'static UITextContentType {
+        // SAFETY: The content types are immutable constants.
+        unsafe {
+            match self {
+                Self::Username => UITextContentTypeUsername,
+                Self::Password => UITextContentTypePassword,
+                Self::NewPassword => UITextContentTypeNewPassword,
+                Self::OneTimeCode => UITextContentTypeOneTimeCode,
+                Self::EmailAddress => UITextContentTypeEmailAddress,
+            }
+        }
+    }
+
+    fn is_secure(self) -> bool {
+        matches!(self, Self::Password | Self::NewPassword)
+    }
+}
+
+/// A text field drawn by the application that the system can fill in automatically, see
+/// [`AutofillFields`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutofillField {
+    /// An identifier for the field, reported back in [`AutofillInput`].
+    pub id: u64,
+    /// What the field contains.
+    pub content_type: TextContentType,
+    /// The frame of the field, in logical coordinates relative to the top-left corner of the
+    /// window.
+    pub rect: Rect,
+    /// The current text of the field.
+    pub text: String,
+}
+
+/// Text fields in a window, such as on a login screen, that iOS can offer to fill in with saved
+/// passwords and one-time codes.
+///
+/// Each field is backed by an invisible native text field. While a field is
+/// [`focused`](Self::focused), its native field is the first responder, which shows the keyboard
+/// with AutoFill suggestions above it, and all text entered into it is delivered as
+/// [`AutofillInput`] messages. Fields that belong together, such as a username and a password,
+/// should be in the same window, as the system fills them in together. Add this to the entity of
+/// the [`Window`](bevy_window::Window).
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct AutofillFields {
+    /// The fields.
+    pub fields: Vec<AutofillField>,
+    /// The [`AutofillField::id`] of the field that is being edited, if any.
+    pub focused: Option<u64>,
+}
+
+/// The text of an [`AutofillField`] was changed, either by typing or by AutoFill.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct AutofillInput {
+    /// The window containing the field.
+    pub window: Entity,
+    /// The [`AutofillField::id`] of the field.
+    pub field: u64,
+    /// The full new text of the field.
+    pub text: String,
+}
+
+/// Propagate changes to [`AutofillFields`] to the native view.
+pub fn update_autofill_fields(
+    autofill_fields: Query<(Entity, Ref<AutofillFields>)>,
+    mut removed: RemovedComponents<AutofillFields>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, autofill_fields) in &autofill_fields {
+        // Also update when the window was only just registered with UIKit.
+        if !autofill_fields.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            trace!(?entity, ?autofill_fields, "setting AutoFill fields");
+            let mut delegate = view.ivars().autofill_delegate.borrow_mut();
+            let delegate = delegate.get_or_insert_with(|| AutofillDelegate::new(entity, mtm));
+            delegate.set_fields(&view, &autofill_fields.fields, autofill_fields.focused, mtm);
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            if let Some(delegate) = view.ivars().autofill_delegate.take() {
+                delegate.set_fields(&view, &[], None, mtm);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct AutofillDelegateIvars {
+    window: Entity,
+    /// The native text field backing each field, by [`AutofillField::id`].
+    text_fields: RefCell<Vec<(u64, Retained<UITextField>)>>,
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "BevyAutofillDelegate"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = AutofillDelegateIvars]
+    #[derive(Debug)]
+    pub(crate) struct AutofillDelegate;
+
+    unsafe impl NSObjectProtocol for AutofillDelegate {}
+
+    impl AutofillDelegate {
+        #[unsafe(method(textFieldDidChange:))]
+        fn textFieldDidChange(&self, text_field: &UITextField) {
+            let Some(field) = self
+                .ivars()
+                .text_fields
+                .borrow()
+                .iter()
+                .find(|(_, existing)| **existing == *text_field)
+                .map(|(id, _)| *id)
+            else {
+                return;
+            };
+            let text = text_field.text().map(|text| text.to_string()).unwrap_or_default();
+            trace!(field, "AutoFill input");
+            send_message(
+                self.mtm(),
+                AutofillInput {
+                    window: self.ivars().window,
+                    field,
+                    text,
+                },
+            );
+        }
+    }
+);
+
+impl AutofillDelegate {
+    fn new(window: Entity, mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(AutofillDelegateIvars {
+            window,
+            text_fields: RefCell::new(Vec::new()),
+        });
+        unsafe { msg_send![super(this), init] }
+    }
+
+    /// Create, update and remove native text fields to match `fields`.
+    fn set_fields(
+        &self,
+        view: &View,
+        fields: &[AutofillField],
+        focused: Option<u64>,
+        mtm: MainThreadMarker,
+    ) {
+        let mut text_fields = self.ivars().text_fields.borrow_mut();
+        text_fields.retain(|(id, text_field)| {
+            let keep = fields.iter().any(|field| field.id == *id);
+            if !keep {
+                text_field.removeFromSuperview();
+            }
+            keep
+        });
+
+        for field in fields {
+            let text_field = match text_fields.iter().find(|(id, _)| *id == field.id) {
+                Some((_, text_field)) => text_field.clone(),
+                None => {
+                    let text_field = self.create_text_field(view, mtm);
+                    text_fields.push((field.id, text_field.clone()));
+                    text_field
+                }
+            };
+            text_field.setFrame(rect_to_uikit(field.rect));
+            text_field.setTextContentType(Some(field.content_type.to_uikit()));
+            text_field.setSecureTextEntry(field.content_type.is_secure());
+            let text = NSString::from_str(&field.text);
+            if text_field.text().as_deref() != Some(&*text) {
+                text_field.setText(Some(&text));
+            }
+        }
+
+        // Release the borrow, as changing the first responder may trigger callbacks.
+        let snapshot = text_fields.clone();
+        drop(text_fields);
+        for (id, text_field) in &snapshot {
+            if Some(*id) == focused {
+                if !text_field.isFirstResponder() {
+                    text_field.becomeFirstResponder();
+                }
+            } else if text_field.isFirstResponder() {
+                text_field.resignFirstResponder();
+            }
+        }
+    }
+
+    fn create_text_field(&self, view: &View, mtm: MainThreadMarker) -> Retained<UITextField> {
+        let text_field = UITextField::new(mtm);
+        text_field.setAlpha(0.0);
+        let target: &AnyObject = self;
+        // SAFETY: The target has a method with the given selector, which takes the text field.
+        unsafe {
+            text_field.addTarget_action_forControlEvents(
+                Some(target),
+                sel!(textFieldDidChange:),
+                UIControlEvents::EditingChanged,
+            );
+        }
+        let view: &UIView = view;
+        view.addSubview(&text_field);
+        text_field
+    }
+}
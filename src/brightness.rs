@@ -0,0 +1,105 @@
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    query::With,
+    resource::Resource,
+    system::{NonSendMarker, Query, Res, ResMut},
+};
+use bevy_window::PrimaryWindow;
+use dispatch2::MainThreadBound;
+use objc2::{rc::Retained, MainThreadMarker};
+use objc2_core_foundation::CGFloat;
+use objc2_ui_kit::{UIApplication, UIApplicationState, UIScreen};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// The brightness of the screen that the primary window is shown on.
+///
+/// The brightness is only applied while the application is in the foreground. The user's original
+/// brightness is restored when the application enters the background or terminates, or when
+/// [`brightness`](Self::brightness) is set back to `None`.
+///
+/// Note that the user can still change the brightness in Control Center, which is respected until
+/// this resource is changed again.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScreenBrightness {
+    /// The brightness, from `0.0` to `1.0`, or `None` to use the user's brightness.
+    pub brightness: Option<f32>,
+    /// Allow dimming the screen further than the hardware can, by overlaying it in software.
+    pub software_dimming: bool,
+}
+
+/// The user's brightness before [`ScreenBrightness`] was applied, restored when dropped.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct OriginalBrightness {
+    saved: Option<MainThreadBound<(Retained<UIScreen>, CGFloat)>>,
+}
+
+impl OriginalBrightness {
+    fn restore(&mut self, mtm: MainThreadMarker) {
+        if let Some(saved) = self.saved.take() {
+            let (screen, brightness) = saved.into_inner(mtm);
+            trace!(brightness, "restoring screen brightness");
+            screen.setBrightness(brightness);
+        }
+    }
+}
+
+impl Drop for OriginalBrightness {
+    fn drop(&mut self) {
+        // The world is dropped on the main thread when the application terminates.
+        if let Some(mtm) = MainThreadMarker::new() {
+            self.restore(mtm);
+        }
+    }
+}
+
+/// Apply [`ScreenBrightness`] while in the foreground, and restore the user's brightness
+/// otherwise.
+pub(crate) fn update_screen_brightness(
+    brightness: Res<ScreenBrightness>,
+    mut original: ResMut<OriginalBrightness>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    let in_background =
+        UIApplication::sharedApplication(mtm).applicationState() == UIApplicationState::Background;
+    let Some(value) = brightness.brightness.filter(|_| !in_background) else {
+        original.restore(mtm);
+        return;
+    };
+
+    let Some(uikit_window) = primary_window
+        .single()
+        .ok()
+        .and_then(|entity| uikit_windows.get(entity, mtm))
+    else {
+        // Wait for the primary window to be set up.
+        return;
+    };
+    let screen = match &uikit_window.scene {
+        Some(scene) => scene.screen(),
+        #[allow(deprecated)]
+        None => UIScreen::mainScreen(mtm),
+    };
+
+    if screen.wantsSoftwareDimming() != brightness.software_dimming {
+        screen.setWantsSoftwareDimming(brightness.software_dimming);
+    }
+
+    // Don't override changes made by the user unless the brightness changed.
+    if original.saved.is_some() && !brightness.is_changed() {
+        return;
+    }
+    if original.saved.is_none() {
+        original.saved = Some(MainThreadBound::new(
+            (screen.clone(), screen.brightness()),
+            mtm,
+        ));
+    }
+    trace!(brightness = value, "setting screen brightness");
+    screen.setBrightness(value as CGFloat);
+}
@@ -0,0 +1,126 @@
+use bevy_ecs::{
+    entity::{hash_map::EntityHashMap, Entity},
+    lifecycle::RemovedComponents,
+    message::{Message, MessageWriter},
+    query::Changed,
+    resource::Resource,
+    system::{Local, Query},
+};
+use bevy_math::Vec2;
+use bevy_window::{CursorGrabMode, CursorOptions, Window};
+use objc2::available;
+use tracing::warn;
+
+/// Which [`Window`] and [`CursorOptions`] fields are applied on the current device.
+///
+/// Fields that aren't applied are silently ignored, except for the ones listed in
+/// [`UnsupportedOperation`], which are reported with [`UnsupportedWindowOperation`] instead.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowCapabilities {
+    /// Whether [`Window::set_cursor_position`] moves the pointer.
+    ///
+    /// UIKit never allows this.
+    pub cursor_position: bool,
+    /// Whether [`CursorOptions::grab_mode`] confines or locks the pointer.
+    pub cursor_grab: bool,
+    /// Whether [`CursorOptions::visible`] hides the pointer.
+    ///
+    /// Requires iOS 13.4 or visionOS, and is not available on tvOS.
+    pub cursor_visible: bool,
+    /// Whether [`Window::position`] moves the window.
+    pub position: bool,
+    /// Whether [`Window::mode`] switches between windowed and fullscreen.
+    pub mode: bool,
+    /// Whether [`Window::decorations`] hides the title bar on Mac Catalyst.
+    pub decorations: bool,
+    /// Whether [`Window::transparent`] shows the content behind the window.
+    pub transparent: bool,
+    /// Whether [`Window::visible`] hides the window.
+    pub visible: bool,
+}
+
+impl Default for WindowCapabilities {
+    fn default() -> Self {
+        Self {
+            cursor_position: false,
+            cursor_grab: false,
+            cursor_visible: cfg!(not(target_os = "tvos"))
+                && available!(ios = 13.4, visionos = 1.0, ..),
+            position: false,
+            mode: false,
+            decorations: false,
+            transparent: false,
+            visible: false,
+        }
+    }
+}
+
+/// A window operation that UIKit cannot perform, see [`UnsupportedWindowOperation`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnsupportedOperation {
+    /// [`Window::set_cursor_position`] was called with the given physical position.
+    SetCursorPosition(Vec2),
+    /// [`CursorOptions::grab_mode`] was set to the given mode.
+    CursorGrab(CursorGrabMode),
+}
+
+/// The application requested a window operation that UIKit cannot perform, and which was ignored.
+///
+/// Use this to fall back to a different approach, such as a software cursor. See
+/// [`WindowCapabilities`] for checking support up front.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct UnsupportedWindowOperation {
+    /// The window that the operation was requested on.
+    pub window: Entity,
+    /// The operation.
+    pub operation: UnsupportedOperation,
+}
+
+/// Report changes to [`Window`] and [`CursorOptions`] that UIKit cannot perform as
+/// [`UnsupportedWindowOperation`].
+pub fn report_unsupported_operations(
+    windows: Query<(Entity, &Window), Changed<Window>>,
+    cursor_options: Query<(Entity, &CursorOptions), Changed<CursorOptions>>,
+    mut removed: RemovedComponents<Window>,
+    // The cursor position is never set by us, so any change is a request from the application.
+    mut cursor_positions: Local<EntityHashMap<Option<Vec2>>>,
+    mut grab_modes: Local<EntityHashMap<CursorGrabMode>>,
+    mut unsupported: MessageWriter<UnsupportedWindowOperation>,
+) {
+    for entity in removed.read() {
+        cursor_positions.remove(&entity);
+        grab_modes.remove(&entity);
+    }
+
+    for (entity, window) in &windows {
+        let position = window.physical_cursor_position();
+        let previous = cursor_positions.insert(entity, position).flatten();
+        if let Some(position) = position.filter(|position| Some(*position) != previous) {
+            warn!(
+                ?entity,
+                ?position,
+                "moving the cursor is not supported by UIKit"
+            );
+            unsupported.write(UnsupportedWindowOperation {
+                window: entity,
+                operation: UnsupportedOperation::SetCursorPosition(position),
+            });
+        }
+    }
+
+    for (entity, cursor_options) in &cursor_options {
+        let grab_mode = cursor_options.grab_mode;
+        let previous = grab_modes.insert(entity, grab_mode);
+        if grab_mode != CursorGrabMode::None && Some(grab_mode) != previous {
+            warn!(
+                ?entity,
+                ?grab_mode,
+                "grabbing the cursor is not supported by UIKit"
+            );
+            unsupported.write(UnsupportedWindowOperation {
+                window: entity,
+                operation: UnsupportedOperation::CursorGrab(grab_mode),
+            });
+        }
+    }
+}
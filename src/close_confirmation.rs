@@ -0,0 +1,204 @@
+use std::ptr::NonNull;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    message::{Message, MessageReader},
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_window::Window;
+use block2::RcBlock;
+use objc2::{available, MainThreadMarker};
+use objc2_foundation::NSString;
+use objc2_ui_kit::{UIAlertAction, UIAlertActionStyle, UIAlertController, UIAlertControllerStyle};
+use tracing::{trace, warn};
+
+use crate::app::send_message;
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Marks a window whose content has unsaved changes.
+///
+/// UIKit does not allow intercepting the close button on Mac Catalyst, so it's disabled while the
+/// window has this component. Instead, offer closing through the application (such as with a
+/// "Close" menu command), ask the user with [`ConfirmWindowClose`], and then either save the
+/// changes or despawn the window. Add this to the entity of the [`Window`].
+///
+/// Requires iOS 16.0, and only has an effect where windows have a close button, such as on Mac
+/// Catalyst.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct UnsavedChanges;
+
+/// Ask the user whether to save the changes in a window before closing it, with a system alert.
+///
+/// The user's choice is delivered as a [`WindowCloseConfirmed`] message.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmWindowClose {
+    /// The window to present the alert in.
+    pub window: Entity,
+    /// The title of the alert, such as `"Do you want to save the changes?"`.
+    pub title: String,
+    /// Additional text shown below the title.
+    pub message: Option<String>,
+    /// The title of the button that saves the changes.
+    pub save: String,
+    /// The title of the button that discards the changes.
+    pub discard: String,
+    /// The title of the button that cancels closing the window.
+    pub cancel: String,
+}
+
+impl ConfirmWindowClose {
+    /// Ask with the given title, and buttons with the default English titles.
+    pub fn new(window: Entity, title: impl Into<String>) -> Self {
+        Self {
+            window,
+            title: title.into(),
+            message: None,
+            save: "Save".into(),
+            discard: "Don't Save".into(),
+            cancel: "Cancel".into(),
+        }
+    }
+}
+
+/// The user's choice in a [`ConfirmWindowClose`] alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CloseChoice {
+    /// Save the changes, and close the window.
+    Save,
+    /// Close the window without saving.
+    Discard,
+    /// Keep the window open.
+    Cancel,
+}
+
+/// The user answered a [`ConfirmWindowClose`] alert.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowCloseConfirmed {
+    /// The window that the alert was presented in.
+    pub window: Entity,
+    /// What the user chose.
+    pub choice: CloseChoice,
+}
+
+/// Disable the close button of windows with [`UnsavedChanges`].
+///
+/// Must run after `changed_windows`, which sets the close button from [`Window::enabled_buttons`].
+pub fn update_close_buttons(
+    unsaved: Query<(Entity, Ref<Window>, Ref<UnsavedChanges>)>,
+    mut removed: RemovedComponents<UnsavedChanges>,
+    windows: Query<&Window>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    if !available!(ios = 16.0, tvos = 16.0, visionos = 1.0, ..) {
+        return;
+    }
+    let set_closable = |entity: Entity, val: bool| {
+        let Some(behaviours) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.scene.as_deref())
+            .and_then(|scene| scene.windowingBehaviors())
+        else {
+            return;
+        };
+        if behaviours.isClosable() != val {
+            trace!(
+                ?entity,
+                ?val,
+                "setting UIWindowScene.windowingBehaviors.closable"
+            );
+            behaviours.setClosable(val);
+        }
+    };
+
+    for (entity, window, unsaved) in &unsaved {
+        if window.is_changed() || unsaved.is_changed() || uikit_windows.is_changed() {
+            set_closable(entity, false);
+        }
+    }
+    for entity in removed.read() {
+        if let Ok(window) = windows.get(entity) {
+            set_closable(entity, window.enabled_buttons.close);
+        }
+    }
+}
+
+/// Present alerts requested with [`ConfirmWindowClose`].
+pub fn present_close_confirmations(
+    mut requests: MessageReader<ConfirmWindowClose>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for request in requests.read() {
+        let window = request.window;
+        let Some(uikit_window) = uikit_windows.get(window, mtm) else {
+            warn!(
+                ?window,
+                "tried to confirm close of window not registered with UIKit"
+            );
+            continue;
+        };
+        trace!(?window, "presenting close confirmation");
+        let alert = UIAlertController::alertControllerWithTitle_message_preferredStyle(
+            Some(&NSString::from_str(&request.title)),
+            request
+                .message
+                .as_deref()
+                .map(NSString::from_str)
+                .as_deref(),
+            UIAlertControllerStyle::Alert,
+            mtm,
+        );
+        let actions = [
+            (
+                &request.save,
+                UIAlertActionStyle::Default,
+                CloseChoice::Save,
+            ),
+            (
+                &request.discard,
+                UIAlertActionStyle::Destructive,
+                CloseChoice::Discard,
+            ),
+            (
+                &request.cancel,
+                UIAlertActionStyle::Cancel,
+                CloseChoice::Cancel,
+            ),
+        ];
+        for (title, style, choice) in actions {
+            let action = alert_action(title, style, mtm, move || {
+                trace!(?window, ?choice, "close confirmed");
+                send_message(mtm, WindowCloseConfirmed { window, choice });
+            });
+            alert.addAction(&action);
+            if choice == CloseChoice::Save {
+                alert.setPreferredAction(Some(&action));
+            }
+        }
+        uikit_window
+            .view_controller
+            .presentViewController_animated_completion(&alert, true, None);
+    }
+}
+
+fn alert_action(
+    title: &str,
+    style: UIAlertActionStyle,
+    mtm: MainThreadMarker,
+    handler: impl Fn() + 'static,
+) -> objc2::rc::Retained<UIAlertAction> {
+    let handler = RcBlock::new(move |_action: NonNull<UIAlertAction>| handler());
+    UIAlertAction::actionWithTitle_style_handler(
+        Some(&NSString::from_str(title)),
+        style,
+        Some(&handler),
+        mtm,
+    )
+}
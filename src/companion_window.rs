@@ -0,0 +1,41 @@
+use bevy_ecs::component::Component;
+use bevy_window::{EnabledButtons, Window, WindowLevel, WindowResizeConstraints, WindowResolution};
+
+/// Marks a small, fixed-size companion window, such as a mini-map or a now-playing panel.
+///
+/// Spawn it with [`CompanionWindow::bundle`], which also gives the window an elevated
+/// [`WindowLevel`] and prevents resizing it. On Mac Catalyst, companion windows are never merged
+/// into a tab of another window.
+///
+/// Note that UIKit only orders windows by level within a scene, and each window gets a scene of
+/// its own, so the system still decides how companion windows are ordered relative to the
+/// application's other windows.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CompanionWindow;
+
+impl CompanionWindow {
+    /// A companion window with the given title and fixed size, in logical points.
+    pub fn bundle(title: impl Into<String>, width: f32, height: f32) -> (Window, Self) {
+        let mut resolution = WindowResolution::default();
+        resolution.set(width, height);
+        let window = Window {
+            title: title.into(),
+            resolution,
+            resize_constraints: WindowResizeConstraints {
+                min_width: width,
+                min_height: height,
+                max_width: width,
+                max_height: height,
+            },
+            resizable: false,
+            enabled_buttons: EnabledButtons {
+                minimize: true,
+                maximize: false,
+                close: true,
+            },
+            window_level: WindowLevel::AlwaysOnTop,
+            ..Window::default()
+        };
+        (window, Self)
+    }
+}
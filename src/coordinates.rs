@@ -0,0 +1,123 @@
+use bevy_math::Vec2;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2_core_foundation::{CGFloat, CGPoint};
+use objc2_ui_kit::UICoordinateSpace;
+
+use crate::UIKitWindow;
+
+/// How touch and cursor positions are reported, see
+/// [`UIKitSettings::touch_coordinates`](crate::UIKitSettings::touch_coordinates).
+///
+/// The default matches the other Bevy backends: Logical points, relative to the top-left corner of
+/// the window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TouchCoordinates {
+    /// The unit of the positions.
+    pub units: CoordinateUnits,
+    /// The corner that positions are relative to.
+    pub origin: CoordinateOrigin,
+}
+
+/// The unit of reported positions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CoordinateUnits {
+    /// Logical points, i.e. not multiplied by the window's scale factor.
+    #[default]
+    Logical,
+    /// Physical pixels, i.e. multiplied by the window's scale factor.
+    Physical,
+}
+
+/// The corner that reported positions are relative to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CoordinateOrigin {
+    /// The top-left corner, with the y-axis pointing downwards (like UIKit).
+    #[default]
+    TopLeft,
+    /// The bottom-left corner, with the y-axis pointing upwards.
+    BottomLeft,
+}
+
+impl TouchCoordinates {
+    /// Convert a position in UIKit's coordinate convention (points, relative to the top-left
+    /// corner) to these coordinates.
+    ///
+    /// `size` is the logical size of the area that the position is relative to.
+    pub fn from_uikit(self, position: Vec2, size: Vec2, scale_factor: f32) -> Vec2 {
+        let position = match self.origin {
+            CoordinateOrigin::TopLeft => position,
+            CoordinateOrigin::BottomLeft => Vec2::new(position.x, size.y - position.y),
+        };
+        match self.units {
+            CoordinateUnits::Logical => position,
+            CoordinateUnits::Physical => position * scale_factor,
+        }
+    }
+
+    /// Convert a position in these coordinates to UIKit's coordinate convention (points, relative
+    /// to the top-left corner).
+    ///
+    /// `size` is the logical size of the area that the position is relative to.
+    pub fn to_uikit(self, position: Vec2, size: Vec2, scale_factor: f32) -> Vec2 {
+        let position = match self.units {
+            CoordinateUnits::Logical => position,
+            CoordinateUnits::Physical => position / scale_factor,
+        };
+        match self.origin {
+            CoordinateOrigin::TopLeft => position,
+            CoordinateOrigin::BottomLeft => Vec2::new(position.x, size.y - position.y),
+        }
+    }
+}
+
+/// A coordinate space that a position in a window can be expressed in.
+///
+/// Positions are in UIKit's convention, i.e. logical points relative to the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CoordinateSpace {
+    /// Relative to Bevy's view.
+    ///
+    /// This usually matches the window, but may differ if the view is presented modally (e.g. by
+    /// the document browser).
+    View,
+    /// Relative to the `UIWindow`.
+    Window,
+    /// Relative to the screen that the window is shown on, in the current interface orientation.
+    Screen,
+}
+
+impl UIKitWindow {
+    /// Convert a position between the coordinate spaces of the window.
+    ///
+    /// Returns `None` if Bevy's view is not loaded yet.
+    pub fn convert_position(
+        &self,
+        position: Vec2,
+        from: CoordinateSpace,
+        to: CoordinateSpace,
+    ) -> Option<Vec2> {
+        let from = self.coordinate_space(from)?;
+        let to = self.coordinate_space(to)?;
+        let point = CGPoint {
+            x: position.x as CGFloat,
+            y: position.y as CGFloat,
+        };
+        let point = from.convertPoint_toCoordinateSpace(point, &to);
+        Some(Vec2::new(point.x as f32, point.y as f32))
+    }
+
+    fn coordinate_space(
+        &self,
+        space: CoordinateSpace,
+    ) -> Option<Retained<ProtocolObject<dyn UICoordinateSpace>>> {
+        Some(match space {
+            CoordinateSpace::View => ProtocolObject::from_retained(self.view()?.into_super()),
+            CoordinateSpace::Window => {
+                let window = self.uiwindow.clone().into_super().into_super();
+                ProtocolObject::from_retained(window)
+            }
+            CoordinateSpace::Screen => self.uiwindow.screen().coordinateSpace(),
+        })
+    }
+}
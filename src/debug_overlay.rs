@@ -0,0 +1,149 @@
+use bevy_ecs::{
+    entity::Entity,
+    query::With,
+    resource::Resource,
+    system::{NonSendMarker, Query, Res, ResMut},
+};
+use bevy_window::PrimaryWindow;
+use dispatch2::MainThreadBound;
+use objc2::{rc::Retained, MainThreadMarker, MainThreadOnly};
+use objc2_core_foundation::{CGPoint, CGRect};
+use objc2_foundation::NSString;
+use objc2_ui_kit::{
+    UIAccessibilityIdentification as _, UIColor, UIFont, UILabel, UIScreen, UIViewController,
+    UIWindow, UIWindowLevelAlert, UIWindowScene,
+};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// A native overlay shown above the primary window, useful for showing debug statistics on a
+/// device where no console is available.
+///
+/// The overlay does not receive touches, so it does not interfere with the application.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DebugOverlay {
+    /// Whether the overlay is shown.
+    pub enabled: bool,
+    /// The text to show in the overlay.
+    pub text: String,
+    /// Hide the overlay while the screen is being captured, i.e. recorded, mirrored or sent over
+    /// AirPlay.
+    ///
+    /// Note that the overlay is never included in
+    /// [`CaptureWindowScreenshot`](crate::CaptureWindowScreenshot).
+    pub hide_while_captured: bool,
+    /// The accessibility identifier of the overlay's label, for locating it in UI tests.
+    pub accessibility_identifier: Option<String>,
+}
+
+/// The UIKit state backing [`DebugOverlay`], created lazily when first enabled.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct DebugOverlayWindow {
+    window: Option<MainThreadBound<(Retained<UIWindow>, Retained<UILabel>)>>,
+}
+
+/// Synchronize the [`DebugOverlay`] with its native window.
+pub(crate) fn update_debug_overlay(
+    overlay: Res<DebugOverlay>,
+    mut overlay_window: ResMut<DebugOverlayWindow>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    let Some(uikit_window) = primary_window
+        .single()
+        .ok()
+        .and_then(|entity| uikit_windows.get(entity, mtm))
+    else {
+        // Wait for the primary window to be set up.
+        return;
+    };
+    let scene = uikit_window.scene.as_deref();
+
+    let mut show = overlay.enabled;
+    if overlay.hide_while_captured {
+        let screen = match scene {
+            Some(scene) => scene.screen(),
+            #[allow(deprecated)]
+            None => UIScreen::mainScreen(mtm),
+        };
+        #[allow(deprecated, reason = "the replacement API requires newer OS versions")]
+        let is_captured = screen.isCaptured();
+        show &= !is_captured;
+    }
+
+    if !show {
+        if let Some((window, _)) = overlay_window.window.as_ref().map(|window| window.get(mtm)) {
+            if !window.isHidden() {
+                trace!("hiding debug overlay");
+                window.setHidden(true);
+            }
+        }
+        return;
+    }
+
+    let (window, label) = overlay_window
+        .window
+        .get_or_insert_with(|| MainThreadBound::new(create_overlay_window(scene, mtm), mtm))
+        .get(mtm);
+
+    if window.isHidden() {
+        trace!("showing debug overlay");
+        window.setHidden(false);
+    }
+
+    let identifier = overlay
+        .accessibility_identifier
+        .as_deref()
+        .map(NSString::from_str);
+    if label.accessibilityIdentifier() != identifier {
+        label.setAccessibilityIdentifier(identifier.as_deref());
+    }
+
+    let text = NSString::from_str(&overlay.text);
+    if label.text().as_deref() != Some(&*text) {
+        label.setText(Some(&text));
+        label.sizeToFit();
+        let insets = window.safeAreaInsets();
+        label.setFrame(CGRect {
+            origin: CGPoint {
+                x: insets.left + 8.0,
+                y: insets.top + 8.0,
+            },
+            size: label.frame().size,
+        });
+    }
+}
+
+fn create_overlay_window(
+    scene: Option<&UIWindowScene>,
+    mtm: MainThreadMarker,
+) -> (Retained<UIWindow>, Retained<UILabel>) {
+    trace!("creating debug overlay window");
+    let window = if let Some(scene) = scene {
+        UIWindow::initWithWindowScene(UIWindow::alloc(mtm), scene)
+    } else {
+        UIWindow::new(mtm)
+    };
+    // Above everything else, including alerts.
+    window.setWindowLevel(unsafe { UIWindowLevelAlert } + 1.0);
+    window.setUserInteractionEnabled(false);
+    window.setBackgroundColor(Some(&UIColor::clearColor()));
+
+    // UIKit complains about windows without a root view controller.
+    let view_controller = UIViewController::new(mtm);
+    window.setRootViewController(Some(&view_controller));
+
+    let label = UILabel::new(mtm);
+    label.setNumberOfLines(0);
+    label.setFont(Some(&UIFont::systemFontOfSize(12.0)));
+    label.setTextColor(Some(&UIColor::whiteColor()));
+    label.setBackgroundColor(Some(&UIColor::colorWithWhite_alpha(0.0, 0.5)));
+    if let Some(view) = view_controller.view() {
+        view.addSubview(&label);
+    }
+
+    (window, label)
+}
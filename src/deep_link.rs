@@ -0,0 +1,259 @@
+use bevy_ecs::{
+    component::Component, entity::Entity, message::Message, resource::Resource, world::World,
+};
+use bevy_window::Window;
+use objc2_foundation::{NSUserActivity, NSURL};
+use tracing::trace;
+
+use crate::ActivateWindow;
+
+/// A link that the application was opened with.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeepLink {
+    /// A URL with one of the application's custom schemes, declared with `CFBundleURLTypes` in
+    /// `Info.plist`.
+    Url(String),
+    /// A user activity, such as a Universal Link or a Handoff activity.
+    Activity {
+        /// The type of the activity, such as `NSUserActivityTypeBrowsingWeb` for Universal Links.
+        activity_type: String,
+        /// The web page URL of the activity, if any.
+        webpage_url: Option<String>,
+    },
+}
+
+impl DeepLink {
+    pub(crate) fn from_url(url: &NSURL) -> Option<Self> {
+        Some(Self::Url(url.absoluteString()?.to_string()))
+    }
+
+    pub(crate) fn from_activity(activity: &NSUserActivity) -> Self {
+        Self::Activity {
+            activity_type: activity.activityType().to_string(),
+            webpage_url: activity
+                .webpageURL()
+                .and_then(|url| url.absoluteString())
+                .map(|url| url.to_string()),
+        }
+    }
+}
+
+/// What a [`DeepLinkRoute`] matches.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeepLinkPattern {
+    /// URLs, or web page URLs of activities, starting with the given prefix, such as
+    /// `"myapp://editor/"`.
+    UrlPrefix(String),
+    /// Activities of the given type.
+    ActivityType(String),
+}
+
+impl DeepLinkPattern {
+    fn matches(&self, link: &DeepLink) -> bool {
+        match (self, link) {
+            (Self::UrlPrefix(prefix), DeepLink::Url(url)) => url.starts_with(prefix),
+            (
+                Self::UrlPrefix(prefix),
+                DeepLink::Activity {
+                    webpage_url: Some(url),
+                    ..
+                },
+            ) => url.starts_with(prefix),
+            (Self::ActivityType(expected), DeepLink::Activity { activity_type, .. }) => {
+                expected == activity_type
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Routes a [`DeepLink`] to windows of the given [`WindowKind`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeepLinkRoute {
+    /// The links to route.
+    pub pattern: DeepLinkPattern,
+    /// The kind of window to route the links to.
+    pub kind: String,
+}
+
+/// Decides which window a [`DeepLink`] is opened in.
+///
+/// When a link matches a route, it is delivered to an existing window of the route's
+/// [`WindowKind`], which is activated. If there is no such window, a new one is created with that
+/// kind. Links that match no route, as well as links that a new scene is connected with, are
+/// delivered to the window that received them.
+///
+/// The first matching route is used.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeepLinkRoutes {
+    /// The routes.
+    pub routes: Vec<DeepLinkRoute>,
+}
+
+impl DeepLinkRoutes {
+    /// Add a route.
+    pub fn with_route(mut self, pattern: DeepLinkPattern, kind: impl Into<String>) -> Self {
+        self.routes.push(DeepLinkRoute {
+            pattern,
+            kind: kind.into(),
+        });
+        self
+    }
+
+    /// The kind of window that the link is routed to, if any.
+    pub fn kind_for(&self, link: &DeepLink) -> Option<&str> {
+        self.routes
+            .iter()
+            .find(|route| route.pattern.matches(link))
+            .map(|route| &*route.kind)
+    }
+}
+
+/// The kind of a window, used for routing links with [`DeepLinkRoutes`].
+///
+/// Add this to the entity of the [`Window`]. Windows created by routing get it automatically.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WindowKind(pub String);
+
+/// A link was opened in the application, see [`DeepLinkRoutes`].
+///
+/// File URLs are delivered as [`FileOpened`](crate::FileOpened) instead.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct DeepLinkOpened {
+    /// The window that the link was routed to.
+    ///
+    /// This may be a window that was just spawned, and whose scene is not connected yet.
+    pub window: Option<Entity>,
+    /// The link.
+    pub link: DeepLink,
+}
+
+/// Route the link to a window with [`DeepLinkRoutes`], and emit [`DeepLinkOpened`].
+///
+/// When `connecting` is set, the window's scene is being connected with the link, so the link is
+/// always delivered to that window, which is given the route's kind.
+pub(crate) fn open_deep_link(
+    world: &mut World,
+    window: Option<Entity>,
+    link: DeepLink,
+    connecting: bool,
+) {
+    let kind = world
+        .get_resource::<DeepLinkRoutes>()
+        .and_then(|routes| routes.kind_for(&link))
+        .map(str::to_owned);
+    let target = match kind {
+        None => window,
+        Some(kind) if connecting => {
+            if let Some(window) = window {
+                world.entity_mut(window).insert(WindowKind(kind));
+            }
+            window
+        }
+        Some(kind) => {
+            let existing = world
+                .query::<(Entity, &WindowKind)>()
+                .iter(world)
+                .find(|(_, window_kind)| window_kind.0 == kind)
+                .map(|(entity, _)| entity);
+            match existing {
+                Some(existing) => {
+                    if Some(existing) != window {
+                        world.write_message(ActivateWindow { window: existing });
+                    }
+                    Some(existing)
+                }
+                None => {
+                    trace!(?kind, "creating window for deep link");
+                    Some(world.spawn((Window::default(), WindowKind(kind))).id())
+                }
+            }
+        }
+    };
+    trace!(window = ?target, ?link, "deep link opened");
+    world.write_message(DeepLinkOpened {
+        window: target,
+        link,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use objc2::rc::Retained;
+    use objc2::AllocAnyThread;
+    use objc2_foundation::NSString;
+
+    use super::*;
+
+    fn url(string: &str) -> Retained<NSURL> {
+        NSURL::URLWithString(&NSString::from_str(string)).unwrap()
+    }
+
+    fn activity(activity_type: &str, webpage_url: Option<&str>) -> DeepLink {
+        DeepLink::Activity {
+            activity_type: activity_type.into(),
+            webpage_url: webpage_url.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn from_url() {
+        assert_eq!(
+            DeepLink::from_url(&url("myapp://editor/1?zoom=2")),
+            Some(DeepLink::Url("myapp://editor/1?zoom=2".into()))
+        );
+    }
+
+    #[test]
+    fn from_activity() {
+        let user_activity = NSUserActivity::initWithActivityType(
+            NSUserActivity::alloc(),
+            &NSString::from_str("NSUserActivityTypeBrowsingWeb"),
+        );
+        user_activity.setWebpageURL(Some(&url("https://example.com/editor/1")));
+        assert_eq!(
+            DeepLink::from_activity(&user_activity),
+            activity(
+                "NSUserActivityTypeBrowsingWeb",
+                Some("https://example.com/editor/1")
+            )
+        );
+    }
+
+    #[test]
+    fn routes() {
+        let routes = DeepLinkRoutes::default()
+            .with_route(
+                DeepLinkPattern::UrlPrefix("myapp://editor/".into()),
+                "editor",
+            )
+            .with_route(
+                DeepLinkPattern::UrlPrefix("https://example.com/editor/".into()),
+                "web editor",
+            )
+            .with_route(
+                DeepLinkPattern::ActivityType("com.example.viewer".into()),
+                "viewer",
+            );
+        assert_eq!(
+            routes.kind_for(&DeepLink::Url("myapp://editor/1".into())),
+            Some("editor")
+        );
+        assert_eq!(
+            routes.kind_for(&DeepLink::Url("myapp://settings".into())),
+            None
+        );
+        assert_eq!(
+            routes.kind_for(&activity(
+                "NSUserActivityTypeBrowsingWeb",
+                Some("https://example.com/editor/1")
+            )),
+            Some("web editor")
+        );
+        assert_eq!(
+            routes.kind_for(&activity("com.example.viewer", None)),
+            Some("viewer")
+        );
+        assert_eq!(routes.kind_for(&activity("com.example.other", None)), None);
+    }
+}
@@ -0,0 +1,72 @@
+use std::cell::Cell;
+use std::ptr::NonNull;
+
+use bevy_ecs::{message::Message, resource::Resource};
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::MainThreadMarker;
+use objc2_foundation::{NSNotification, NSNotificationCenter, NSObjectProtocol, NSOperationQueue};
+use objc2_ui_kit::{UITextInputCurrentInputModeDidChangeNotification, UITextInputMode};
+use tracing::trace;
+
+use crate::app::send_message;
+
+/// The user started dictating with the keyboard's microphone button.
+///
+/// Useful for showing a "listening" indicator. The dictated text is delivered to the text input
+/// responder, like typed text.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DictationStarted;
+
+/// Dictation ended, see [`DictationStarted`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DictationEnded;
+
+/// Keeps the input mode notification observer alive.
+#[derive(Resource, Debug)]
+pub(crate) struct DictationObserver(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<Retained<ProtocolObject<dyn NSObjectProtocol>>>,
+);
+
+impl DictationObserver {
+    /// Observe changes to the keyboard's input mode, and forward switches to and from dictation as
+    /// [`DictationStarted`] and [`DictationEnded`].
+    pub(crate) fn new(mtm: MainThreadMarker) -> Self {
+        let dictating = Cell::new(false);
+        let block = RcBlock::new(move |notification: NonNull<NSNotification>| {
+            // SAFETY: The notification is valid for the duration of the block.
+            let notification = unsafe { notification.as_ref() };
+            // The object is the new input mode, and dictation is a pseudo-language.
+            let is_dictation = notification
+                .object()
+                .and_then(|object| object.downcast::<UITextInputMode>().ok())
+                .and_then(|mode| mode.primaryLanguage())
+                .is_some_and(|language| language.to_string() == "dictation");
+            if dictating.replace(is_dictation) == is_dictation {
+                return;
+            }
+            trace!(
+                is_dictation,
+                "UITextInputCurrentInputModeDidChangeNotification"
+            );
+            if is_dictation {
+                send_message(mtm, DictationStarted);
+            } else {
+                send_message(mtm, DictationEnded);
+            }
+        });
+        // SAFETY: The block has the correct signature, and is run on the main queue.
+        let observer = unsafe {
+            NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+                Some(UITextInputCurrentInputModeDidChangeNotification),
+                None,
+                Some(&NSOperationQueue::mainQueue()),
+                &block,
+            )
+        };
+        Self(MainThreadBound::new(observer, mtm))
+    }
+}
@@ -0,0 +1,130 @@
+use bevy_ecs::{
+    entity::{Entity, EntityHashMap},
+    resource::Resource,
+    system::{NonSendMarker, Res, ResMut},
+};
+use bevy_math::UVec2;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2_metal::{MTLCreateSystemDefaultDevice, MTLDevice};
+use objc2_quartz_core::{CALayer, CAMetalLayer};
+use objc2_ui_kit::{UIScreen, UIView};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// The Metal device and screen that each window is presented with.
+///
+/// Render code can use this to pick the matching adapter, and to pace frames according to the
+/// refresh rate of the screen, which may differ when a window is moved to an external display.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct WindowDisplays {
+    windows: EntityHashMap<WindowDisplay>,
+}
+
+impl WindowDisplays {
+    /// Get the display information of a window.
+    pub fn get(&self, window: Entity) -> Option<&WindowDisplay> {
+        self.windows.get(&window)
+    }
+
+    /// Iterate over the display information of all windows registered with UIKit.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &WindowDisplay)> + '_ {
+        self.windows
+            .iter()
+            .map(|(entity, display)| (*entity, display))
+    }
+}
+
+/// The Metal device and screen that a window is presented with, see [`WindowDisplays`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowDisplay {
+    /// The device that the window's Metal layer renders with.
+    ///
+    /// If the renderer hasn't yet created the layer (or set its device), this is the system's
+    /// default device, which is the only one on iOS.
+    pub device: Option<MetalDevice>,
+    /// Whether the window is on an external display, such as when connected to a monitor.
+    pub external: bool,
+    /// The maximum refresh rate of the screen.
+    pub maximum_frames_per_second: u32,
+    /// The resolution of the screen in physical pixels, in portrait orientation.
+    pub native_resolution: UVec2,
+}
+
+/// Identifies an `MTLDevice`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MetalDevice {
+    /// The name of the device, such as `"Apple A15 GPU"`.
+    pub name: String,
+    /// The device's `registryID`, which is unique across processes.
+    pub registry_id: u64,
+}
+
+impl MetalDevice {
+    fn new(device: &ProtocolObject<dyn MTLDevice>) -> Self {
+        Self {
+            name: device.name().to_string(),
+            registry_id: device.registryID(),
+        }
+    }
+}
+
+/// Keep [`WindowDisplays`] up to date with the windows' layers and screens.
+pub fn update_window_displays(
+    uikit_windows: Res<UIKitWindows>,
+    mut displays: ResMut<WindowDisplays>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    let main_screen = UIScreen::screens(mtm).firstObject();
+
+    // Only mutate the resource when something changed, to not trigger change detection.
+    if displays
+        .windows
+        .keys()
+        .any(|entity| !uikit_windows.is_initialized(*entity))
+    {
+        displays
+            .windows
+            .retain(|entity, _| uikit_windows.is_initialized(*entity));
+    }
+
+    for (entity, uikit_window) in uikit_windows.iter(mtm) {
+        let screen = uikit_window.uiwindow.screen();
+        let device = uikit_window
+            .view()
+            .and_then(|view| metal_layer(&view))
+            .and_then(|layer| layer.device())
+            .or_else(|| MTLCreateSystemDefaultDevice());
+        let native_bounds = screen.nativeBounds();
+        let display = WindowDisplay {
+            device: device.as_deref().map(MetalDevice::new),
+            external: main_screen.as_ref() != Some(&screen),
+            maximum_frames_per_second: screen.maximumFramesPerSecond() as u32,
+            native_resolution: UVec2::new(
+                native_bounds.size.width as u32,
+                native_bounds.size.height as u32,
+            ),
+        };
+        if displays.get(entity) != Some(&display) {
+            trace!(?entity, ?display, "window display changed");
+            displays.windows.insert(entity, display);
+        }
+    }
+}
+
+/// Find the Metal layer that the renderer presents to.
+///
+/// The renderer may either use the view's own layer, or add a sublayer to it.
+pub(crate) fn metal_layer(view: &UIView) -> Option<Retained<CAMetalLayer>> {
+    let layer = view.layer();
+    if let Some(layer) = layer.downcast_ref::<CAMetalLayer>() {
+        return Some(layer.retain());
+    }
+    // SAFETY: The sublayers are not mutated while iterating.
+    let sublayers = unsafe { layer.sublayers() }?;
+    sublayers
+        .iter()
+        .find_map(|sublayer: Retained<CALayer>| sublayer.downcast::<CAMetalLayer>().ok())
+}
@@ -0,0 +1,371 @@
+use std::ptr::NonNull;
+
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    query::{Has, With},
+    resource::Resource,
+    system::{NonSendMarker, Query, Res, ResMut},
+};
+use bevy_tasks::tick_global_task_pools_on_main_thread;
+use bevy_window::Window;
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, ProtocolObject};
+use objc2::{available, define_class, msg_send, sel, MainThreadMarker, MainThreadOnly};
+use objc2_foundation::{
+    NSNotification, NSNotificationCenter, NSNotificationName, NSObject, NSObjectProtocol,
+    NSOperationQueue, NSRunLoop, NSRunLoopCommonModes,
+};
+use objc2_quartz_core::{CADisplayLink, CAFrameRateRange};
+use objc2_ui_kit::{
+    UIApplicationDidBecomeActiveNotification, UIApplicationWillResignActiveNotification,
+};
+use tracing::trace;
+
+use crate::app::{guard_callback, update_app_for_frame, with_world};
+use crate::{main_thread_marker, RenderPaused, SuggestedQuality, UIKitSettings};
+
+/// The frame rates to update the application at, see [`DisplayLink::frame_rate_range`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameRateRange {
+    /// The lowest acceptable frame rate.
+    pub minimum: f32,
+    /// The highest frame rate to update at.
+    pub maximum: f32,
+    /// The frame rate to prefer, or `None` to let the system decide.
+    pub preferred: Option<f32>,
+}
+
+impl FrameRateRange {
+    /// Update at exactly the given frame rate, if the display supports it.
+    pub fn fixed(frames_per_second: f32) -> Self {
+        Self {
+            minimum: frames_per_second,
+            maximum: frames_per_second,
+            preferred: Some(frames_per_second),
+        }
+    }
+
+    /// Lower the range such that it doesn't exceed the given frame rate.
+    ///
+    /// `None` (the display's native rate) becomes exactly the given frame rate.
+    fn limited_to(range: Option<Self>, maximum: f32) -> Self {
+        let Some(range) = range else {
+            return Self::fixed(maximum);
+        };
+        let maximum = range.maximum.min(maximum);
+        Self {
+            minimum: range.minimum.min(maximum),
+            maximum,
+            preferred: range.preferred.map(|preferred| preferred.min(maximum)),
+        }
+    }
+}
+
+/// Update the application in sync with the display's refresh, using a `CADisplayLink`.
+///
+/// Without this resource, the application is only updated in response to events from UIKit. With
+/// it, the application is additionally updated once per frame, unless [`paused`](Self::paused),
+/// independently of Bevy's `UpdateMode`. Change the resource at runtime to pause the updates (e.g.
+/// in a static menu) or to change the frame rate (e.g. to 10 fps in a paused menu and 120 fps in
+/// gameplay), and remove it to stop updating per frame.
+///
+/// While running, this also ticks `bevy_tasks`' main thread executors every frame, such that tasks
+/// woken from other threads make progress without waiting for an event from UIKit.
+///
+/// With [`UIKitSettings::thermal_quality_scaling`], the frame rate range is lowered to
+/// [`SuggestedQuality::maximum_frames_per_second`] under thermal pressure, and restored once the
+/// device cools down. Similarly, [`UIKitSettings::inactive_updates`] is applied while the
+/// application is inactive, such as while a system alert covers it. Changes made to the resource
+/// in the meantime are kept, and become the settings that are restored.
+///
+/// The display link runs in the run loop's common modes, so without a limit, it keeps updating
+/// while UIKit is tracking touches or presenting system alerts.
+///
+/// [`UIKitSettings::thermal_quality_scaling`]: crate::UIKitSettings::thermal_quality_scaling
+/// [`UIKitSettings::inactive_updates`]: crate::UIKitSettings::inactive_updates
+///
+/// Frame rates above 60 fps on iPhone require `CADisableMinimumFrameDurationOnPhone` to be set in
+/// the application's `Info.plist`.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct DisplayLink {
+    /// Whether per-frame updates are paused.
+    pub paused: bool,
+    /// The frame rates to update at, or `None` to update at the display's native rate.
+    ///
+    /// Requires iOS 15.0, and only the maximum is used on older versions.
+    pub frame_rate_range: Option<FrameRateRange>,
+}
+
+impl DisplayLink {
+    /// Pause the per-frame updates.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resume the per-frame updates.
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+}
+
+/// The native display link while a [`DisplayLink`] resource exists.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct DisplayLinkHandle(Option<MainThreadBound<Retained<CADisplayLink>>>);
+
+define_class!(
+    /// The target of the display link, which updates the application every frame.
+    ///
+    /// The display link retains its target.
+    #[unsafe(super(NSObject))]
+    #[name = "BevyDisplayLinkTarget"]
+    #[thread_kind = MainThreadOnly]
+    #[derive(Debug)]
+    struct DisplayLinkTarget;
+
+    unsafe impl NSObjectProtocol for DisplayLinkTarget {}
+
+    impl DisplayLinkTarget {
+        #[unsafe(method(step:))]
+        fn step(&self, _display_link: &CADisplayLink) {
+            guard_callback("display link", || {
+                update_app_for_frame(self.mtm());
+                // Waking a main thread task from another thread doesn't wake the run loop, so
+                // make sure that the executors make progress at least once per frame.
+                tick_global_task_pools_on_main_thread();
+            });
+        }
+    }
+);
+
+/// What happens to the per-frame updates of the [`DisplayLink`] while the application is inactive,
+/// see [`UIKitSettings::inactive_updates`](crate::UIKitSettings::inactive_updates).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum InactiveUpdates {
+    /// Keep updating as usual.
+    #[default]
+    Continue,
+    /// Limit the frame rate to the given number of frames per second.
+    Throttle(f32),
+    /// Pause the updates.
+    Pause,
+}
+
+/// Whether the application is active, i.e. in the foreground and not covered by a system alert,
+/// Control Center, an incoming call or similar.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ApplicationActivity {
+    active: bool,
+}
+
+/// Keeps the application state notification observers alive.
+#[derive(Resource, Debug)]
+pub(crate) struct ApplicationActivityObserver(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<[Retained<ProtocolObject<dyn NSObjectProtocol>>; 2]>,
+);
+
+/// Observe the application becoming active and resigning active.
+///
+/// `UIApplication` isn't available before launching, so this starts out as active; the application
+/// becomes active right after launching anyway.
+pub(crate) fn observe_application_activity(
+    mtm: MainThreadMarker,
+) -> (ApplicationActivity, ApplicationActivityObserver) {
+    let observe = |name: &NSNotificationName, active: bool| {
+        let block = RcBlock::new(move |_notification: NonNull<NSNotification>| {
+            trace!(active, "application activity changed");
+            with_world(mtm, move |world| {
+                world.insert_resource(ApplicationActivity { active });
+            });
+        });
+        // SAFETY: The block has the correct signature, and is run on the main queue.
+        unsafe {
+            NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+                Some(name),
+                None,
+                Some(&NSOperationQueue::mainQueue()),
+                &block,
+            )
+        }
+    };
+    // SAFETY: The notification names are immutable constants.
+    let (did_become_active, will_resign_active) = unsafe {
+        (
+            UIApplicationDidBecomeActiveNotification,
+            UIApplicationWillResignActiveNotification,
+        )
+    };
+    let observers = [
+        observe(did_become_active, true),
+        observe(will_resign_active, false),
+    ];
+    (
+        ApplicationActivity { active: true },
+        ApplicationActivityObserver(MainThreadBound::new(observers, mtm)),
+    )
+}
+
+/// The [`DisplayLink`] as configured by the application, while [`limit_display_link`] limits it.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct DisplayLinkLimits {
+    /// The settings chosen by the application.
+    requested: DisplayLink,
+    /// The settings last written by [`limit_display_link`], to detect changes by the application.
+    applied: Option<DisplayLink>,
+}
+
+/// Lower the [`DisplayLink`]'s frame rate range to the [`SuggestedQuality`], and apply
+/// [`UIKitSettings::inactive_updates`](crate::UIKitSettings::inactive_updates) while the
+/// application is inactive. Updates are paused while every window has [`RenderPaused`]. The
+/// settings chosen by the application are restored once the limits are lifted.
+pub(crate) fn limit_display_link(
+    display_link: Option<ResMut<DisplayLink>>,
+    suggested: Res<SuggestedQuality>,
+    settings: Res<UIKitSettings>,
+    activity: Res<ApplicationActivity>,
+    windows: Query<Has<RenderPaused>, With<Window>>,
+    mut limits: ResMut<DisplayLinkLimits>,
+) {
+    let Some(mut display_link) = display_link else {
+        limits.applied = None;
+        return;
+    };
+    if limits.applied != Some(*display_link) {
+        // Inserted or changed by the application.
+        limits.requested = *display_link;
+    }
+
+    let mut limited = limits.requested;
+    let mut maximum = suggested
+        .maximum_frames_per_second
+        .map(|maximum| maximum as f32);
+    if !activity.active {
+        match settings.inactive_updates {
+            InactiveUpdates::Continue => {}
+            InactiveUpdates::Throttle(frames_per_second) => {
+                maximum = Some(
+                    maximum.map_or(frames_per_second, |maximum| maximum.min(frames_per_second)),
+                );
+            }
+            InactiveUpdates::Pause => limited.paused = true,
+        }
+    }
+    if !windows.is_empty() && windows.iter().all(|paused| paused) {
+        // Nothing would be presented anyway.
+        limited.paused = true;
+    }
+    if let Some(maximum) = maximum {
+        limited.frame_rate_range = Some(FrameRateRange::limited_to(
+            limited.frame_rate_range,
+            maximum,
+        ));
+    }
+    limits.applied = Some(limited);
+    if display_link.set_if_neq(limited) {
+        trace!(requested = ?limits.requested, ?limited, "limiting display link");
+    }
+}
+
+/// Create, configure and remove the native display link to match [`DisplayLink`].
+pub fn update_display_link(
+    display_link: Option<Res<DisplayLink>>,
+    mut handle: ResMut<DisplayLinkHandle>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    let Some(display_link) = display_link else {
+        if let Some(native) = handle.0.take() {
+            trace!("removing display link");
+            native.get(mtm).invalidate();
+        }
+        return;
+    };
+    if !display_link.is_changed() && handle.0.is_some() {
+        return;
+    }
+
+    let native = handle.0.get_or_insert_with(|| {
+        trace!("creating display link");
+        let target: Retained<DisplayLinkTarget> =
+            unsafe { msg_send![DisplayLinkTarget::alloc(mtm), init] };
+        let target: &AnyObject = &target;
+        // SAFETY: The target has a method with the given selector, which takes the display link.
+        let native = unsafe { CADisplayLink::displayLinkWithTarget_selector(target, sel!(step:)) };
+        // SAFETY: Added to the main run loop, which is the one we're on. Common modes are used to
+        // keep updating while UIKit is tracking touches.
+        unsafe { native.addToRunLoop_forMode(&NSRunLoop::mainRunLoop(), NSRunLoopCommonModes) };
+        MainThreadBound::new(native, mtm)
+    });
+    let native = native.get(mtm);
+
+    trace!(?display_link, "configuring display link");
+    native.setPaused(display_link.paused);
+    let range = display_link.frame_rate_range;
+    if available!(ios = 15.0, tvos = 15.0, visionos = 1.0, ..) {
+        native.setPreferredFrameRateRange(range.map_or(
+            CAFrameRateRange {
+                minimum: 0.0,
+                maximum: 0.0,
+                preferred: 0.0,
+            },
+            |range| CAFrameRateRange {
+                minimum: range.minimum,
+                maximum: range.maximum,
+                preferred: range.preferred.unwrap_or(0.0),
+            },
+        ));
+    } else {
+        #[allow(deprecated, reason = "the replacement API requires newer OS versions")]
+        native.setPreferredFramesPerSecond(range.map_or(0, |range| range.maximum as _));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_native_rate() {
+        assert_eq!(
+            FrameRateRange::limited_to(None, 30.0),
+            FrameRateRange::fixed(30.0)
+        );
+    }
+
+    #[test]
+    fn limit_range() {
+        let range = FrameRateRange {
+            minimum: 30.0,
+            maximum: 120.0,
+            preferred: Some(120.0),
+        };
+        assert_eq!(
+            FrameRateRange::limited_to(Some(range), 60.0),
+            FrameRateRange {
+                minimum: 30.0,
+                maximum: 60.0,
+                preferred: Some(60.0),
+            }
+        );
+        assert_eq!(
+            FrameRateRange::limited_to(Some(range), 20.0),
+            FrameRateRange {
+                minimum: 20.0,
+                maximum: 20.0,
+                preferred: Some(20.0),
+            }
+        );
+    }
+
+    #[test]
+    fn limit_above_range() {
+        let range = FrameRateRange {
+            minimum: 10.0,
+            maximum: 30.0,
+            preferred: None,
+        };
+        assert_eq!(FrameRateRange::limited_to(Some(range), 60.0), range);
+    }
+}
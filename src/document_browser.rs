@@ -0,0 +1,222 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use std::path::PathBuf;
+
+use bevy_ecs::{
+    entity::Entity,
+    message::{Message, MessageReader},
+    system::{Commands, NonSendMarker, Res},
+};
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{
+    define_class, msg_send, DefinedClass as _, MainThreadMarker, MainThreadOnly, Message as _,
+};
+use objc2_foundation::{NSArray, NSError, NSObject, NSObjectProtocol, NSString, NSURL};
+use objc2_ui_kit::{
+    UIDocumentBrowserViewController, UIDocumentBrowserViewControllerDelegate,
+    UIModalPresentationStyle,
+};
+use tracing::{trace, warn};
+
+use crate::app::with_world;
+use crate::view::ViewController;
+use crate::{main_thread_marker, SecurityScopedUrl, UIKitWindows};
+
+/// Settings for document-based applications, see
+/// [`UIKitSettings::document_browser`](crate::UIKitSettings::document_browser).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentBrowserSettings {
+    /// The Uniform Type Identifiers of the documents that can be opened.
+    ///
+    /// If empty, the types declared with `CFBundleDocumentTypes` in `Info.plist` are used.
+    pub content_types: Vec<String>,
+}
+
+/// The user picked a document in the document browser, and Bevy's view was presented.
+///
+/// Access to the document is granted for as long as the window's
+/// [`SecurityScopedUrl`] component is present, which is inserted alongside this message.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct DocumentOpened {
+    /// The window whose document browser opened the document.
+    pub window: Entity,
+    /// The path to the document.
+    pub path: PathBuf,
+}
+
+/// Close the currently open document in a window, and return to the document browser.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CloseDocument {
+    /// The window whose document to close.
+    pub window: Entity,
+}
+
+/// The document browser shown as the root of a window.
+#[derive(Debug)]
+pub(crate) struct DocumentBrowser {
+    browser: Retained<UIDocumentBrowserViewController>,
+    // The browser only holds a weak reference to its delegate.
+    _delegate: Retained<DocumentBrowserDelegate>,
+}
+
+impl DocumentBrowser {
+    pub(crate) fn new(
+        settings: &DocumentBrowserSettings,
+        entity: Entity,
+        view_controller: &ViewController,
+        mtm: MainThreadMarker,
+    ) -> Self {
+        trace!(?settings, "creating document browser");
+        let content_types = (!settings.content_types.is_empty()).then(|| {
+            let content_types: Vec<_> = settings
+                .content_types
+                .iter()
+                .map(|content_type| NSString::from_str(content_type))
+                .collect();
+            NSArray::from_retained_slice(&content_types)
+        });
+        #[allow(deprecated, reason = "the replacement API requires newer OS versions")]
+        let browser = UIDocumentBrowserViewController::initForOpeningFilesWithContentTypes(
+            UIDocumentBrowserViewController::alloc(mtm),
+            content_types.as_deref(),
+        );
+        browser.setAllowsDocumentCreation(false);
+        browser.setAllowsPickingMultipleItems(false);
+
+        let delegate = DocumentBrowserDelegate::new(mtm, entity, view_controller);
+        browser.setDelegate(Some(ProtocolObject::from_ref(&*delegate)));
+
+        Self {
+            browser,
+            _delegate: delegate,
+        }
+    }
+
+    pub(crate) fn view_controller(&self) -> &UIDocumentBrowserViewController {
+        &self.browser
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DelegateIvars {
+    entity: Entity,
+    view_controller: Retained<ViewController>,
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "BevyDocumentBrowserDelegate"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = DelegateIvars]
+    #[derive(Debug)]
+    pub(crate) struct DocumentBrowserDelegate;
+
+    unsafe impl NSObjectProtocol for DocumentBrowserDelegate {}
+
+    unsafe impl UIDocumentBrowserViewControllerDelegate for DocumentBrowserDelegate {
+        #[unsafe(method(documentBrowser:didPickDocumentsAtURLs:))]
+        fn documentBrowser_didPickDocumentsAtURLs(
+            &self,
+            controller: &UIDocumentBrowserViewController,
+            document_urls: &NSArray<NSURL>,
+        ) {
+            trace!(?document_urls, "documentBrowser:didPickDocumentsAtURLs:");
+            // We disallow picking multiple items.
+            if let Some(url) = document_urls.firstObject() {
+                self.open_document(controller, &url);
+            }
+        }
+
+        #[unsafe(method(documentBrowser:didImportDocumentAtURL:toDestinationURL:))]
+        fn documentBrowser_didImportDocumentAtURL_toDestinationURL(
+            &self,
+            controller: &UIDocumentBrowserViewController,
+            source_url: &NSURL,
+            destination_url: &NSURL,
+        ) {
+            trace!(
+                ?source_url,
+                ?destination_url,
+                "documentBrowser:didImportDocumentAtURL:toDestinationURL:"
+            );
+            self.open_document(controller, destination_url);
+        }
+
+        #[unsafe(method(documentBrowser:failedToImportDocumentAtURL:error:))]
+        fn documentBrowser_failedToImportDocumentAtURL_error(
+            &self,
+            _controller: &UIDocumentBrowserViewController,
+            document_url: &NSURL,
+            error: Option<&NSError>,
+        ) {
+            warn!(?document_url, ?error, "failed importing document");
+        }
+    }
+);
+
+impl DocumentBrowserDelegate {
+    fn new(
+        mtm: MainThreadMarker,
+        entity: Entity,
+        view_controller: &ViewController,
+    ) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(DelegateIvars {
+            entity,
+            view_controller: view_controller.retain(),
+        });
+        unsafe { msg_send![super(this), init] }
+    }
+
+    fn open_document(&self, browser: &UIDocumentBrowserViewController, url: &NSURL) {
+        let security_scoped_url = SecurityScopedUrl::new(url);
+        let Some(path) = security_scoped_url.path() else {
+            warn!(?url, "tried to open document that is not a file");
+            return;
+        };
+
+        // Show Bevy's view on top of the browser.
+        let view_controller = &self.ivars().view_controller;
+        if view_controller.presentingViewController().is_none() {
+            view_controller.setModalPresentationStyle(UIModalPresentationStyle::FullScreen);
+            browser.presentViewController_animated_completion(view_controller, true, None);
+        }
+
+        let window = self.ivars().entity;
+        with_world(self.mtm(), move |world| {
+            let Ok(mut entity) = world.get_entity_mut(window) else {
+                return;
+            };
+            entity.insert(security_scoped_url);
+            world.write_message(DocumentOpened { window, path });
+        });
+    }
+}
+
+/// Return to the document browser for windows requested with [`CloseDocument`].
+pub fn close_documents(
+    mut requests: MessageReader<CloseDocument>,
+    mut commands: Commands,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for CloseDocument { window } in requests.read().copied() {
+        let Some(uikit_window) = uikit_windows.get(window, mtm) else {
+            continue;
+        };
+        if uikit_window.document_browser.is_none() {
+            warn!(
+                ?window,
+                "tried to close document in window without a document browser"
+            );
+            continue;
+        }
+        trace!(?window, "closing document");
+        uikit_window
+            .view_controller
+            .dismissViewControllerAnimated_completion(true, None);
+        if let Ok(mut entity) = commands.get_entity(window) {
+            entity.remove::<SecurityScopedUrl>();
+        }
+    }
+}
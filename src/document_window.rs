@@ -0,0 +1,99 @@
+use std::path::PathBuf;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use objc2::{available, msg_send, rc::Retained, runtime::AnyObject};
+use objc2_foundation::{ns_string, NSURL};
+use objc2_ui_kit::UIWindowScene;
+use tracing::{trace, warn};
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Native document affordances for the titlebar of a window on Mac Catalyst.
+///
+/// The represented file is shown as a proxy icon next to the title, which can be dragged, or
+/// clicked with Command to show the file's location. Add this to the entity of the
+/// [`Window`](bevy_window::Window).
+///
+/// Has no effect on other platforms. Requires Mac Catalyst 15.0.
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DocumentWindow {
+    /// The file that the window represents.
+    pub represented_file: Option<PathBuf>,
+    /// Whether the document has unsaved changes.
+    ///
+    /// UIKit does not expose the edited state of the underlying `NSWindow`, so this is shown as
+    /// an "Edited" subtitle instead, similar to how macOS shows it in unified titlebars.
+    pub edited: bool,
+}
+
+/// Propagate changes to [`DocumentWindow`] to the titlebar of the scene.
+pub fn update_document_windows(
+    document_windows: Query<(Entity, Ref<DocumentWindow>)>,
+    mut removed: RemovedComponents<DocumentWindow>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    if !cfg!(target_abi = "macabi") || !available!(ios = 15.0, ..) {
+        return;
+    }
+
+    for (entity, document_window) in &document_windows {
+        // Also update when the window was only just registered with UIKit.
+        if !document_window.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(scene) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.scene.as_deref())
+        {
+            trace!(?entity, ?document_window, "setting document window");
+            let url = document_window
+                .represented_file
+                .as_deref()
+                .and_then(|path| {
+                    let url = NSURL::from_file_path(path);
+                    if url.is_none() {
+                        warn!(?path, "represented file must be an absolute path");
+                    }
+                    url
+                });
+            set_document(scene, url.as_deref(), document_window.edited);
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(scene) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.scene.as_deref())
+        {
+            set_document(scene, None, false);
+        }
+    }
+}
+
+fn set_document(scene: &UIWindowScene, url: Option<&NSURL>, edited: bool) {
+    // `UITitlebar` is only available on Mac Catalyst, and not exposed by `objc2-ui-kit`.
+    // SAFETY: `titlebar` is a nullable `UITitlebar` property on `UIWindowScene`.
+    let titlebar: Option<Retained<AnyObject>> = unsafe { msg_send![scene, titlebar] };
+    if let Some(titlebar) = titlebar {
+        // SAFETY: `representedURL` is a nullable `NSURL` property on `UITitlebar`.
+        let _: () = unsafe { msg_send![&titlebar, setRepresentedURL: url] };
+    }
+
+    let subtitle = if edited {
+        ns_string!("Edited")
+    } else {
+        ns_string!("")
+    };
+    if &*scene.subtitle() != subtitle {
+        scene.setSubtitle(subtitle);
+    }
+}
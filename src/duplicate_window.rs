@@ -0,0 +1,53 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    message::{Message, MessageReader},
+    system::{Commands, Query},
+};
+use bevy_window::Window;
+use tracing::{trace, warn};
+
+use crate::PropertyListValue;
+
+/// Open a copy of an existing window in a new scene, like the "Open in New Window" action that
+/// iPadOS applications offer for documents.
+///
+/// Spawns a new entity with a clone of the window's [`Window`] component, and the given payload as
+/// a [`WindowPayload`]. Use the payload to decide what to show in the new window, such as which
+/// document or level to open.
+///
+/// This requires multiple window support, so it does nothing on single-window iOS.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct DuplicateWindow {
+    /// The window to copy.
+    pub window: Entity,
+    /// Application-specific data describing the content of the new window.
+    pub payload: Option<PropertyListValue>,
+}
+
+/// Application-specific data describing the content of a window, such as set by
+/// [`DuplicateWindow`].
+///
+/// This is included in the user activity that requests the window's scene, so it must be inserted
+/// together with the [`Window`] component for it to take effect.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct WindowPayload(pub PropertyListValue);
+
+/// Spawn the windows requested with [`DuplicateWindow`].
+pub fn duplicate_windows(
+    mut requests: MessageReader<DuplicateWindow>,
+    windows: Query<&Window>,
+    mut commands: Commands,
+) {
+    for DuplicateWindow { window, payload } in requests.read() {
+        let Ok(source) = windows.get(*window) else {
+            warn!(?window, "tried to duplicate entity without a Window");
+            continue;
+        };
+        let mut entity = commands.spawn(source.clone());
+        if let Some(payload) = payload {
+            entity.insert(WindowPayload(payload.clone()));
+        }
+        trace!(?window, new_window = ?entity.id(), "duplicating window");
+    }
+}
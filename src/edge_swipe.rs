@@ -0,0 +1,223 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    message::Message,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_input::touch::TouchPhase;
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{define_class, msg_send, sel, DefinedClass as _, MainThreadMarker, MainThreadOnly};
+use objc2_foundation::{NSObject, NSObjectProtocol};
+use objc2_ui_kit::{
+    UIGestureRecognizerState, UIRectEdge, UIScreenEdgePanGestureRecognizer, UIView,
+};
+use tracing::trace;
+
+use crate::app::send_input_message;
+use crate::{main_thread_marker, GestureTouches, UIKitSettings, UIKitWindows};
+
+/// An edge of the screen, see [`ScreenEdgeSwipes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SwipeEdge {
+    /// The top edge.
+    Top,
+    /// The left edge.
+    Left,
+    /// The bottom edge.
+    Bottom,
+    /// The right edge.
+    Right,
+}
+
+impl SwipeEdge {
+    fn to_uikit(self) -> UIRectEdge {
+        match self {
+            Self::Top => UIRectEdge::Top,
+            Self::Left => UIRectEdge::Left,
+            Self::Bottom => UIRectEdge::Bottom,
+            Self::Right => UIRectEdge::Right,
+        }
+    }
+}
+
+/// Recognize swipes in from the edges of the screen, and emit [`ScreenEdgeSwiped`] messages.
+///
+/// Useful for implementing slide-in panels. Swipes from edges that the system also uses (such as
+/// the bottom edge for going home) are only recognized if they're deferred with
+/// [`Window::preferred_screen_edges_deferring_system_gestures`]. Add this to the entity of the
+/// [`Window`](bevy_window::Window).
+///
+/// [`Window::preferred_screen_edges_deferring_system_gestures`]: bevy_window::Window::preferred_screen_edges_deferring_system_gestures
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScreenEdgeSwipes {
+    /// The edges to recognize swipes from.
+    pub edges: Vec<SwipeEdge>,
+}
+
+/// A swipe in from an edge of the screen, see [`ScreenEdgeSwipes`].
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct ScreenEdgeSwiped {
+    /// The window that was swiped in.
+    pub window: Entity,
+    /// The edge that the swipe started from.
+    pub edge: SwipeEdge,
+    /// Whether the swipe started, moved or ended.
+    pub phase: TouchPhase,
+    /// How far the swipe has moved away from the edge, in logical points.
+    pub progress: f32,
+}
+
+/// The screen edge pan gesture recognizers of a view.
+#[derive(Debug)]
+pub(crate) struct EdgeSwipeRecognizers {
+    _handler: Retained<EdgeSwipeHandler>,
+    recognizers: Vec<Retained<UIScreenEdgePanGestureRecognizer>>,
+}
+
+impl EdgeSwipeRecognizers {
+    fn new(
+        view: &UIView,
+        window: Entity,
+        edges: &[SwipeEdge],
+        touches: GestureTouches,
+        mtm: MainThreadMarker,
+    ) -> Self {
+        let handler = EdgeSwipeHandler::new(window, mtm);
+        let target: &AnyObject = &handler;
+        let recognizers = edges
+            .iter()
+            .map(|edge| {
+                // SAFETY: The target has a method with the given selector, which takes the
+                // recognizer.
+                let recognizer = unsafe {
+                    UIScreenEdgePanGestureRecognizer::initWithTarget_action(
+                        UIScreenEdgePanGestureRecognizer::alloc(mtm),
+                        Some(target),
+                        Some(sel!(handleEdgePan:)),
+                    )
+                };
+                recognizer.setEdges(edge.to_uikit());
+                touches.apply(&recognizer);
+                view.addGestureRecognizer(&recognizer);
+                recognizer
+            })
+            .collect();
+        Self {
+            _handler: handler,
+            recognizers,
+        }
+    }
+
+    fn remove(&self, view: &UIView) {
+        for recognizer in &self.recognizers {
+            view.removeGestureRecognizer(recognizer);
+        }
+    }
+}
+
+/// Propagate changes to [`ScreenEdgeSwipes`] to the native view.
+pub fn update_screen_edge_swipes(
+    swipes: Query<(Entity, Ref<ScreenEdgeSwipes>)>,
+    mut removed: RemovedComponents<ScreenEdgeSwipes>,
+    uikit_windows: Res<UIKitWindows>,
+    settings: Res<UIKitSettings>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, swipes) in &swipes {
+        // Also update when the window was only just registered with UIKit.
+        if !swipes.is_changed() && !uikit_windows.is_changed() && !settings.is_changed() {
+            continue;
+        }
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            trace!(?entity, edges = ?swipes.edges, "setting screen edge swipes");
+            let mut recognizers = view.ivars().edge_swipes.borrow_mut();
+            if let Some(recognizers) = recognizers.take() {
+                recognizers.remove(&view);
+            }
+            *recognizers = Some(EdgeSwipeRecognizers::new(
+                &view,
+                entity,
+                &swipes.edges,
+                settings.gestures.edge_swipe_touches,
+                mtm,
+            ));
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            if let Some(recognizers) = view.ivars().edge_swipes.borrow_mut().take() {
+                recognizers.remove(&view);
+            }
+        }
+    }
+}
+
+define_class!(
+    /// The target of the screen edge pan gesture recognizers.
+    ///
+    /// Gesture recognizers do not retain their target.
+    #[unsafe(super(NSObject))]
+    #[name = "BevyEdgeSwipeHandler"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = Entity]
+    #[derive(Debug)]
+    pub(crate) struct EdgeSwipeHandler;
+
+    unsafe impl NSObjectProtocol for EdgeSwipeHandler {}
+
+    impl EdgeSwipeHandler {
+        #[unsafe(method(handleEdgePan:))]
+        fn handleEdgePan(&self, recognizer: &UIScreenEdgePanGestureRecognizer) {
+            let phase = match recognizer.state() {
+                UIGestureRecognizerState::Began => TouchPhase::Started,
+                UIGestureRecognizerState::Changed => TouchPhase::Moved,
+                UIGestureRecognizerState::Ended => TouchPhase::Ended,
+                UIGestureRecognizerState::Cancelled | UIGestureRecognizerState::Failed => {
+                    TouchPhase::Canceled
+                }
+                _ => return,
+            };
+            let edges = recognizer.edges();
+            let translation = recognizer.translationInView(recognizer.view().as_deref());
+            let (edge, progress) = if edges == UIRectEdge::Top {
+                (SwipeEdge::Top, translation.y)
+            } else if edges == UIRectEdge::Left {
+                (SwipeEdge::Left, translation.x)
+            } else if edges == UIRectEdge::Bottom {
+                (SwipeEdge::Bottom, -translation.y)
+            } else {
+                (SwipeEdge::Right, -translation.x)
+            };
+            trace!(?edge, ?phase, progress, "screen edge swipe");
+            send_input_message(
+                self.mtm(),
+                ScreenEdgeSwiped {
+                    window: *self.ivars(),
+                    edge,
+                    phase,
+                    progress: progress as f32,
+                },
+            );
+        }
+    }
+);
+
+impl EdgeSwipeHandler {
+    fn new(window: Entity, mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(window);
+        unsafe { msg_send![super(this), init] }
+    }
+}
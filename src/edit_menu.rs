@@ -0,0 +1,129 @@
+use bevy_ecs::{
+    entity::Entity,
+    message::{Message, MessageReader},
+    system::{NonSendMarker, Res},
+};
+use bevy_math::Vec2;
+use objc2::runtime::{ProtocolObject, Sel};
+use objc2::{available, sel, DefinedClass as _, MainThreadOnly as _};
+use objc2_core_foundation::{CGFloat, CGPoint};
+use objc2_ui_kit::{UIEditMenuConfiguration, UIEditMenuInteraction, UIResponder, UIView};
+use tracing::{trace, warn};
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// A standard editing action in the system edit menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditAction {
+    /// Cut the selection to the pasteboard.
+    Cut,
+    /// Copy the selection to the pasteboard.
+    Copy,
+    /// Paste the contents of the pasteboard.
+    Paste,
+    /// Select all text.
+    SelectAll,
+}
+
+impl EditAction {
+    pub(crate) fn from_selector(selector: Sel) -> Option<Self> {
+        if selector == sel!(cut:) {
+            Some(Self::Cut)
+        } else if selector == sel!(copy:) {
+            Some(Self::Copy)
+        } else if selector == sel!(paste:) {
+            Some(Self::Paste)
+        } else if selector == sel!(selectAll:) {
+            Some(Self::SelectAll)
+        } else {
+            None
+        }
+    }
+}
+
+/// Present the system edit menu in a window, such as when the user long-presses a text field.
+///
+/// The menu shows the given actions, and emits [`EditMenuActionPerformed`] when one is chosen.
+/// The actions stay enabled until the next request, so they're also available with keyboard
+/// shortcuts (such as ⌘C) while Bevy's view is the first responder.
+///
+/// Requires iOS 16.0.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct PresentEditMenu {
+    /// The window to present the menu in.
+    pub window: Entity,
+    /// The point that the menu points to, in logical coordinates relative to the top-left corner
+    /// of the window.
+    pub position: Vec2,
+    /// The actions to show in the menu.
+    pub actions: Vec<EditAction>,
+}
+
+/// The user chose an action in the edit menu, see [`PresentEditMenu`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EditMenuActionPerformed {
+    /// The window that the menu was presented in.
+    pub window: Entity,
+    /// The chosen action.
+    pub action: EditAction,
+}
+
+/// Present edit menus requested with [`PresentEditMenu`].
+pub fn present_edit_menus(
+    mut requests: MessageReader<PresentEditMenu>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for PresentEditMenu {
+        window,
+        position,
+        actions,
+    } in requests.read()
+    {
+        let Some(view) = uikit_windows
+            .get(*window, mtm)
+            .and_then(|uikit_window| uikit_window.view())
+        else {
+            warn!(
+                ?window,
+                "tried to present edit menu in window not known to UIKit"
+            );
+            continue;
+        };
+        *view.ivars().edit_actions.borrow_mut() = actions.clone();
+        if !available!(ios = 16.0, visionos = 1.0, ..) {
+            continue;
+        }
+
+        // The menu is built from the actions that the first responder can perform.
+        let responder: &UIResponder = &view;
+        if !responder.isFirstResponder() {
+            responder.becomeFirstResponder();
+        }
+
+        let mut interaction = view.ivars().edit_menu_interaction.borrow_mut();
+        let interaction = interaction.get_or_insert_with(|| {
+            let interaction =
+                UIEditMenuInteraction::initWithDelegate(UIEditMenuInteraction::alloc(mtm), None);
+            let uiview: &UIView = &view;
+            uiview.addInteraction(ProtocolObject::from_ref(&*interaction));
+            interaction
+        });
+
+        trace!(?window, ?position, ?actions, "presenting edit menu");
+        let source_point = CGPoint {
+            x: position.x as CGFloat,
+            y: position.y as CGFloat,
+        };
+        // SAFETY: No identifier is needed, since only one menu is presented at a time.
+        let configuration = unsafe {
+            UIEditMenuConfiguration::configurationWithIdentifier_sourcePoint(
+                None,
+                source_point,
+                mtm,
+            )
+        };
+        interaction.presentEditMenuWithConfiguration(&configuration);
+    }
+}
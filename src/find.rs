@@ -0,0 +1,301 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use std::cell::Cell;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    message::{Message, MessageReader},
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{
+    available, define_class, msg_send, DefinedClass as _, MainThreadMarker, MainThreadOnly,
+};
+use objc2_foundation::{
+    NSInteger, NSNotFound, NSObject, NSObjectProtocol, NSString, NSStringCompareOptions,
+};
+use objc2_ui_kit::{
+    UIFindInteraction, UIFindInteractionDelegate, UIFindSession, UITextSearchOptions,
+    UITextStorageDirection, UIView,
+};
+use tracing::{trace, warn};
+
+use crate::app::send_message;
+use crate::view::View;
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Enable the system find navigator (Cmd+F) for a window, and report the results of searches.
+///
+/// Searches are requested with [`FindRequest`] messages, and the application is responsible for
+/// searching its own content and updating this component with the results. Add this to the
+/// entity of the [`Window`](bevy_window::Window).
+///
+/// Requires iPadOS 16.0 or Mac Catalyst 16.0.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FindResults {
+    /// The number of results for the current query.
+    pub count: usize,
+    /// The index of the currently highlighted result, if any.
+    pub highlighted: Option<usize>,
+}
+
+/// What the user requested in the find navigator, see [`FindRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FindAction {
+    /// Search for the given query, and update [`FindResults`] with the results.
+    Search {
+        /// The text to search for.
+        query: String,
+        /// Whether the search should ignore case.
+        ignore_case: bool,
+    },
+    /// Highlight the next result.
+    Next,
+    /// Highlight the previous result.
+    Previous,
+    /// Stop highlighting results, e.g. because the query was cleared.
+    Clear,
+    /// The find navigator was dismissed.
+    Dismissed,
+}
+
+/// The user interacted with the find navigator of a window with [`FindResults`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct FindRequest {
+    /// The window whose find navigator was used.
+    pub window: Entity,
+    /// What the user requested.
+    pub action: FindAction,
+}
+
+/// Show the find navigator in a window with [`FindResults`], e.g. from a toolbar button.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresentFindNavigator {
+    /// The window to show the find navigator in.
+    pub window: Entity,
+}
+
+/// Propagate changes to [`FindResults`] to the native view.
+pub fn update_find_results(
+    find_results: Query<(Entity, Ref<FindResults>)>,
+    mut removed: RemovedComponents<FindResults>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    if !available!(ios = 16.0, visionos = 1.0, ..) {
+        return;
+    }
+
+    for (entity, results) in &find_results {
+        // Also update when the window was only just registered with UIKit.
+        if !results.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            trace!(?entity, ?results, "setting find results");
+            let mut find = view.ivars().find.borrow_mut();
+            let find = find.get_or_insert_with(|| Find::install(&view, entity, mtm));
+            let session = &find.delegate.ivars().session;
+            session.ivars().count.set(results.count);
+            session.ivars().highlighted.set(results.highlighted);
+            find.interaction.updateResultCount();
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            if let Some(find) = view.ivars().find.borrow_mut().take() {
+                find.interaction.dismissFindNavigator();
+                let view: &UIView = &view;
+                view.removeInteraction(ProtocolObject::from_ref(&*find.interaction));
+            }
+        }
+    }
+}
+
+/// Show the find navigator for windows requested with [`PresentFindNavigator`].
+pub fn present_find_navigators(
+    mut requests: MessageReader<PresentFindNavigator>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for PresentFindNavigator { window } in requests.read().copied() {
+        let view = uikit_windows
+            .get(window, mtm)
+            .and_then(|uikit_window| uikit_window.view());
+        let Some(find) = view
+            .as_ref()
+            .and_then(|view| view.ivars().find.borrow().clone())
+        else {
+            warn!(
+                ?window,
+                "tried to present find navigator in window without FindResults"
+            );
+            continue;
+        };
+        trace!(?window, "presenting find navigator");
+        find.interaction.presentFindNavigatorShowingReplace(false);
+    }
+}
+
+/// The find interaction of a view.
+#[derive(Debug, Clone)]
+pub(crate) struct Find {
+    interaction: Retained<UIFindInteraction>,
+    // The interaction only holds a weak reference to its delegate.
+    delegate: Retained<FindDelegate>,
+}
+
+impl Find {
+    fn install(view: &View, window: Entity, mtm: MainThreadMarker) -> Self {
+        let delegate = FindDelegate::new(window, mtm);
+        let interaction = UIFindInteraction::initWithSessionDelegate(
+            UIFindInteraction::alloc(mtm),
+            ProtocolObject::from_ref(&*delegate),
+        );
+        let view: &UIView = view;
+        view.addInteraction(ProtocolObject::from_ref(&*interaction));
+        Self {
+            interaction,
+            delegate,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FindDelegateIvars {
+    session: Retained<FindSession>,
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "BevyFindInteractionDelegate"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = FindDelegateIvars]
+    #[derive(Debug)]
+    pub(crate) struct FindDelegate;
+
+    unsafe impl NSObjectProtocol for FindDelegate {}
+
+    unsafe impl UIFindInteractionDelegate for FindDelegate {
+        #[unsafe(method_id(findInteraction:sessionForView:))]
+        fn findInteraction_sessionForView(
+            &self,
+            _interaction: &UIFindInteraction,
+            _view: &UIView,
+        ) -> Option<Retained<UIFindSession>> {
+            Some(self.ivars().session.clone().into_super())
+        }
+
+        #[unsafe(method(findInteraction:didEndFindSession:))]
+        fn findInteraction_didEndFindSession(
+            &self,
+            _interaction: &UIFindInteraction,
+            _session: &UIFindSession,
+        ) {
+            self.ivars().session.send(FindAction::Dismissed);
+        }
+    }
+);
+
+impl FindDelegate {
+    fn new(window: Entity, mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(FindDelegateIvars {
+            session: FindSession::new(window, mtm),
+        });
+        unsafe { msg_send![super(this), init] }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FindSessionIvars {
+    window: Entity,
+    count: Cell<usize>,
+    highlighted: Cell<Option<usize>>,
+}
+
+define_class!(
+    /// A find session that forwards searches to the application, and reports the results from
+    /// [`FindResults`].
+    #[unsafe(super(UIFindSession, NSObject))]
+    #[name = "BevyFindSession"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = FindSessionIvars]
+    #[derive(Debug)]
+    pub(crate) struct FindSession;
+
+    /// Overridden UIFindSession methods.
+    impl FindSession {
+        #[unsafe(method(resultCount))]
+        fn resultCount(&self) -> NSInteger {
+            self.ivars().count.get() as NSInteger
+        }
+
+        #[unsafe(method(highlightedResultIndex))]
+        fn highlightedResultIndex(&self) -> NSInteger {
+            match self.ivars().highlighted.get() {
+                Some(index) => index as NSInteger,
+                None => NSNotFound,
+            }
+        }
+
+        #[unsafe(method(performSearchWithQuery:options:))]
+        fn performSearchWithQuery_options(
+            &self,
+            query: &NSString,
+            options: Option<&UITextSearchOptions>,
+        ) {
+            let ignore_case = options.is_some_and(|options| {
+                options
+                    .stringCompareOptions()
+                    .contains(NSStringCompareOptions::CaseInsensitiveSearch)
+            });
+            self.send(FindAction::Search {
+                query: query.to_string(),
+                ignore_case,
+            });
+        }
+
+        #[unsafe(method(highlightNextResultInDirection:))]
+        fn highlightNextResultInDirection(&self, direction: UITextStorageDirection) {
+            self.send(match direction {
+                UITextStorageDirection::Backward => FindAction::Previous,
+                _ => FindAction::Next,
+            });
+        }
+
+        #[unsafe(method(invalidateFoundResults))]
+        fn invalidateFoundResults(&self) {
+            self.send(FindAction::Clear);
+        }
+    }
+);
+
+impl FindSession {
+    fn new(window: Entity, mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(FindSessionIvars {
+            window,
+            count: Cell::new(0),
+            highlighted: Cell::new(None),
+        });
+        unsafe { msg_send![super(this), init] }
+    }
+
+    fn send(&self, action: FindAction) {
+        let window = self.ivars().window;
+        trace!(?window, ?action, "find request");
+        send_message(self.mtm(), FindRequest { window, action });
+    }
+}
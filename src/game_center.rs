@@ -0,0 +1,295 @@
+#![expect(
+    non_snake_case,
+    reason = "GameKit does not use Rust naming conventions"
+)]
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    message::{Message, MessageReader},
+    query::With,
+    resource::Resource,
+    system::{NonSendMarker, Query, Res, ResMut},
+};
+use bevy_math::{Rect, Vec2};
+use bevy_window::PrimaryWindow;
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{
+    available, define_class, msg_send, DefinedClass as _, MainThreadMarker, MainThreadOnly,
+};
+use objc2_foundation::{NSError, NSObject, NSObjectProtocol};
+use objc2_game_kit::{
+    GKAccessPoint, GKAccessPointLocation, GKGameCenterControllerDelegate,
+    GKGameCenterViewController, GKGameCenterViewControllerState, GKLocalPlayer,
+};
+use objc2_ui_kit::UIViewController;
+use tracing::{trace, warn};
+
+use crate::app::{send_message, with_world};
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Integrate Game Center's access point and view controllers with the application's windows.
+///
+/// Authenticate the player with [`AuthenticateLocalPlayer`], and then configure the access point
+/// with the [`GameCenterAccessPoint`] resource. Its frame is available in
+/// [`GameCenterAccessPointFrame`], so that the game can avoid placing content under it.
+#[derive(Debug, Default)]
+pub struct GameCenterPlugin;
+
+impl Plugin for GameCenterPlugin {
+    fn name(&self) -> &str {
+        "bevy_uikit::GameCenterPlugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        let mtm = MainThreadMarker::new().expect("must build the App on the main thread");
+        app.init_resource::<GameCenterAccessPoint>()
+            .init_resource::<GameCenterAccessPointFrame>()
+            .insert_resource(GameCenterDelegateResource(MainThreadBound::new(
+                GameCenterDelegate::new(mtm),
+                mtm,
+            )))
+            .add_message::<AuthenticateLocalPlayer>()
+            .add_message::<LocalPlayerAuthenticated>()
+            .add_message::<PresentGameCenter>()
+            .add_message::<GameCenterDismissed>()
+            .add_systems(
+                Last,
+                (
+                    authenticate_local_player,
+                    update_access_point,
+                    present_game_center,
+                ),
+            );
+    }
+}
+
+/// The corner of the screen that the access point is shown in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AccessPointLocation {
+    /// The top corner on the leading side.
+    #[default]
+    TopLeading,
+    /// The top corner on the trailing side.
+    TopTrailing,
+    /// The bottom corner on the leading side.
+    BottomLeading,
+    /// The bottom corner on the trailing side.
+    BottomTrailing,
+}
+
+/// The configuration of Game Center's access point, the button that opens the Game Center
+/// dashboard.
+///
+/// The access point is only shown once the local player is authenticated. Requires iOS 14.0.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GameCenterAccessPoint {
+    /// Whether the access point is shown.
+    pub active: bool,
+    /// Where the access point is shown.
+    pub location: AccessPointLocation,
+    /// Whether the access point periodically shows the player's achievements and scores.
+    pub show_highlights: bool,
+}
+
+/// The area covered by the access point, see [`GameCenterAccessPoint`].
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct GameCenterAccessPointFrame {
+    /// The frame in logical coordinates relative to the top-left corner of the primary window, or
+    /// `None` if the access point is not visible.
+    pub frame: Option<Rect>,
+}
+
+/// Authenticate the local player with Game Center, presenting the sign-in UI in the given window
+/// if needed.
+///
+/// The result is delivered as a [`LocalPlayerAuthenticated`] message, which may be emitted again
+/// later, such as if the player signs out in Settings.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthenticateLocalPlayer {
+    /// The window to present the sign-in UI in.
+    pub window: Entity,
+}
+
+/// The outcome of [`AuthenticateLocalPlayer`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct LocalPlayerAuthenticated {
+    /// Whether the player is authenticated, or the error description if authentication failed.
+    pub result: Result<(), String>,
+}
+
+/// Present the Game Center dashboard in the given window.
+///
+/// Emits [`GameCenterDismissed`] when the player closes it. Requires iOS 14.0.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresentGameCenter {
+    /// The window to present the dashboard in.
+    pub window: Entity,
+}
+
+/// The Game Center dashboard was closed.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameCenterDismissed;
+
+/// Set the authentication handler for [`AuthenticateLocalPlayer`] requests.
+fn authenticate_local_player(
+    mut requests: MessageReader<AuthenticateLocalPlayer>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for AuthenticateLocalPlayer { window } in requests.read() {
+        let window = *window;
+        trace!(?window, "authenticating local player");
+        let handler = RcBlock::new(
+            move |view_controller: *mut UIViewController, error: *mut NSError| {
+                // SAFETY: The view controller is either NULL or a valid `UIViewController`.
+                if let Some(view_controller) = unsafe { view_controller.as_ref() } {
+                    trace!(?window, "presenting Game Center sign-in");
+                    let view_controller = view_controller.retain();
+                    with_world(mtm, move |world| {
+                        let uikit_windows = world.resource::<UIKitWindows>();
+                        if let Some(uikit_window) = uikit_windows.get(window, mtm) {
+                            present_modally(&uikit_window.view_controller, &view_controller);
+                        }
+                    });
+                    return;
+                }
+                // SAFETY: The error is either NULL or a valid `NSError`.
+                let result = match unsafe { error.as_ref() } {
+                    Some(error) => Err(error.localizedDescription().to_string()),
+                    None if GKLocalPlayer::local().isAuthenticated() => Ok(()),
+                    None => Err("not authenticated".into()),
+                };
+                trace!(?result, "local player authentication finished");
+                send_message(mtm, LocalPlayerAuthenticated { result });
+            },
+        );
+        // SAFETY: The handler has the correct signature.
+        unsafe { GKLocalPlayer::local().setAuthenticateHandler(Some(&handler)) };
+    }
+}
+
+/// Propagate [`GameCenterAccessPoint`] to GameKit, and update [`GameCenterAccessPointFrame`].
+fn update_access_point(
+    access_point: Res<GameCenterAccessPoint>,
+    mut frame: ResMut<GameCenterAccessPointFrame>,
+    primary_window: Query<Entity, With<PrimaryWindow>>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    if !available!(ios = 14.0, ..) {
+        return;
+    }
+    let shared = GKAccessPoint::shared(mtm);
+    if access_point.is_changed() {
+        trace!(?access_point, "configuring Game Center access point");
+        shared.setLocation(match access_point.location {
+            AccessPointLocation::TopLeading => GKAccessPointLocation::TopLeading,
+            AccessPointLocation::TopTrailing => GKAccessPointLocation::TopTrailing,
+            AccessPointLocation::BottomLeading => GKAccessPointLocation::BottomLeading,
+            AccessPointLocation::BottomTrailing => GKAccessPointLocation::BottomTrailing,
+        });
+        shared.setShowHighlights(access_point.show_highlights);
+        shared.setActive(access_point.active);
+    }
+
+    let new_frame = primary_window
+        .single()
+        .ok()
+        .and_then(|entity| uikit_windows.get(entity, mtm))
+        .filter(|_| shared.isActive() && shared.isVisible())
+        .map(|uikit_window| {
+            // Converts from screen coordinates when no window is given.
+            let rect = uikit_window
+                .uiwindow
+                .convertRect_fromWindow(shared.frameInScreenCoordinates(), None);
+            Rect::from_corners(
+                Vec2::new(rect.origin.x as f32, rect.origin.y as f32),
+                Vec2::new(
+                    (rect.origin.x + rect.size.width) as f32,
+                    (rect.origin.y + rect.size.height) as f32,
+                ),
+            )
+        });
+    if frame.frame != new_frame {
+        frame.frame = new_frame;
+    }
+}
+
+/// Present the dashboard for [`PresentGameCenter`] requests.
+fn present_game_center(
+    mut requests: MessageReader<PresentGameCenter>,
+    delegate: Res<GameCenterDelegateResource>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for PresentGameCenter { window } in requests.read() {
+        if !available!(ios = 14.0, ..) {
+            warn!("presenting Game Center requires iOS 14.0");
+            continue;
+        }
+        let Some(uikit_window) = uikit_windows.get(*window, mtm) else {
+            warn!(
+                ?window,
+                "tried to present Game Center in window not registered with UIKit"
+            );
+            continue;
+        };
+        trace!(?window, "presenting Game Center");
+        let controller = GKGameCenterViewController::initWithState(
+            GKGameCenterViewController::alloc(mtm),
+            GKGameCenterViewControllerState::Default,
+        );
+        controller.setGameCenterDelegate(Some(ProtocolObject::from_ref(&**delegate.0.get(mtm))));
+        present_modally(&uikit_window.view_controller, &controller);
+    }
+}
+
+/// Present a view controller over whatever is currently presented in the window, as UIKit
+/// refuses to present from a view controller that is already presenting something.
+fn present_modally(root: &UIViewController, view_controller: &UIViewController) {
+    let mut presenter = root.retain();
+    while let Some(presented) = presenter.presentedViewController() {
+        if presented.isBeingDismissed() {
+            break;
+        }
+        presenter = presented;
+    }
+    presenter.presentViewController_animated_completion(view_controller, true, None);
+}
+
+/// Keeps the delegate alive, as the view controller only holds a weak reference to it.
+#[derive(Resource, Debug)]
+struct GameCenterDelegateResource(MainThreadBound<Retained<GameCenterDelegate>>);
+
+define_class!(
+    /// The delegate of the Game Center dashboard, dismissing it when the player closes it.
+    #[unsafe(super(NSObject))]
+    #[name = "BevyGameCenterDelegate"]
+    #[thread_kind = MainThreadOnly]
+    #[derive(Debug)]
+    struct GameCenterDelegate;
+
+    unsafe impl NSObjectProtocol for GameCenterDelegate {}
+
+    unsafe impl GKGameCenterControllerDelegate for GameCenterDelegate {
+        #[unsafe(method(gameCenterViewControllerDidFinish:))]
+        fn gameCenterViewControllerDidFinish(&self, controller: &GKGameCenterViewController) {
+            trace!("gameCenterViewControllerDidFinish:");
+            controller.dismissViewControllerAnimated_completion(true, None);
+            send_message(self.mtm(), GameCenterDismissed);
+        }
+    }
+);
+
+impl GameCenterDelegate {
+    fn new(mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(());
+        unsafe { msg_send![super(this), init] }
+    }
+}
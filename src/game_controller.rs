@@ -0,0 +1,187 @@
+use std::ptr::NonNull;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::{message::Message, resource::Resource, world::World};
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{available, MainThreadMarker};
+use objc2_foundation::{
+    NSNotification, NSNotificationCenter, NSNotificationName, NSObjectProtocol, NSOperationQueue,
+};
+use objc2_game_controller::{
+    GCController, GCControllerDidConnectNotification, GCControllerDidDisconnectNotification,
+};
+use tracing::trace;
+
+use crate::app::with_world;
+
+/// Track connected game controllers, such as to show a "controller connected" notice, or to pause
+/// the game when the controller is disconnected.
+///
+/// The connected controllers are available in the [`GameControllers`] resource, and changes are
+/// emitted as [`GameControllerConnected`] and [`GameControllerDisconnected`] messages.
+///
+/// This does not provide input from the controllers, use `bevy_gilrs` for that.
+#[derive(Debug, Default)]
+pub struct GameControllerPlugin;
+
+impl Plugin for GameControllerPlugin {
+    fn name(&self) -> &str {
+        "bevy_uikit::GameControllerPlugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        let mtm = MainThreadMarker::new().expect("must build the App on the main thread");
+        let controllers = GameControllers {
+            controllers: GCController::controllers()
+                .iter()
+                .map(|controller| GameController::new(&controller))
+                .collect(),
+        };
+        trace!(?controllers, "initial game controllers");
+
+        // SAFETY: The statics are valid notification names.
+        let (connect, disconnect) = unsafe {
+            (
+                GCControllerDidConnectNotification,
+                GCControllerDidDisconnectNotification,
+            )
+        };
+        let observers = GameControllerObservers(MainThreadBound::new(
+            [
+                observe(connect, mtm, |world, controller| {
+                    let mut controllers = world.resource_mut::<GameControllers>();
+                    if controllers.get(controller.id).is_none() {
+                        controllers.controllers.push(controller.clone());
+                    }
+                    world.write_message(GameControllerConnected { controller });
+                }),
+                observe(disconnect, mtm, |world, controller| {
+                    let id = controller.id;
+                    world
+                        .resource_mut::<GameControllers>()
+                        .controllers
+                        .retain(|controller| controller.id != id);
+                    world.write_message(GameControllerDisconnected { controller });
+                }),
+            ],
+            mtm,
+        ));
+
+        app.insert_resource(controllers)
+            .insert_resource(observers)
+            .add_message::<GameControllerConnected>()
+            .add_message::<GameControllerDisconnected>();
+    }
+}
+
+/// Information about a connected game controller.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GameController {
+    /// An identifier for the controller, unique among the currently connected controllers.
+    pub id: usize,
+    /// The name of the controller's vendor, such as `"Xbox Wireless Controller"`.
+    pub vendor_name: Option<String>,
+    /// The kind of controller, such as `"DualShock 4"` or `"Siri Remote"`.
+    ///
+    /// Requires iOS 13.0 or tvOS 13.0.
+    pub product_category: Option<String>,
+    /// Whether the controller is physically attached to the device, such as a controller that the
+    /// device is slotted into.
+    pub attached_to_device: bool,
+    /// Whether the controller has the full set of controls of an extended gamepad (two
+    /// thumbsticks, a directional pad, four face buttons and shoulder buttons and triggers).
+    pub extended_gamepad: bool,
+}
+
+impl GameController {
+    fn new(controller: &GCController) -> Self {
+        let product_category = if available!(ios = 13.0, tvos = 13.0, visionos = 1.0, ..) {
+            Some(controller.productCategory().to_string())
+        } else {
+            None
+        };
+        Self {
+            id: std::ptr::from_ref(controller) as usize,
+            vendor_name: controller.vendorName().map(|name| name.to_string()),
+            product_category,
+            attached_to_device: controller.isAttachedToDevice(),
+            extended_gamepad: controller.extendedGamepad().is_some(),
+        }
+    }
+}
+
+/// The currently connected game controllers, see [`GameControllerPlugin`].
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameControllers {
+    /// The controllers, in the order they were connected.
+    pub controllers: Vec<GameController>,
+}
+
+impl GameControllers {
+    /// Get a controller by its [`GameController::id`].
+    pub fn get(&self, id: usize) -> Option<&GameController> {
+        self.controllers
+            .iter()
+            .find(|controller| controller.id == id)
+    }
+
+    /// Whether any controllers are connected.
+    pub fn is_empty(&self) -> bool {
+        self.controllers.is_empty()
+    }
+}
+
+/// A game controller was connected.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct GameControllerConnected {
+    /// The controller.
+    pub controller: GameController,
+}
+
+/// A game controller was disconnected.
+///
+/// Games will usually want to pause when this happens.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct GameControllerDisconnected {
+    /// The controller, as it was before being disconnected.
+    pub controller: GameController,
+}
+
+/// Keeps the notification observers alive.
+#[derive(Resource, Debug)]
+struct GameControllerObservers(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<[Retained<ProtocolObject<dyn NSObjectProtocol>>; 2]>,
+);
+
+fn observe(
+    name: &'static NSNotificationName,
+    mtm: MainThreadMarker,
+    handler: fn(&mut World, GameController),
+) -> Retained<ProtocolObject<dyn NSObjectProtocol>> {
+    let block = RcBlock::new(move |notification: NonNull<NSNotification>| {
+        // SAFETY: The notification is valid for the duration of the block.
+        let notification = unsafe { notification.as_ref() };
+        let Some(controller) = notification
+            .object()
+            .and_then(|object| object.downcast::<GCController>().ok())
+        else {
+            return;
+        };
+        let controller = GameController::new(&controller);
+        trace!(?name, ?controller, "game controller notification");
+        with_world(mtm, move |world| handler(world, controller));
+    });
+    // SAFETY: The block has the correct signature, and is run on the main queue.
+    unsafe {
+        NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+            Some(name),
+            None,
+            Some(&NSOperationQueue::mainQueue()),
+            &block,
+        )
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(not(target_os = "tvos"))]
+use objc2_ui_kit::UIGestureRecognizer;
+
+/// How the gesture recognizers installed by the plugin interact with each other and with the
+/// touches delivered to Bevy's view, see [`UIKitSettings::gestures`](crate::UIKitSettings).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct GestureSettings {
+    /// How trackpad pinch and rotation gestures are resolved when both could be recognized.
+    ///
+    /// Applied when a window is created.
+    pub pinch_and_rotation: PinchRotationConflict,
+    /// What happens to touches that are part of a swipe from a
+    /// [`ScreenEdgeSwipes`](crate::ScreenEdgeSwipes) edge.
+    pub edge_swipe_touches: GestureTouches,
+    /// What happens to indirect pointer touches that are part of a trackpad pinch or rotation.
+    ///
+    /// Applied when a window is created.
+    pub trackpad_touches: GestureTouches,
+}
+
+/// How trackpad [`PinchGesture`]s and [`RotationGesture`]s are resolved, see
+/// [`GestureSettings::pinch_and_rotation`].
+///
+/// [`PinchGesture`]: bevy_input::gestures::PinchGesture
+/// [`RotationGesture`]: bevy_input::gestures::RotationGesture
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PinchRotationConflict {
+    /// Recognize both at the same time.
+    #[default]
+    Simultaneous,
+    /// Only recognize whichever gesture is recognized first.
+    Exclusive,
+    /// Only recognize rotation once the gesture can no longer be a pinch.
+    PreferPinch,
+    /// Only recognize pinching once the gesture can no longer be a rotation.
+    PreferRotation,
+}
+
+/// What happens to the touches that a gesture recognizer installed by the plugin consumes, see
+/// [`GestureSettings`].
+///
+/// Cancelled touches are delivered as [`TouchInput`]s with [`TouchPhase::Canceled`].
+///
+/// [`TouchInput`]: bevy_input::touch::TouchInput
+/// [`TouchPhase::Canceled`]: bevy_input::touch::TouchPhase::Canceled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GestureTouches {
+    /// Deliver the touches until the gesture is recognized, and then cancel them.
+    ///
+    /// This is UIKit's default behaviour.
+    #[default]
+    Cancel,
+    /// Hold back the touches until the gesture can no longer be recognized, such that touches
+    /// are never delivered for recognized gestures. This delays all touches that could start the
+    /// gesture.
+    Delay,
+    /// Keep delivering the touches while the gesture is recognized, and never cancel them.
+    Deliver,
+}
+
+impl GestureTouches {
+    /// Configure `cancelsTouchesInView` and `delaysTouchesBegan` on the recognizer.
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) fn apply(self, recognizer: &UIGestureRecognizer) {
+        recognizer.setCancelsTouchesInView(self != Self::Deliver);
+        recognizer.setDelaysTouchesBegan(self == Self::Delay);
+    }
+}
@@ -0,0 +1,56 @@
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_math::Rect;
+use objc2::DefinedClass as _;
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Regions of a window where touches are not handled by Bevy, but instead passed through to the
+/// views behind Bevy's view.
+///
+/// Useful for letting native overlay views and Bevy's content share touch real estate
+/// predictably. Add this to the entity of the [`Window`](bevy_window::Window).
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct HitTestPassthrough {
+    /// The regions, in logical coordinates relative to the top-left corner of the window.
+    pub regions: Vec<Rect>,
+}
+
+/// Propagate changes to [`HitTestPassthrough`] to the native view.
+pub fn update_hit_test_passthrough(
+    passthroughs: Query<(Entity, Ref<HitTestPassthrough>)>,
+    mut removed: RemovedComponents<HitTestPassthrough>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, passthrough) in &passthroughs {
+        // Also update when the window was only just registered with UIKit.
+        if !passthrough.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            trace!(?entity, regions = ?passthrough.regions, "setting hit test passthrough");
+            *view.ivars().passthrough_regions.borrow_mut() = passthrough.regions.clone();
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            view.ivars().passthrough_regions.borrow_mut().clear();
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_math::Rect;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{available, DefinedClass as _, MainThreadMarker};
+use objc2_core_foundation::{CGFloat, CGPoint, CGRect, CGSize};
+use objc2_ui_kit::{
+    UIColor, UIHoverAutomaticEffect, UIHoverEffect, UIHoverHighlightEffect, UIHoverLiftEffect,
+    UIHoverStyle, UIShape, UIView,
+};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// The effect applied to a [`HoverRegion`] when the user looks at it (visionOS) or hovers over it
+/// with a pointer (iPadOS).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HoverEffect {
+    /// Let the system choose an appropriate effect.
+    #[default]
+    Automatic,
+    /// Highlight the region.
+    Highlight,
+    /// Lift the region, with a shadow behind it.
+    Lift,
+}
+
+/// The shape of the effect of a [`HoverRegion`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HoverShape {
+    /// A rectangle, with the given corner radius in logical points.
+    Rect {
+        /// The radius of the corners.
+        corner_radius: f32,
+    },
+    /// A rectangle with fully rounded ends.
+    Capsule,
+    /// A circle.
+    Circle,
+}
+
+impl Default for HoverShape {
+    fn default() -> Self {
+        Self::Rect { corner_radius: 0.0 }
+    }
+}
+
+/// An interactive area of a window, see [`HoverEffects`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HoverRegion {
+    /// The area, in logical coordinates relative to the top-left corner of the window.
+    pub rect: Rect,
+    /// The effect to apply.
+    pub effect: HoverEffect,
+    /// The shape of the effect.
+    pub shape: HoverShape,
+}
+
+/// Interactive areas of a window, which are highlighted by the system when the user looks at
+/// them on visionOS, or hovers over them with a pointer on iPadOS.
+///
+/// The effect is rendered by the system, and the application is not told what the user is looking
+/// at. Add this to the entity of the [`Window`](bevy_window::Window).
+///
+/// Requires iOS 17.0 or visionOS 1.0.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct HoverEffects {
+    /// The regions.
+    pub regions: Vec<HoverRegion>,
+}
+
+/// Propagate changes to [`HoverEffects`] to the native view.
+pub fn update_hover_effects(
+    hover_effects: Query<(Entity, Ref<HoverEffects>)>,
+    mut removed: RemovedComponents<HoverEffects>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    if !available!(ios = 17.0, visionos = 1.0, ..) {
+        return;
+    }
+
+    for (entity, hover_effects) in &hover_effects {
+        // Also update when the window was only just registered with UIKit.
+        if !hover_effects.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            trace!(?entity, regions = ?hover_effects.regions, "setting hover effects");
+            let mut hover_views = view.ivars().hover_views.borrow_mut();
+            for hover_view in hover_views.drain(..) {
+                hover_view.removeFromSuperview();
+            }
+            for region in &hover_effects.regions {
+                let hover_view = hover_view(region, mtm);
+                view.addSubview(&hover_view);
+                hover_views.push(hover_view);
+            }
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            for hover_view in view.ivars().hover_views.borrow_mut().drain(..) {
+                hover_view.removeFromSuperview();
+            }
+        }
+    }
+}
+
+/// Create a transparent view covering the region, with the region's hover style.
+///
+/// Touches in the view are forwarded by UIKit to the next responder, which is Bevy's view.
+fn hover_view(region: &HoverRegion, mtm: MainThreadMarker) -> Retained<UIView> {
+    let frame = CGRect {
+        origin: CGPoint {
+            x: region.rect.min.x as CGFloat,
+            y: region.rect.min.y as CGFloat,
+        },
+        size: CGSize {
+            width: region.rect.width() as CGFloat,
+            height: region.rect.height() as CGFloat,
+        },
+    };
+    let view = UIView::initWithFrame(UIView::alloc(mtm), frame);
+    view.setBackgroundColor(Some(&UIColor::clearColor()));
+
+    let effect: Retained<ProtocolObject<dyn UIHoverEffect>> = match region.effect {
+        HoverEffect::Automatic => {
+            ProtocolObject::from_retained(UIHoverAutomaticEffect::effect(mtm))
+        }
+        HoverEffect::Highlight => {
+            ProtocolObject::from_retained(UIHoverHighlightEffect::effect(mtm))
+        }
+        HoverEffect::Lift => ProtocolObject::from_retained(UIHoverLiftEffect::effect(mtm)),
+    };
+    let shape = match region.shape {
+        HoverShape::Rect { corner_radius } => {
+            UIShape::rectShapeWithCornerRadius(corner_radius as CGFloat)
+        }
+        HoverShape::Capsule => UIShape::capsuleShape(),
+        HoverShape::Circle => UIShape::circleShape(),
+    };
+    // SAFETY: The effect is one of the system-provided effects.
+    let style = unsafe { UIHoverStyle::styleWithEffect_shape(&effect, Some(&shape)) };
+    view.setHoverStyle(Some(&style));
+    view
+}
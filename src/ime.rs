@@ -0,0 +1,339 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use std::cell::Cell;
+use std::ops::Range;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    message::Message,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_math::{Rect, Vec2};
+use bevy_window::{Ime, Window};
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{
+    define_class, msg_send, sel, ClassType, DefinedClass as _, MainThreadMarker, MainThreadOnly,
+};
+use objc2_core_foundation::CGRect;
+use objc2_foundation::{NSAttributedString, NSNumber, NSRange, NSString};
+use objc2_ui_kit::{
+    NSUnderlineStyle, NSUnderlineStyleAttributeName, UIControl, UIControlEvents, UIResponder,
+    UITextField, UITextInput, UIView,
+};
+use tracing::trace;
+
+use crate::app::{send_message, send_window_message};
+use crate::scribble::rect_to_uikit;
+use crate::view::View;
+use crate::{main_thread_marker, UIKitWindows};
+
+/// A clause of the marked text, see [`MarkedTextChanged`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MarkedTextUnderline {
+    /// The byte range in [`MarkedTextChanged::text`] that is underlined.
+    pub range: Range<usize>,
+    /// Whether the underline is thick, which input methods use for the clause being converted.
+    pub thick: bool,
+}
+
+/// The text being composed with an input method (the marked text) changed.
+///
+/// This is sent on every change during composition, alongside [`Ime::Preedit`], and includes the
+/// underlines that input methods such as Japanese use for showing clauses, which Bevy's message
+/// lacks. When composition ends, this is sent once more with empty `text`.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct MarkedTextChanged {
+    /// The window that text is being composed in.
+    pub window: Entity,
+    /// The marked text.
+    pub text: String,
+    /// The byte range in `text` that is selected, which is empty when only a caret is shown.
+    pub selected_range: Range<usize>,
+    /// The ranges of `text` to underline, in order.
+    pub underlines: Vec<MarkedTextUnderline>,
+}
+
+/// Show the keyboard while [`Window::ime_enabled`] is set, and deliver text input as [`Ime`]
+/// messages.
+///
+/// The input is received by an invisible native text field at [`Window::ime_position`], such that
+/// the system places the candidate bar next to it. Marked text is additionally delivered as
+/// [`MarkedTextChanged`].
+pub fn update_ime(
+    windows: Query<(Entity, Ref<Window>)>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, window) in &windows {
+        // Also update when the window was only just registered with UIKit.
+        if !window.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        else {
+            continue;
+        };
+
+        let mut ime_field = view.ivars().ime_field.borrow_mut();
+        if !window.ime_enabled {
+            // Release the borrow, as resigning the first responder sends a message.
+            let text_field = ime_field.clone();
+            drop(ime_field);
+            if let Some(text_field) = text_field.filter(|field| field.isFirstResponder()) {
+                trace!(?entity, "disabling IME");
+                text_field.resignFirstResponder();
+            }
+            continue;
+        }
+
+        let text_field = ime_field
+            .get_or_insert_with(|| ImeTextField::install(&view, entity, mtm))
+            .clone();
+        drop(ime_field);
+        let position = window.ime_position;
+        text_field.setFrame(rect_to_uikit(Rect::from_corners(
+            position,
+            position + Vec2::ONE,
+        )));
+        if !text_field.isFirstResponder() {
+            trace!(?entity, ?position, "enabling IME");
+            text_field.becomeFirstResponder();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ImeTextFieldIvars {
+    window: Entity,
+    /// Whether marked text is shown, and thus an empty preedit must be sent when it's committed.
+    composing: Cell<bool>,
+    /// Set while inside `setAttributedMarkedText:selectedRange:`.
+    setting_attributed: Cell<bool>,
+}
+
+define_class!(
+    /// An invisible native text field that receives the text input while IME is enabled.
+    ///
+    /// The text field is kept empty; text is delivered as soon as it's committed.
+    #[unsafe(super(UITextField, UIControl, UIView, UIResponder))]
+    #[name = "BevyImeTextField"]
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    #[ivars = ImeTextFieldIvars]
+    pub(crate) struct ImeTextField;
+
+    /// Overridden UIResponder methods.
+    impl ImeTextField {
+        #[unsafe(method(becomeFirstResponder))]
+        fn becomeFirstResponder(&self) -> bool {
+            let success: bool = unsafe { msg_send![super(self), becomeFirstResponder] };
+            if success {
+                send_window_message(
+                    self.mtm(),
+                    Ime::Enabled {
+                        window: self.ivars().window,
+                    },
+                );
+            }
+            success
+        }
+
+        #[unsafe(method(resignFirstResponder))]
+        fn resignFirstResponder(&self) -> bool {
+            let success: bool = unsafe { msg_send![super(self), resignFirstResponder] };
+            if success {
+                send_window_message(
+                    self.mtm(),
+                    Ime::Disabled {
+                        window: self.ivars().window,
+                    },
+                );
+            }
+            success
+        }
+    }
+
+    /// Overridden UITextInput methods.
+    impl ImeTextField {
+        #[unsafe(method(setMarkedText:selectedRange:))]
+        fn setMarkedText_selectedRange(&self, text: Option<&NSString>, selected_range: NSRange) {
+            unsafe { msg_send![super(self), setMarkedText: text, selectedRange: selected_range] }
+            if self.ivars().setting_attributed.get() {
+                return;
+            }
+            let text = text.map(|text| text.to_string()).unwrap_or_default();
+            // Without attributes, the system underlines the marked text as a whole.
+            let underline = NSRange::new(0, text.encode_utf16().count());
+            self.send_marked_text(text, selected_range, vec![(underline, false)]);
+        }
+
+        #[unsafe(method(setAttributedMarkedText:selectedRange:))]
+        fn setAttributedMarkedText_selectedRange(
+            &self,
+            text: Option<&NSAttributedString>,
+            selected_range: NSRange,
+        ) {
+            // `UITextField` may implement this in terms of `setMarkedText:selectedRange:`, which
+            // must then not report the marked text without its attributes.
+            self.ivars().setting_attributed.set(true);
+            let range = selected_range;
+            // Optional in `UITextInput`, though implemented by `UITextField` on recent versions.
+            if UITextField::class().responds_to(sel!(setAttributedMarkedText:selectedRange:)) {
+                unsafe {
+                    msg_send![super(self), setAttributedMarkedText: text, selectedRange: range]
+                }
+            } else {
+                let string = text.map(|text| text.string());
+                let string = string.as_deref();
+                unsafe { msg_send![super(self), setMarkedText: string, selectedRange: range] }
+            }
+            self.ivars().setting_attributed.set(false);
+            let underlines = text.map(marked_text_underlines).unwrap_or_default();
+            let text = text.map(|text| text.string().to_string()).unwrap_or_default();
+            self.send_marked_text(text, selected_range, underlines);
+        }
+
+        #[unsafe(method(unmarkText))]
+        fn unmarkText(&self) {
+            unsafe { msg_send![super(self), unmarkText] }
+            self.commit();
+        }
+    }
+
+    impl ImeTextField {
+        #[unsafe(method(textFieldDidChange:))]
+        fn textFieldDidChange(&self, _text_field: &UITextField) {
+            self.commit();
+        }
+    }
+);
+
+impl ImeTextField {
+    /// Create the text field, and add it to the view.
+    fn install(view: &View, window: Entity, mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(ImeTextFieldIvars {
+            window,
+            composing: Cell::new(false),
+            setting_attributed: Cell::new(false),
+        });
+        let this: Retained<Self> = unsafe { msg_send![super(this), initWithFrame: CGRect::ZERO] };
+        this.setAlpha(0.0);
+
+        let target: &AnyObject = &this;
+        // SAFETY: The target has a method with the given selector, which takes the text field.
+        unsafe {
+            this.addTarget_action_forControlEvents(
+                Some(target),
+                sel!(textFieldDidChange:),
+                UIControlEvents::EditingChanged,
+            );
+        }
+        let view: &UIView = view;
+        view.addSubview(&this);
+        this
+    }
+
+    fn send_marked_text(
+        &self,
+        text: String,
+        selected_range: NSRange,
+        underlines: Vec<(NSRange, bool)>,
+    ) {
+        let window = self.ivars().window;
+        let selected_range = byte_range(&text, selected_range);
+        let underlines = underlines
+            .into_iter()
+            .map(|(range, thick)| MarkedTextUnderline {
+                range: byte_range(&text, range),
+                thick,
+            })
+            .filter(|underline| !underline.range.is_empty())
+            .collect();
+        trace!(?window, text, ?selected_range, "marked text");
+        self.ivars().composing.set(!text.is_empty());
+
+        send_window_message(
+            self.mtm(),
+            Ime::Preedit {
+                window,
+                value: text.clone(),
+                cursor: (!text.is_empty()).then_some((selected_range.start, selected_range.end)),
+            },
+        );
+        send_message(
+            self.mtm(),
+            MarkedTextChanged {
+                window,
+                text,
+                selected_range,
+                underlines,
+            },
+        );
+    }
+
+    /// Deliver the text of the field, unless it is still being composed.
+    fn commit(&self) {
+        if self.markedTextRange().is_some() {
+            return;
+        }
+        let text = self.text().map(|text| text.to_string()).unwrap_or_default();
+        if self.ivars().composing.get() {
+            self.send_marked_text(String::new(), NSRange::new(0, 0), Vec::new());
+        }
+        if text.is_empty() {
+            return;
+        }
+        let window = self.ivars().window;
+        trace!(?window, text, "committed text");
+        self.setText(None);
+        send_window_message(
+            self.mtm(),
+            Ime::Commit {
+                window,
+                value: text,
+            },
+        );
+    }
+}
+
+/// The ranges of the given marked text that have an underline, and whether it is thick.
+fn marked_text_underlines(text: &NSAttributedString) -> Vec<(NSRange, bool)> {
+    let mut underlines = Vec::new();
+    let mut index = 0;
+    while index < text.length() {
+        let mut range = NSRange::new(index, 0);
+        // SAFETY: The range pointer is valid.
+        let style = unsafe {
+            text.attribute_atIndex_effectiveRange(NSUnderlineStyleAttributeName, index, &mut range)
+        };
+        let style = style
+            .and_then(|style| style.downcast::<NSNumber>().ok())
+            .map(|style| NSUnderlineStyle(style.as_isize()))
+            .unwrap_or(NSUnderlineStyle::None);
+        if style != NSUnderlineStyle::None {
+            underlines.push((range, style.contains(NSUnderlineStyle::Thick)));
+        }
+        // Always make progress, even if the effective range is empty.
+        index = (range.location + range.length).max(index + 1);
+    }
+    underlines
+}
+
+/// Convert a range of UTF-16 code units in `text` to a range of bytes.
+fn byte_range(text: &str, range: NSRange) -> Range<usize> {
+    let byte_offset = |utf16_offset: usize| {
+        let mut utf16 = 0;
+        for (index, c) in text.char_indices() {
+            if utf16 >= utf16_offset {
+                return index;
+            }
+            utf16 += c.len_utf16();
+        }
+        text.len()
+    };
+    byte_offset(range.location)..byte_offset(range.location + range.length)
+}
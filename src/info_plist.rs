@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use objc2_foundation::NSBundle;
+use tracing::{error, warn};
+
+use crate::PropertyListValue;
+
+/// The main bundle's `Info.plist`.
+pub(crate) fn info_dictionary() -> Option<HashMap<String, PropertyListValue>> {
+    let info = NSBundle::mainBundle().infoDictionary()?;
+    match PropertyListValue::from_object(&info)? {
+        PropertyListValue::Dictionary(info) => Some(info),
+        _ => None,
+    }
+}
+
+/// Check that `Info.plist` doesn't contain keys that conflict with how Bevy sets up windows, and
+/// report how to fix them.
+///
+/// See also [`audit_multitasking_support`](crate::audit_multitasking_support).
+pub(crate) fn validate_info_plist() {
+    let Some(info) = info_dictionary() else {
+        warn!("could not read Info.plist, skipping validation");
+        return;
+    };
+
+    for key in ["UIMainStoryboardFile", "UIMainStoryboardFile~ipad"] {
+        if info.contains_key(key) {
+            error!("storyboards are not supported in Bevy, remove the `{key}` key from Info.plist");
+        }
+    }
+
+    let Some(PropertyListValue::Dictionary(manifest)) = info.get("UIApplicationSceneManifest")
+    else {
+        return;
+    };
+    if cfg!(feature = "no-scene") {
+        error!(
+            "the `no-scene` feature is enabled, but Info.plist contains \
+             `UIApplicationSceneManifest`, remove it to disable scenes"
+        );
+        return;
+    }
+    let Some(PropertyListValue::Dictionary(configurations)) = manifest.get("UISceneConfigurations")
+    else {
+        return;
+    };
+    // Bevy creates scene configurations itself, so the ones in Info.plist are never used.
+    for (role, configurations) in configurations {
+        let PropertyListValue::Array(configurations) = configurations else {
+            continue;
+        };
+        for configuration in configurations {
+            let PropertyListValue::Dictionary(configuration) = configuration else {
+                continue;
+            };
+            if configuration.contains_key("UISceneStoryboardFile") {
+                error!(
+                    role,
+                    "storyboards are not supported in Bevy, remove `UISceneStoryboardFile` from \
+                     `UISceneConfigurations` in Info.plist"
+                );
+            }
+            if let Some(PropertyListValue::String(class)) =
+                configuration.get("UISceneDelegateClassName")
+            {
+                warn!(
+                    role,
+                    class,
+                    "Bevy uses its own scene delegate, `UISceneDelegateClassName` in Info.plist \
+                     is ignored"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+use bevy_ecs::{entity::Entity, message::Message};
+use objc2_foundation::{NSProcessInfo, NSTimeInterval};
+use objc2_ui_kit::{UIEvent, UIEventSubtype, UIEventType};
+
+/// An event that UIKit delivered to a window, before it was dispatched further.
+///
+/// Only emitted when [`UIKitSettings::raw_events`](crate::UIKitSettings::raw_events) is enabled.
+/// This is intended for advanced users implementing input schemes that this crate does not
+/// cover, see also [`UIKitSettings::raw_event_filter`](crate::UIKitSettings::raw_event_filter).
+///
+/// The message is delivered in the next update, which is usually the one caused by the input that
+/// the event contains.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct RawUIEvent {
+    /// The window that received the event.
+    pub window: Entity,
+    /// The type of the event.
+    pub event_type: UIEventType,
+    /// The subtype of the event (for motion and remote control events).
+    pub subtype: UIEventSubtype,
+    /// The time when the event occurred, in seconds since system startup.
+    pub timestamp: f64,
+    /// The time when the event occurred, in the same time base as Bevy's `Time`.
+    ///
+    /// This is derived from `timestamp`, and is usually earlier than when the message is received.
+    pub instant: Instant,
+}
+
+impl RawUIEvent {
+    pub(crate) fn new(window: Entity, event: &UIEvent) -> Self {
+        Self {
+            window,
+            event_type: event.r#type(),
+            subtype: event.subtype(),
+            timestamp: event.timestamp(),
+            instant: instant_from_timestamp(event.timestamp()),
+        }
+    }
+}
+
+/// The time when a touch was last updated, sent alongside each
+/// [`TouchInput`](bevy_input::touch::TouchInput) with the same `id`.
+///
+/// Bevy's `TouchInput` carries no timestamp, so this is sent alongside it for applications that
+/// need precise timing, such as rhythm games or velocity estimation.
+///
+/// The two are separate messages, so read both in the same system and correlate them by `window`
+/// and `id`. A timestamp is always delivered in the same update as its touch, but a touch can move
+/// several times per update, in which case there is a timestamp for each `TouchInput`, in the same
+/// order.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TouchTimestamp {
+    /// The window that received the touch.
+    pub window: Entity,
+    /// The [`TouchInput::id`](bevy_input::touch::TouchInput::id) of the touch.
+    pub id: u64,
+    /// The time when the touch changed, derived from `UITouch.timestamp`.
+    pub instant: Instant,
+}
+
+/// Convert a UIKit timestamp (such as `UIEvent.timestamp` or `UITouch.timestamp`), which is
+/// measured in seconds since system startup, to an [`Instant`].
+pub(crate) fn instant_from_timestamp(timestamp: NSTimeInterval) -> Instant {
+    let now = Instant::now();
+    let uptime = NSProcessInfo::processInfo().systemUptime();
+    // Events are never from the future, but guard against rounding anyway.
+    let elapsed = Duration::try_from_secs_f64(uptime - timestamp).unwrap_or(Duration::ZERO);
+    now.checked_sub(elapsed).unwrap_or(now)
+}
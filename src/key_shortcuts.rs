@@ -0,0 +1,245 @@
+use std::any::type_name;
+use std::marker::PhantomData;
+use std::time::Instant;
+
+use bevy_app::{App, Last, Plugin, PreUpdate};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::Entity,
+    message::{Message, MessageReader, MessageWriter},
+    resource::Resource,
+    system::{NonSendMarker, Res},
+};
+use objc2::rc::Retained;
+use objc2::{sel, DefinedClass as _, MainThreadMarker};
+use objc2_foundation::{NSArray, NSString};
+use objc2_ui_kit::{UIKeyCommand, UIKeyModifierFlags};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// The modifier keys that must be held for a [`KeyShortcut`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ShortcutModifiers {
+    /// The Command (⌘) key.
+    pub command: bool,
+    /// The Shift (⇧) key.
+    pub shift: bool,
+    /// The Option (⌥) key.
+    pub option: bool,
+    /// The Control (⌃) key.
+    pub control: bool,
+}
+
+impl ShortcutModifiers {
+    /// Only the Command key.
+    pub const COMMAND: Self = Self {
+        command: true,
+        shift: false,
+        option: false,
+        control: false,
+    };
+
+    fn flags(self) -> UIKeyModifierFlags {
+        let mut flags = UIKeyModifierFlags::empty();
+        flags.set(UIKeyModifierFlags::Command, self.command);
+        flags.set(UIKeyModifierFlags::Shift, self.shift);
+        flags.set(UIKeyModifierFlags::Alternate, self.option);
+        flags.set(UIKeyModifierFlags::Control, self.control);
+        flags
+    }
+}
+
+/// A hardware keyboard shortcut that sends an action message, see [`KeyShortcutsPlugin`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyShortcut<A> {
+    /// The key that triggers the shortcut, as the characters it types, such as `"s"`, or the value
+    /// of one of UIKit's `UIKeyInput*` constants, such as `"\u{1b}"` for Escape.
+    pub input: String,
+    /// The modifier keys that must be held.
+    pub modifiers: ShortcutModifiers,
+    /// A description of the action, shown in the shortcut overlay when the user holds the Command
+    /// key on iPad. Shortcuts without a title are not shown there.
+    pub title: Option<String>,
+    /// The message sent when the shortcut is pressed.
+    pub action: A,
+}
+
+/// The keyboard shortcuts that send `A`, see [`KeyShortcutsPlugin`].
+#[derive(Resource, Debug, Clone)]
+pub struct KeyShortcuts<A: Message> {
+    /// The shortcuts, in order of priority.
+    pub shortcuts: Vec<KeyShortcut<A>>,
+}
+
+impl<A: Message> Default for KeyShortcuts<A> {
+    fn default() -> Self {
+        Self {
+            shortcuts: Vec::new(),
+        }
+    }
+}
+
+impl<A: Message> KeyShortcuts<A> {
+    /// Add a shortcut with a title.
+    pub fn with(
+        mut self,
+        input: impl Into<String>,
+        modifiers: ShortcutModifiers,
+        title: impl Into<String>,
+        action: A,
+    ) -> Self {
+        self.shortcuts.push(KeyShortcut {
+            input: input.into(),
+            modifiers,
+            title: Some(title.into()),
+            action,
+        });
+        self
+    }
+}
+
+/// Send the application-defined message `A` when one of the [`KeyShortcuts<A>`] is pressed on a
+/// hardware keyboard, instead of having to match on raw key codes.
+///
+/// The shortcuts are registered as `UIKeyCommand`s on Bevy's view in every window, so they are
+/// only delivered while it's the first responder. This plugin can be added once for each message
+/// type.
+///
+/// ```ignore
+/// #[derive(Message, Clone)]
+/// enum Action {
+///     Save,
+///     Undo,
+/// }
+///
+/// app.add_plugins(KeyShortcutsPlugin::<Action>::default())
+///     .insert_resource(
+///         KeyShortcuts::default()
+///             .with("s", ShortcutModifiers::COMMAND, "Save", Action::Save)
+///             .with("z", ShortcutModifiers::COMMAND, "Undo", Action::Undo),
+///     );
+/// ```
+pub struct KeyShortcutsPlugin<A>(PhantomData<fn() -> A>);
+
+impl<A> Default for KeyShortcutsPlugin<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Message + Clone> Plugin for KeyShortcutsPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KeyShortcuts<A>>()
+            .add_message::<A>()
+            .add_systems(PreUpdate, send_shortcut_actions::<A>)
+            .add_systems(Last, update_key_shortcuts::<A>);
+    }
+}
+
+/// A key command created from [`KeyShortcuts`] was pressed.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct KeyShortcutPressed {
+    pub(crate) window: Entity,
+    /// The type name of the action message.
+    pub(crate) set: &'static str,
+    /// The index in [`KeyShortcuts::shortcuts`].
+    pub(crate) index: usize,
+    pub(crate) instant: Instant,
+}
+
+/// The time when a key shortcut was pressed, sent right after each action message from
+/// [`KeyShortcuts`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyShortcutTimestamp {
+    /// The window that Bevy's view is the first responder in.
+    pub window: Entity,
+    /// The time when UIKit performed the key command.
+    ///
+    /// UIKit does not expose the key event to key commands, so this is taken when the command is
+    /// handled, which happens synchronously while the event is being dispatched.
+    pub instant: Instant,
+}
+
+/// Find which shortcut a key command that was performed belongs to.
+///
+/// UIKit may pass a copy of the registered command, so they are compared by value.
+pub(crate) fn find_shortcut(
+    sets: &[(&'static str, Retained<NSArray<UIKeyCommand>>)],
+    command: &UIKeyCommand,
+) -> Option<(&'static str, usize)> {
+    sets.iter().find_map(|(set, commands)| {
+        let index = commands
+            .iter()
+            .position(|registered| *registered == *command)?;
+        Some((*set, index))
+    })
+}
+
+fn update_key_shortcuts<A: Message>(
+    shortcuts: Res<KeyShortcuts<A>>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    // Also update when windows were registered with UIKit.
+    if !shortcuts.is_changed() && !uikit_windows.is_changed() {
+        return;
+    }
+    let set = type_name::<A>();
+    let commands = key_commands(&shortcuts, mtm);
+    for (entity, uikit_window) in uikit_windows.iter(mtm) {
+        let Some(view) = uikit_window.view() else {
+            continue;
+        };
+        trace!(?entity, set, "updating key shortcuts");
+        let mut sets = view.ivars().shortcut_commands.borrow_mut();
+        sets.retain(|(existing, _)| *existing != set);
+        sets.push((set, commands.clone()));
+    }
+}
+
+fn key_commands<A: Message>(
+    shortcuts: &KeyShortcuts<A>,
+    mtm: MainThreadMarker,
+) -> Retained<NSArray<UIKeyCommand>> {
+    let commands: Vec<_> = shortcuts
+        .shortcuts
+        .iter()
+        .map(|shortcut| {
+            // SAFETY: The selector is implemented by Bevy's view.
+            let command = unsafe {
+                UIKeyCommand::keyCommandWithInput_modifierFlags_action(
+                    &NSString::from_str(&shortcut.input),
+                    shortcut.modifiers.flags(),
+                    sel!(performKeyShortcut:),
+                    mtm,
+                )
+            };
+            if let Some(title) = &shortcut.title {
+                command.setDiscoverabilityTitle(Some(&NSString::from_str(title)));
+            }
+            command
+        })
+        .collect();
+    NSArray::from_retained_slice(&commands)
+}
+
+fn send_shortcut_actions<A: Message + Clone>(
+    mut pressed: MessageReader<KeyShortcutPressed>,
+    shortcuts: Res<KeyShortcuts<A>>,
+    mut actions: MessageWriter<A>,
+    mut timestamps: MessageWriter<KeyShortcutTimestamp>,
+) {
+    let set = type_name::<A>();
+    for pressed in pressed.read().filter(|pressed| pressed.set == set) {
+        if let Some(shortcut) = shortcuts.shortcuts.get(pressed.index) {
+            trace!(window = ?pressed.window, set, index = pressed.index, "key shortcut");
+            actions.write(shortcut.action.clone());
+            timestamps.write(KeyShortcutTimestamp {
+                window: pressed.window,
+                instant: pressed.instant,
+            });
+        }
+    }
+}
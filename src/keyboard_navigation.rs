@@ -0,0 +1,147 @@
+use std::time::Instant;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    message::Message,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use objc2::rc::Retained;
+use objc2::{available, sel, DefinedClass as _, MainThreadMarker};
+use objc2_foundation::{ns_string, NSArray, NSString};
+use objc2_ui_kit::{
+    UIKeyCommand, UIKeyInputDownArrow, UIKeyInputLeftArrow, UIKeyInputRightArrow,
+    UIKeyInputUpArrow, UIKeyModifierFlags,
+};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// A focus navigation intent from a hardware keyboard, see [`KeyboardNavigation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FocusDirection {
+    /// Move focus to the next element (Tab).
+    Next,
+    /// Move focus to the previous element (Shift-Tab).
+    Previous,
+    /// Move focus up (up arrow).
+    Up,
+    /// Move focus down (down arrow).
+    Down,
+    /// Move focus left (left arrow).
+    Left,
+    /// Move focus right (right arrow).
+    Right,
+    /// Activate the focused element (Space).
+    Activate,
+}
+
+impl FocusDirection {
+    /// The key input and modifiers of the command for each direction.
+    fn commands() -> [(&'static NSString, UIKeyModifierFlags, Self); 7] {
+        let none = UIKeyModifierFlags::empty();
+        // SAFETY: The key inputs are immutable constants.
+        unsafe {
+            [
+                (ns_string!("\t"), none, Self::Next),
+                (ns_string!("\t"), UIKeyModifierFlags::Shift, Self::Previous),
+                (UIKeyInputUpArrow, none, Self::Up),
+                (UIKeyInputDownArrow, none, Self::Down),
+                (UIKeyInputLeftArrow, none, Self::Left),
+                (UIKeyInputRightArrow, none, Self::Right),
+                (ns_string!(" "), none, Self::Activate),
+            ]
+        }
+    }
+
+    pub(crate) fn from_key_command(command: &UIKeyCommand) -> Option<Self> {
+        let input = command.input()?;
+        let modifiers = command.modifierFlags();
+        Self::commands()
+            .into_iter()
+            .find(|(command_input, command_modifiers, _)| {
+                **command_input == *input && *command_modifiers == modifiers
+            })
+            .map(|(_, _, direction)| direction)
+    }
+}
+
+/// Deliver Tab, Shift-Tab, the arrow keys and Space from hardware keyboards as
+/// [`FocusNavigationRequested`] messages, for implementing keyboard-only navigation of Bevy UI.
+///
+/// The keys take priority over the system's own focus navigation, including Full Keyboard Access
+/// on iPadOS and Mac Catalyst. Note that there is no public API for detecting whether Full
+/// Keyboard Access is enabled, so consider always enabling this when a hardware keyboard is used.
+/// Add this to the entity of the [`Window`](bevy_window::Window).
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KeyboardNavigation;
+
+/// The user requested moving focus with the keyboard, see [`KeyboardNavigation`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FocusNavigationRequested {
+    /// The window that Bevy's view is the first responder in.
+    pub window: Entity,
+    /// The requested direction.
+    pub direction: FocusDirection,
+    /// The time when the key command was performed.
+    ///
+    /// Key commands don't carry their event, so this is slightly later than the key press.
+    pub instant: Instant,
+}
+
+/// Propagate [`KeyboardNavigation`] to the native view.
+pub fn update_keyboard_navigation(
+    navigations: Query<(Entity, Ref<KeyboardNavigation>)>,
+    mut removed: RemovedComponents<KeyboardNavigation>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, navigation) in &navigations {
+        // Also update when the window was only just registered with UIKit.
+        if !navigation.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            trace!(?entity, "enabling keyboard navigation");
+            *view.ivars().navigation_commands.borrow_mut() = Some(key_commands(mtm));
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            view.ivars().navigation_commands.take();
+        }
+    }
+}
+
+fn key_commands(mtm: MainThreadMarker) -> Retained<NSArray<UIKeyCommand>> {
+    let commands: Vec<_> = FocusDirection::commands()
+        .into_iter()
+        .map(|(input, modifiers, _)| {
+            // SAFETY: The selector is implemented by Bevy's view.
+            let command = unsafe {
+                UIKeyCommand::keyCommandWithInput_modifierFlags_action(
+                    input,
+                    modifiers,
+                    sel!(navigateFocus:),
+                    mtm,
+                )
+            };
+            if available!(ios = 15.0, ..) {
+                command.setWantsPriorityOverSystemBehavior(true);
+            }
+            command
+        })
+        .collect();
+    NSArray::from_retained_slice(&commands)
+}
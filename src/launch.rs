@@ -0,0 +1,91 @@
+use std::mem;
+use std::time::{Duration, SystemTime};
+
+use bevy_ecs::{resource::Resource, world::World};
+use tracing::{debug, warn};
+
+use crate::UIKitWindows;
+
+/// Timing milestones of the application launch, for measuring and optimizing startup time.
+///
+/// The milestones are recorded as they happen, so they're `None` until reached.
+///
+/// Note that the system may launch the process ahead of time to reduce launch times
+/// ("prewarming"), in which case [`process_start`](Self::process_start) can be long before the
+/// user actually launched the application, and should not be used as the reference point.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct LaunchTimings {
+    /// Whether the process was prewarmed by the system.
+    pub prewarmed: bool,
+    /// When the process was started.
+    pub process_start: Option<SystemTime>,
+    /// When `application:willFinishLaunchingWithOptions:` was called.
+    pub will_finish_launching: Option<SystemTime>,
+    /// When the first scene connected, or `None` when not using scenes.
+    pub first_scene_connected: Option<SystemTime>,
+    /// When the first update after a window was created finished.
+    pub first_frame: Option<SystemTime>,
+}
+
+impl LaunchTimings {
+    pub(crate) fn new() -> Self {
+        // Set by the system when launching a prewarmed process.
+        let prewarmed = std::env::var_os("ActivePrewarm").is_some_and(|value| value == "1");
+        Self {
+            prewarmed,
+            process_start: process_start(),
+            will_finish_launching: None,
+            first_scene_connected: None,
+            first_frame: None,
+        }
+    }
+
+    /// The time from the process was started until the given milestone, if both are known.
+    ///
+    /// When [prewarmed](Self::prewarmed), consider measuring from
+    /// [`will_finish_launching`](Self::will_finish_launching) instead.
+    pub fn since_process_start(&self, milestone: Option<SystemTime>) -> Option<Duration> {
+        milestone?.duration_since(self.process_start?).ok()
+    }
+}
+
+/// Record [`LaunchTimings::first_frame`] if not already set, and a window exists.
+pub(crate) fn record_first_frame(world: &mut World) {
+    if world.resource::<LaunchTimings>().first_frame.is_some()
+        || world.resource::<UIKitWindows>().entities().next().is_none()
+    {
+        return;
+    }
+    let mut timings = world.resource_mut::<LaunchTimings>();
+    timings.first_frame = Some(SystemTime::now());
+    debug!(
+        prewarmed = timings.prewarmed,
+        time_to_first_frame = ?timings.since_process_start(timings.first_frame),
+        "first frame"
+    );
+}
+
+fn process_start() -> Option<SystemTime> {
+    // SAFETY: `proc_bsdinfo` is plain old data, so all zeroes is a valid value.
+    let mut info: libc::proc_bsdinfo = unsafe { mem::zeroed() };
+    let size = mem::size_of::<libc::proc_bsdinfo>() as libc::c_int;
+    // SAFETY: The buffer is valid for writes of the given size.
+    let written = unsafe {
+        libc::proc_pidinfo(
+            libc::getpid(),
+            libc::PROC_PIDTBSDINFO,
+            0,
+            (&raw mut info).cast(),
+            size,
+        )
+    };
+    if written != size {
+        warn!("failed getting process start time");
+        return None;
+    }
+    Some(
+        SystemTime::UNIX_EPOCH
+            + Duration::from_secs(info.pbi_start_tvsec)
+            + Duration::from_micros(info.pbi_start_tvusec),
+    )
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use bevy_ecs::{
+    resource::Resource,
+    system::{Res, ResMut},
+};
+use objc2_foundation::NSProcessInfo;
+use tracing::debug;
+
+use crate::{DebugOverlay, UIKitSettings};
+
+/// The arguments and environment variables that the process was launched with.
+///
+/// These can be configured in the Xcode scheme, or passed with `xcrun devicectl device process
+/// launch`, which makes them useful for debug toggles and UI testing on devices.
+///
+/// A few flags are recognized by this crate itself, and applied at startup:
+/// - `-BevyUIKitDebugOverlay`: Enable the [`DebugOverlay`].
+/// - `-BevyUIKitRawEvents`: Enable [`UIKitSettings::raw_events`].
+/// - `-BevyUIKitBatchedInput YES|NO`: Override [`UIKitSettings::batched_input`].
+/// - `-BevyUIKitTraceCallbacks`: Log every UIKit delegate callback at the `info` level.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LaunchArguments {
+    /// The arguments, excluding the executable path.
+    pub arguments: Vec<String>,
+    /// The environment variables.
+    pub environment: HashMap<String, String>,
+}
+
+impl LaunchArguments {
+    pub(crate) fn new() -> Self {
+        let process_info = NSProcessInfo::processInfo();
+        let arguments = process_info
+            .arguments()
+            .iter()
+            .skip(1)
+            .map(|argument| argument.to_string())
+            .collect();
+        let (keys, values) = process_info.environment().to_vecs();
+        let environment = keys
+            .iter()
+            .zip(&values)
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        Self {
+            arguments,
+            environment,
+        }
+    }
+
+    /// The value following `-name` in the arguments, such as `"YES"` for `-name YES`.
+    pub fn value(&self, name: &str) -> Option<&str> {
+        let position = self
+            .arguments
+            .iter()
+            .position(|argument| argument.strip_prefix('-') == Some(name))?;
+        self.arguments
+            .get(position + 1)
+            .filter(|value| !value.starts_with('-'))
+            .map(|value| &**value)
+    }
+
+    /// Whether `-name` was passed, optionally followed by a boolean value such as `YES` or `0`.
+    ///
+    /// An argument following `-name` that is not a boolean is not treated as its value, so the flag
+    /// is then enabled.
+    pub fn flag(&self, name: &str) -> Option<bool> {
+        if !self
+            .arguments
+            .iter()
+            .any(|argument| argument.strip_prefix('-') == Some(name))
+        {
+            return None;
+        }
+        let value = self.value(name).map(str::to_ascii_lowercase);
+        Some(!matches!(value.as_deref(), Some("no" | "false" | "0")))
+    }
+}
+
+static TRACE_CALLBACKS: AtomicBool = AtomicBool::new(false);
+
+/// Whether every UIKit callback should be logged, see [`LaunchArguments`].
+pub(crate) fn trace_callbacks() -> bool {
+    TRACE_CALLBACKS.load(Ordering::Relaxed)
+}
+
+/// Apply the flags that must take effect before the application is started.
+pub(crate) fn apply_early_launch_arguments(arguments: &LaunchArguments) {
+    if arguments.flag("BevyUIKitTraceCallbacks") == Some(true) {
+        TRACE_CALLBACKS.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Apply the flags recognized by this crate, see [`LaunchArguments`].
+pub fn apply_launch_arguments(
+    arguments: Res<LaunchArguments>,
+    mut settings: ResMut<UIKitSettings>,
+    mut debug_overlay: ResMut<DebugOverlay>,
+) {
+    if arguments.flag("BevyUIKitDebugOverlay") == Some(true) {
+        debug!("enabling debug overlay from launch arguments");
+        debug_overlay.enabled = true;
+    }
+    if arguments.flag("BevyUIKitRawEvents") == Some(true) {
+        debug!("enabling raw events from launch arguments");
+        settings.raw_events = true;
+    }
+    if let Some(batched_input) = arguments.flag("BevyUIKitBatchedInput") {
+        debug!(
+            batched_input,
+            "overriding batched input from launch arguments"
+        );
+        settings.batched_input = batched_input;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(arguments: &[&str]) -> LaunchArguments {
+        LaunchArguments {
+            arguments: arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect(),
+            environment: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn value() {
+        let arguments = arguments(&["-Name", "value", "-Other", "-Last"]);
+        assert_eq!(arguments.value("Name"), Some("value"));
+        assert_eq!(arguments.value("Other"), None);
+        assert_eq!(arguments.value("Last"), None);
+        assert_eq!(arguments.value("Missing"), None);
+        assert_eq!(arguments.value("value"), None);
+    }
+
+    #[test]
+    fn flag() {
+        let arguments = arguments(&["-Bare", "-Yes", "YES", "-Off", "0", "-False", "false"]);
+        assert_eq!(arguments.flag("Bare"), Some(true));
+        assert_eq!(arguments.flag("Yes"), Some(true));
+        assert_eq!(arguments.flag("Off"), Some(false));
+        assert_eq!(arguments.flag("False"), Some(false));
+        assert_eq!(arguments.flag("Missing"), None);
+    }
+
+    #[test]
+    fn flag_followed_by_positional_argument() {
+        let arguments = arguments(&["-Flag", "file.txt", "-Last"]);
+        assert_eq!(arguments.value("Flag"), Some("file.txt"));
+        assert_eq!(arguments.flag("Flag"), Some(true));
+        assert_eq!(arguments.flag("Last"), Some(true));
+    }
+}
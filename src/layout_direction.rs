@@ -0,0 +1,101 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    message::{Message, MessageWriter},
+    system::{Commands, NonSendMarker, Query, Res},
+};
+use objc2::MainThreadMarker;
+use objc2_ui_kit::{
+    UIApplication, UITraitEnvironment as _, UITraitEnvironmentLayoutDirection,
+    UIUserInterfaceLayoutDirection,
+};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// The direction that a window's content is laid out in, which follows the user's language.
+///
+/// Inserted on the entity of the [`Window`](bevy_window::Window) once it is registered with UIKit,
+/// and kept up to date. Layouts should be mirrored horizontally in right-to-left windows, such
+/// that content starts at the leading edge, see [`to_left_right`](Self::to_left_right).
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LayoutDirection {
+    /// Content starts at the left, such as for English.
+    #[default]
+    LeftToRight,
+    /// Content starts at the right, such as for Arabic and Hebrew.
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    fn from_uikit(direction: UITraitEnvironmentLayoutDirection, mtm: MainThreadMarker) -> Self {
+        match direction {
+            UITraitEnvironmentLayoutDirection::LeftToRight => Self::LeftToRight,
+            UITraitEnvironmentLayoutDirection::RightToLeft => Self::RightToLeft,
+            // Before the window has a trait collection, use the application's direction.
+            _ => match UIApplication::sharedApplication(mtm).userInterfaceLayoutDirection() {
+                UIUserInterfaceLayoutDirection::RightToLeft => Self::RightToLeft,
+                _ => Self::LeftToRight,
+            },
+        }
+    }
+
+    /// Whether content starts at the right.
+    pub fn is_right_to_left(self) -> bool {
+        self == Self::RightToLeft
+    }
+
+    /// Convert values for the leading and trailing edges, such as insets or margins, to values for
+    /// the left and right edges.
+    ///
+    /// ```ignore
+    /// let (left, right) = direction.to_left_right(Val::Px(16.0), Val::Px(8.0));
+    /// node.padding = UiRect { left, right, ..node.padding };
+    /// ```
+    pub fn to_left_right<T>(self, leading: T, trailing: T) -> (T, T) {
+        match self {
+            Self::LeftToRight => (leading, trailing),
+            Self::RightToLeft => (trailing, leading),
+        }
+    }
+}
+
+/// The [`LayoutDirection`] of a window changed.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LayoutDirectionChanged {
+    /// The window.
+    pub window: Entity,
+    /// The new direction.
+    pub direction: LayoutDirection,
+}
+
+/// Keep [`LayoutDirection`] in sync with the trait collections of the windows.
+///
+/// UIKit updates the application when the traits change, so this is checked on every update.
+pub fn update_layout_directions(
+    mut directions: Query<&mut LayoutDirection>,
+    mut changed: MessageWriter<LayoutDirectionChanged>,
+    mut commands: Commands,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, uikit_window) in uikit_windows.iter(mtm) {
+        let traits = uikit_window.uiwindow.traitCollection();
+        // SAFETY: Accessed on the main thread.
+        let direction = LayoutDirection::from_uikit(unsafe { traits.layoutDirection() }, mtm);
+
+        match directions.get_mut(entity) {
+            Ok(current) if *current == direction => continue,
+            Ok(mut current) => *current = direction,
+            Err(_) => {
+                commands.entity(entity).try_insert(direction);
+            }
+        }
+        trace!(?entity, ?direction, "layout direction changed");
+        changed.write(LayoutDirectionChanged {
+            window: entity,
+            direction,
+        });
+    }
+}
@@ -4,34 +4,328 @@
     html_favicon_url = "https://bevyengine.org/assets/icon.png"
 )]
 
-use bevy_app::{App, Last, Plugin};
+use bevy_app::{App, Last, Plugin, Startup};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::NonSendMarker;
 use objc2::{available, ClassType, MainThreadMarker};
 
+pub use crate::accessibility::{update_accessibility_identifiers, AccessibilityIdentifiers};
 use crate::app::ApplicationDelegate;
 pub use crate::app::{disallow_app_exit, uikit_runner};
+pub use crate::app_clip::AppClipInvocation;
+#[cfg(not(target_os = "tvos"))]
+pub use crate::autofill::{
+    update_autofill_fields, AutofillField, AutofillFields, AutofillInput, TextContentType,
+};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::brightness::ScreenBrightness;
+#[cfg(not(target_os = "tvos"))]
+use crate::brightness::{update_screen_brightness, OriginalBrightness};
+pub use crate::capabilities::{
+    report_unsupported_operations, UnsupportedOperation, UnsupportedWindowOperation,
+    WindowCapabilities,
+};
+pub use crate::close_confirmation::{
+    present_close_confirmations, update_close_buttons, CloseChoice, ConfirmWindowClose,
+    UnsavedChanges, WindowCloseConfirmed,
+};
+pub use crate::companion_window::CompanionWindow;
+pub use crate::coordinates::{
+    CoordinateOrigin, CoordinateSpace, CoordinateUnits, TouchCoordinates,
+};
+pub use crate::debug_overlay::DebugOverlay;
+use crate::debug_overlay::{update_debug_overlay, DebugOverlayWindow};
+pub use crate::deep_link::{
+    DeepLink, DeepLinkOpened, DeepLinkPattern, DeepLinkRoute, DeepLinkRoutes, WindowKind,
+};
+use crate::dictation::DictationObserver;
+pub use crate::dictation::{DictationEnded, DictationStarted};
+pub use crate::display::{update_window_displays, MetalDevice, WindowDisplay, WindowDisplays};
+use crate::display_link::{
+    limit_display_link, observe_application_activity, DisplayLinkHandle, DisplayLinkLimits,
+};
+pub use crate::display_link::{update_display_link, DisplayLink, FrameRateRange, InactiveUpdates};
+pub use crate::document_browser::{
+    close_documents, CloseDocument, DocumentBrowserSettings, DocumentOpened,
+};
+pub use crate::document_window::{update_document_windows, DocumentWindow};
+pub use crate::duplicate_window::{duplicate_windows, DuplicateWindow, WindowPayload};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::edge_swipe::{
+    update_screen_edge_swipes, ScreenEdgeSwiped, ScreenEdgeSwipes, SwipeEdge,
+};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::edit_menu::{
+    present_edit_menus, EditAction, EditMenuActionPerformed, PresentEditMenu,
+};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::find::{
+    present_find_navigators, update_find_results, FindAction, FindRequest, FindResults,
+    PresentFindNavigator,
+};
+#[cfg(feature = "game-kit")]
+pub use crate::game_center::{
+    AccessPointLocation, AuthenticateLocalPlayer, GameCenterAccessPoint,
+    GameCenterAccessPointFrame, GameCenterDismissed, GameCenterPlugin, LocalPlayerAuthenticated,
+    PresentGameCenter,
+};
+#[cfg(feature = "game-controller")]
+pub use crate::game_controller::{
+    GameController, GameControllerConnected, GameControllerDisconnected, GameControllerPlugin,
+    GameControllers,
+};
+pub use crate::gestures::{GestureSettings, GestureTouches, PinchRotationConflict};
+pub use crate::hit_test::{update_hit_test_passthrough, HitTestPassthrough};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::hover::{update_hover_effects, HoverEffect, HoverEffects, HoverRegion, HoverShape};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::ime::{update_ime, MarkedTextChanged, MarkedTextUnderline};
+use crate::info_plist::validate_info_plist;
+pub use crate::input::{RawUIEvent, TouchTimestamp};
+#[cfg(not(target_os = "tvos"))]
+use crate::key_shortcuts::KeyShortcutPressed;
+#[cfg(not(target_os = "tvos"))]
+pub use crate::key_shortcuts::{
+    KeyShortcut, KeyShortcutTimestamp, KeyShortcuts, KeyShortcutsPlugin, ShortcutModifiers,
+};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::keyboard_navigation::{
+    update_keyboard_navigation, FocusDirection, FocusNavigationRequested, KeyboardNavigation,
+};
+pub use crate::launch::LaunchTimings;
+use crate::launch_arguments::apply_early_launch_arguments;
+pub use crate::launch_arguments::{apply_launch_arguments, LaunchArguments};
+pub use crate::layout_direction::{
+    update_layout_directions, LayoutDirection, LayoutDirectionChanged,
+};
+use crate::managed_config::observe_managed_configuration;
+pub use crate::managed_config::{ManagedConfiguration, ManagedConfigurationChanged};
+#[cfg(feature = "metric-kit")]
+pub use crate::metric_kit::{DiagnosticPayloadReceived, MetricKitPlugin, MetricPayloadReceived};
+pub use crate::multitasking::{
+    audit_multitasking_support, MultitaskingIssue, MultitaskingIssuesDetected,
+};
+pub use crate::occlusion::emit_window_occlusion;
+pub use crate::open_file::{FileOpenMode, FileOpened};
+use crate::orientation::DeviceOrientationObserver;
+pub use crate::orientation::{DeviceOrientation, DeviceOrientationChanged, InterfaceOrientation};
+#[cfg(feature = "os-log")]
+pub use crate::os_log::{os_log_layer, OsLogLayer};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::pasteboard::{
+    detect_pasteboard_patterns, DetectPasteboardPatterns, PasteboardPattern,
+    PasteboardPatternsDetected,
+};
+pub use crate::pause_button::{update_pause_buttons, PauseButton, PauseRequested};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::pointer::{
+    update_cursor_visibility, update_pointer_regions, PointerRegion, PointerRegionBehavior,
+    PointerRegions,
+};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::print::{
+    print_documents, PrintCompleted, PrintContentKind, PrintDocument, PrintResult,
+};
+pub use crate::property_list::PropertyListValue;
+use crate::reduce_motion::observe_reduce_motion;
+pub use crate::reduce_motion::{ReduceMotion, ReduceMotionChanged};
+pub use crate::resize::{
+    end_window_resizes, AcknowledgeWindowResize, WindowResizeBegan, WindowResizeEnded,
+    WindowResizing,
+};
+pub use crate::resource_usage::{sample_resource_usage, ResourceUsage};
+pub use crate::scale::{ScaleMode, ScreenScale};
 use crate::scene_delegate::SceneDelegate;
-pub use crate::settings::UIKitSettings;
+pub use crate::scene_session::{
+    despawn_disconnected_windows, DelayWindowDespawn, DisconnectedWindow, SceneDisconnected,
+    SceneSession, SceneSessionsDiscarded,
+};
+pub use crate::screenshot::{
+    capture_window_screenshots, CaptureWindowScreenshot, WindowScreenshotCaptured,
+};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::scribble::{update_scribble_fields, ScribbleField, ScribbleFields, ScribbleInput};
+pub use crate::security_scope::SecurityScopedUrl;
+pub use crate::settings::{InsertPrimaryWindow, RawEventFilter, SpawnSystemWindow, UIKitSettings};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::share::{share_screenshots, ScreenshotShared, ShareResult, ShareScreenshot};
+pub use crate::snapshot_privacy::{update_snapshot_placeholders, SnapshotPlaceholder};
+#[cfg(feature = "core-spotlight")]
+pub use crate::spotlight::{
+    update_spotlight_index, DeleteSpotlightItems, IndexSpotlightItems, SearchableItem,
+    SpotlightItemOpened, SpotlightPlugin,
+};
+pub use crate::stage_manager::StageManagerWindow;
+#[cfg(not(target_os = "tvos"))]
+pub use crate::status_bar::{update_status_bars, StatusBarStyle};
+#[cfg(feature = "store-kit")]
+pub use crate::store_review::{request_reviews, RequestReview};
+pub use crate::surface::{
+    update_window_surfaces, InvalidatedSurface, RenderPaused, WindowSurfaceInvalidated,
+    WindowSurfaceRecreated,
+};
+#[cfg(not(target_os = "tvos"))]
+pub use crate::system_gestures::TouchCancelledBySystem;
+pub use crate::text_settings::{
+    update_text_settings, ContentSizeCategory, TextSettings, TextSettingsChanged,
+};
+use crate::thermal::observe_thermal_state;
+pub use crate::thermal::{
+    update_suggested_quality, SuggestedQuality, ThermalQualityScaling, ThermalState,
+    ThermalStateChanged,
+};
+pub use crate::ubiquitous_store::{
+    UbiquitousKeyValueStore, UbiquitousKeyValueStoreChangeReason, UbiquitousKeyValueStoreChanged,
+    UbiquitousKeyValueStorePlugin,
+};
+pub use crate::user_defaults::{
+    handle_user_defaults, ObserveUserDefault, ReadUserDefault, UserDefaultChanged, UserDefaultRead,
+    WriteUserDefault,
+};
+use crate::user_defaults::{ObservedUserDefaults, UserDefaultsObserver};
 use crate::view::{View, ViewController};
-use crate::windows::BevyWindow;
-pub use windows::{changed_windows, create_windows, despawn_windows, UIKitWindow, UIKitWindows};
+pub use crate::window_schedule::WindowSchedule;
+use crate::windows::{BevyWindow, PendingWindowResizes};
+pub use windows::{
+    activate_windows, bring_windows_to_front, changed_windows, create_windows, despawn_windows,
+    sync_background_color, update_dismissal_animations, ActivateWindow, BringWindowToFront,
+    NativeBackgroundColor, UIKitWindow, UIKitWindows, WindowDismissalAnimation, WorldHelper,
+};
 
+mod accessibility;
 mod app;
+mod app_clip;
+#[cfg(not(target_os = "tvos"))]
+mod autofill;
+#[cfg(not(target_os = "tvos"))]
+mod brightness;
+mod capabilities;
+mod close_confirmation;
+mod companion_window;
+mod coordinates;
+mod debug_overlay;
+mod deep_link;
+mod dictation;
+mod display;
+mod display_link;
+mod document_browser;
+mod document_window;
+mod duplicate_window;
+#[cfg(not(target_os = "tvos"))]
+mod edge_swipe;
+#[cfg(not(target_os = "tvos"))]
+mod edit_menu;
+#[cfg(not(target_os = "tvos"))]
+mod find;
+#[cfg(feature = "game-kit")]
+mod game_center;
+#[cfg(feature = "game-controller")]
+mod game_controller;
+mod gestures;
+mod hit_test;
+#[cfg(not(target_os = "tvos"))]
+mod hover;
+#[cfg(not(target_os = "tvos"))]
+mod ime;
+mod info_plist;
+mod input;
+#[cfg(not(target_os = "tvos"))]
+mod key_shortcuts;
+#[cfg(not(target_os = "tvos"))]
+mod keyboard_navigation;
+mod launch;
+mod launch_arguments;
+mod layout_direction;
+mod managed_config;
+#[cfg(feature = "metric-kit")]
+mod metric_kit;
+mod multitasking;
+mod occlusion;
+mod open_file;
+mod orientation;
+#[cfg(any(feature = "panic-hook", feature = "os-log"))]
+mod os_log;
+#[cfg(feature = "panic-hook")]
+mod panic_hook;
+#[cfg(not(target_os = "tvos"))]
+mod pasteboard;
+mod pause_button;
+#[cfg(not(target_os = "tvos"))]
+mod pointer;
+#[cfg(not(target_os = "tvos"))]
+mod print;
+mod property_list;
+mod reduce_motion;
+mod resize;
+mod resource_usage;
+mod scale;
 mod scene_delegate;
+mod scene_session;
+mod screenshot;
+#[cfg(not(target_os = "tvos"))]
+mod scribble;
+mod security_scope;
 mod settings;
+#[cfg(not(target_os = "tvos"))]
+mod share;
+mod signpost;
+mod snapshot_privacy;
+#[cfg(feature = "core-spotlight")]
+mod spotlight;
+mod stage_manager;
+#[cfg(not(target_os = "tvos"))]
+mod status_bar;
+#[cfg(feature = "store-kit")]
+mod store_review;
+mod surface;
+#[cfg(not(target_os = "tvos"))]
+mod system_gestures;
+mod text_settings;
+mod thermal;
+#[cfg(not(target_os = "tvos"))]
+mod trackpad;
+mod ubiquitous_store;
+mod user_defaults;
 mod view;
+mod window_schedule;
 mod windows;
 
 // Used to pass the newly created window entity ID to `scene:willConnectToSession:options:`.
 pub(crate) const WINDOW_ACTIVITY_TYPE: &str = "org.bevyengine.internal.new-window";
 pub(crate) const USER_INFO_WINDOW_ENTITY_ID: &str = "BevyWindowEntityId";
+pub(crate) const USER_INFO_WINDOW_PAYLOAD: &str = "BevyWindowPayload";
 
 #[derive(Default)]
 pub struct UIKitPlugin;
 
 /// A marker used to statically know that a system runs on the main thread.
+#[deprecated = "use `NonSendMarker` with `main_thread_marker` instead"]
 #[derive(Debug)]
 pub struct MainThread(MainThreadMarker);
 
+#[allow(deprecated, reason = "kept for one release for compatibility")]
+impl MainThread {
+    /// The marker for the main thread.
+    pub fn marker(&self) -> MainThreadMarker {
+        self.0
+    }
+}
+
+/// Get a [`MainThreadMarker`] inside a system.
+///
+/// The [`NonSendMarker`] makes Bevy run the system on the main thread, so a system can call UIKit
+/// by taking it as a parameter:
+///
+/// ```ignore
+/// fn my_system(main_thread: NonSendMarker) {
+///     let mtm = bevy_uikit::main_thread_marker(main_thread);
+///     // ...
+/// }
+/// ```
+pub fn main_thread_marker(_: NonSendMarker) -> MainThreadMarker {
+    MainThreadMarker::new().expect("systems with `NonSendMarker` must run on the main thread")
+}
+
 impl Plugin for UIKitPlugin {
     fn name(&self) -> &str {
         "bevy_uikit::UIKitPlugin"
@@ -41,6 +335,12 @@ impl Plugin for UIKitPlugin {
         let mtm = MainThreadMarker::new()
             .expect("must build the App on the main thread when using UIKit");
 
+        #[cfg(feature = "panic-hook")]
+        panic_hook::install();
+        validate_info_plist();
+        let launch_arguments = LaunchArguments::new();
+        apply_early_launch_arguments(&launch_arguments);
+
         // Initialize classes with Objective-C runtime.
         let _ = ApplicationDelegate::class();
         let _ = BevyWindow::class();
@@ -50,11 +350,181 @@ impl Plugin for UIKitPlugin {
             let _ = SceneDelegate::class();
         }
 
-        app.init_non_send_resource::<UIKitWindows>()
-            .insert_non_send_resource(MainThread(mtm))
+        let (managed_configuration, managed_configuration_observer) =
+            observe_managed_configuration(mtm);
+        let (thermal_state, thermal_state_observer) = observe_thermal_state(mtm);
+        let (reduce_motion, reduce_motion_observer) = observe_reduce_motion(mtm);
+        let (activity, activity_observer) = observe_application_activity(mtm);
+
+        #[allow(deprecated, reason = "kept for one release for compatibility")]
+        app.insert_non_send_resource(MainThread(mtm));
+
+        app.init_resource::<UIKitWindows>()
+            .init_resource::<PendingWindowResizes>()
+            .init_resource::<WindowDisplays>()
+            .init_resource::<DisplayLinkHandle>()
+            .init_resource::<DisplayLinkLimits>()
+            .init_resource::<WindowCapabilities>()
             .init_resource::<UIKitSettings>()
+            .init_resource::<DebugOverlay>()
+            .init_resource::<DeepLinkRoutes>()
+            .init_resource::<DebugOverlayWindow>()
+            .insert_resource(LaunchTimings::new())
+            .insert_resource(launch_arguments)
+            .insert_resource(DeviceOrientationObserver::new(mtm))
+            .insert_resource(DictationObserver::new(mtm))
+            .insert_resource(managed_configuration)
+            .insert_resource(managed_configuration_observer)
+            .insert_resource(thermal_state)
+            .insert_resource(thermal_state_observer)
+            .insert_resource(reduce_motion)
+            .insert_resource(reduce_motion_observer)
+            .insert_resource(activity)
+            .insert_resource(activity_observer)
+            .init_resource::<SuggestedQuality>()
+            .init_resource::<ResourceUsage>()
+            .init_resource::<ObservedUserDefaults>()
+            .insert_resource(UserDefaultsObserver::new(mtm))
             .set_runner(uikit_runner)
+            .add_message::<AppClipInvocation>()
+            .add_message::<CaptureWindowScreenshot>()
+            .add_message::<WindowScreenshotCaptured>()
+            .add_message::<RawUIEvent>()
+            .add_message::<TouchTimestamp>()
+            .add_message::<DocumentOpened>()
+            .add_message::<CloseDocument>()
+            .add_message::<FileOpened>()
+            .add_message::<DeepLinkOpened>()
+            .add_message::<DeviceOrientationChanged>()
+            .add_message::<DictationStarted>()
+            .add_message::<DictationEnded>()
+            .add_message::<ActivateWindow>()
+            .add_message::<SceneDisconnected>()
+            .add_message::<SceneSessionsDiscarded>()
+            .add_message::<BringWindowToFront>()
+            .add_message::<ManagedConfigurationChanged>()
+            .add_message::<ThermalStateChanged>()
+            .add_message::<ReduceMotionChanged>()
+            .add_message::<WindowResizeBegan>()
+            .add_message::<WindowResizeEnded>()
+            .add_message::<AcknowledgeWindowResize>()
+            .add_message::<WindowSurfaceInvalidated>()
+            .add_message::<WindowSurfaceRecreated>()
+            .add_message::<ReadUserDefault>()
+            .add_message::<UserDefaultRead>()
+            .add_message::<WriteUserDefault>()
+            .add_message::<ObserveUserDefault>()
+            .add_message::<UserDefaultChanged>()
+            .add_message::<MultitaskingIssuesDetected>()
+            .add_message::<TextSettingsChanged>()
+            .add_message::<ConfirmWindowClose>()
+            .add_message::<WindowCloseConfirmed>()
+            .add_message::<DuplicateWindow>()
+            .add_message::<LayoutDirectionChanged>()
+            .add_message::<PauseRequested>()
+            .add_message::<UnsupportedWindowOperation>()
+            .add_systems(
+                Startup,
+                (audit_multitasking_support, apply_launch_arguments),
+            )
             .add_systems(Last, disallow_app_exit)
-            .add_systems(Last, (create_windows, changed_windows, despawn_windows));
+            .add_systems(
+                Last,
+                (
+                    create_windows,
+                    // Must run before, so that the new windows are created in the same update.
+                    duplicate_windows.before(create_windows),
+                    activate_windows,
+                    bring_windows_to_front,
+                    changed_windows,
+                    despawn_windows,
+                    // Must run after, since despawned windows also have their components removed.
+                    update_dismissal_animations.after(despawn_windows),
+                    despawn_disconnected_windows,
+                    sync_background_color,
+                    update_hit_test_passthrough,
+                    update_accessibility_identifiers,
+                    update_window_displays,
+                    update_window_surfaces,
+                ),
+            )
+            .add_systems(
+                Last,
+                (
+                    capture_window_screenshots,
+                    update_debug_overlay,
+                    close_documents,
+                    handle_user_defaults,
+                    update_suggested_quality,
+                    sample_resource_usage,
+                    end_window_resizes,
+                    update_snapshot_placeholders,
+                    update_document_windows,
+                    update_text_settings,
+                    emit_window_occlusion,
+                    // Must run after, since that resets the close button.
+                    update_close_buttons.after(changed_windows),
+                    present_close_confirmations,
+                    update_layout_directions,
+                    // Must run after, so that windows are paused in the same update.
+                    limit_display_link
+                        .after(update_suggested_quality)
+                        .after(update_window_surfaces)
+                        .before(update_display_link),
+                    update_display_link,
+                    update_pause_buttons,
+                    report_unsupported_operations,
+                ),
+            );
+
+        #[cfg(not(target_os = "tvos"))]
+        app.init_resource::<ScreenBrightness>()
+            .init_resource::<OriginalBrightness>()
+            .add_message::<PrintDocument>()
+            .add_message::<PrintCompleted>()
+            .add_message::<ScreenEdgeSwiped>()
+            .add_message::<ScribbleInput>()
+            .add_message::<FindRequest>()
+            .add_message::<PresentFindNavigator>()
+            .add_message::<ShareScreenshot>()
+            .add_message::<ScreenshotShared>()
+            .add_message::<PresentEditMenu>()
+            .add_message::<EditMenuActionPerformed>()
+            .add_message::<FocusNavigationRequested>()
+            .add_message::<DetectPasteboardPatterns>()
+            .add_message::<PasteboardPatternsDetected>()
+            .add_message::<KeyShortcutPressed>()
+            .add_message::<KeyShortcutTimestamp>()
+            .add_message::<AutofillInput>()
+            .add_message::<MarkedTextChanged>()
+            .add_message::<TouchCancelledBySystem>()
+            .add_systems(
+                Last,
+                (
+                    print_documents,
+                    update_pointer_regions,
+                    update_cursor_visibility,
+                    update_screen_edge_swipes,
+                    update_scribble_fields,
+                    update_find_results,
+                    present_find_navigators.after(update_find_results),
+                    share_screenshots,
+                    update_hover_effects,
+                    present_edit_menus,
+                    update_status_bars,
+                    update_screen_brightness,
+                    update_keyboard_navigation,
+                    detect_pasteboard_patterns,
+                    update_autofill_fields,
+                    update_ime,
+                ),
+            );
+
+        #[cfg(feature = "core-spotlight")]
+        app.add_message::<SpotlightItemOpened>();
+
+        #[cfg(feature = "store-kit")]
+        app.add_message::<RequestReview>()
+            .add_systems(Last, request_reviews);
     }
 }
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+use std::ptr::NonNull;
+
+use bevy_ecs::{message::Message, resource::Resource};
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::MainThreadMarker;
+use objc2_foundation::{
+    ns_string, NSNotification, NSNotificationCenter, NSObjectProtocol, NSOperationQueue,
+    NSUserDefaults, NSUserDefaultsDidChangeNotification,
+};
+use tracing::{trace, warn};
+
+use crate::app::with_world;
+use crate::PropertyListValue;
+
+/// The user defaults key that MDM servers push managed app configuration to.
+const MANAGED_CONFIGURATION_KEY: &str = "com.apple.configuration.managed";
+
+/// The managed app configuration pushed by an MDM (Mobile Device Management) server.
+///
+/// Enterprise deployments can use this to configure the application, e.g. with server URLs or
+/// feature flags. Empty if the device is not managed, or no configuration was pushed.
+///
+/// Kept up to date while the application is running, see [`ManagedConfigurationChanged`].
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct ManagedConfiguration {
+    /// The configuration values.
+    pub values: HashMap<String, PropertyListValue>,
+}
+
+impl ManagedConfiguration {
+    /// Get a configuration value.
+    pub fn get(&self, key: &str) -> Option<&PropertyListValue> {
+        self.values.get(key)
+    }
+
+    fn read() -> Self {
+        let defaults = NSUserDefaults::standardUserDefaults();
+        let values = match defaults
+            .objectForKey(ns_string!(MANAGED_CONFIGURATION_KEY))
+            .as_deref()
+            .and_then(PropertyListValue::from_object)
+        {
+            Some(PropertyListValue::Dictionary(values)) => values,
+            Some(value) => {
+                warn!(?value, "managed configuration was not a dictionary");
+                HashMap::new()
+            }
+            None => HashMap::new(),
+        };
+        Self { values }
+    }
+}
+
+/// The [`ManagedConfiguration`] was changed by the MDM server.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManagedConfigurationChanged;
+
+/// Keeps the user defaults notification observer alive.
+#[derive(Resource, Debug)]
+pub(crate) struct ManagedConfigurationObserver(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<Retained<ProtocolObject<dyn NSObjectProtocol>>>,
+);
+
+/// Read the current [`ManagedConfiguration`], and observe changes to it.
+pub(crate) fn observe_managed_configuration(
+    mtm: MainThreadMarker,
+) -> (ManagedConfiguration, ManagedConfigurationObserver) {
+    let block = RcBlock::new(move |_notification: NonNull<NSNotification>| {
+        // Posted for all changes to user defaults, so check whether the configuration changed.
+        let configuration = ManagedConfiguration::read();
+        with_world(mtm, move |world| {
+            if *world.resource::<ManagedConfiguration>() != configuration {
+                trace!(?configuration, "managed configuration changed");
+                world.insert_resource(configuration);
+                world.write_message(ManagedConfigurationChanged);
+            }
+        });
+    });
+    // SAFETY: The block has the correct signature, and is run on the main queue.
+    let observer = unsafe {
+        NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+            Some(NSUserDefaultsDidChangeNotification),
+            None,
+            Some(&NSOperationQueue::mainQueue()),
+            &block,
+        )
+    };
+    (
+        ManagedConfiguration::read(),
+        ManagedConfigurationObserver(MainThreadBound::new(observer, mtm)),
+    )
+}
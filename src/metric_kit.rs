@@ -0,0 +1,115 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use bevy_app::{App, Plugin};
+use bevy_ecs::{message::Message, resource::Resource};
+use dispatch2::{DispatchQueue, MainThreadBound};
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{define_class, msg_send, AllocAnyThread, MainThreadMarker};
+use objc2_foundation::{NSArray, NSData, NSObject, NSObjectProtocol};
+use objc2_metric_kit::{
+    MXDiagnosticPayload, MXMetricManager, MXMetricManagerSubscriber, MXMetricPayload,
+};
+use tracing::trace;
+
+use crate::app::with_world;
+
+/// Collect performance telemetry with MetricKit.
+///
+/// The system delivers metrics (such as launch times, hang rates and GPU time) for the previous
+/// day at most once per day, and diagnostics (such as crashes and hangs) as they're available.
+/// These are forwarded as [`MetricPayloadReceived`] and [`DiagnosticPayloadReceived`] messages.
+#[derive(Debug, Default)]
+pub struct MetricKitPlugin;
+
+impl Plugin for MetricKitPlugin {
+    fn name(&self) -> &str {
+        "bevy_uikit::MetricKitPlugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        let subscriber = MetricSubscriber::new();
+        MXMetricManager::sharedManager().addSubscriber(ProtocolObject::from_ref(&*subscriber));
+
+        let mtm = MainThreadMarker::new().expect("must build the App on the main thread");
+        app.insert_resource(MetricKitSubscription(MainThreadBound::new(subscriber, mtm)))
+            .add_message::<MetricPayloadReceived>()
+            .add_message::<DiagnosticPayloadReceived>();
+    }
+}
+
+/// Keeps the subscriber alive.
+#[derive(Resource, Debug)]
+struct MetricKitSubscription(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<Retained<MetricSubscriber>>,
+);
+
+/// A daily metrics report from MetricKit.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct MetricPayloadReceived {
+    /// The JSON representation of the `MXMetricPayload`.
+    pub json: String,
+}
+
+/// A diagnostics report (crash, hang, disk write exception, etc.) from MetricKit.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticPayloadReceived {
+    /// The JSON representation of the `MXDiagnosticPayload`.
+    pub json: String,
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "BevyMetricSubscriber"]
+    #[derive(Debug)]
+    struct MetricSubscriber;
+
+    unsafe impl NSObjectProtocol for MetricSubscriber {}
+
+    // NOTE: Called on a background queue.
+    unsafe impl MXMetricManagerSubscriber for MetricSubscriber {
+        #[unsafe(method(didReceiveMetricPayloads:))]
+        fn didReceiveMetricPayloads(&self, payloads: &NSArray<MXMetricPayload>) {
+            trace!(count = payloads.count(), "didReceiveMetricPayloads:");
+            let messages = payloads
+                .iter()
+                .map(|payload| MetricPayloadReceived {
+                    json: json_string(&payload.JSONRepresentation()),
+                })
+                .collect();
+            forward_to_main_thread(messages);
+        }
+
+        #[unsafe(method(didReceiveDiagnosticPayloads:))]
+        fn didReceiveDiagnosticPayloads(&self, payloads: &NSArray<MXDiagnosticPayload>) {
+            trace!(count = payloads.count(), "didReceiveDiagnosticPayloads:");
+            let messages = payloads
+                .iter()
+                .map(|payload| DiagnosticPayloadReceived {
+                    json: json_string(&payload.JSONRepresentation()),
+                })
+                .collect();
+            forward_to_main_thread(messages);
+        }
+    }
+);
+
+impl MetricSubscriber {
+    fn new() -> Retained<Self> {
+        let this = Self::alloc().set_ivars(());
+        unsafe { msg_send![super(this), init] }
+    }
+}
+
+fn json_string(data: &NSData) -> String {
+    String::from_utf8_lossy(&data.to_vec()).into_owned()
+}
+
+fn forward_to_main_thread<M: Message>(messages: Vec<M>) {
+    DispatchQueue::main().exec_async(move || {
+        let mtm = MainThreadMarker::new().expect("main queue runs on the main thread");
+        with_world(mtm, move |world| {
+            world.write_message_batch(messages);
+        });
+    });
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use bevy_ecs::{
+    message::{Message, MessageWriter},
+    system::NonSendMarker,
+};
+use objc2::available;
+use objc2_ui_kit::{UIDevice, UIUserInterfaceIdiom};
+use tracing::{debug, warn};
+
+use crate::info_plist::info_dictionary;
+use crate::{main_thread_marker, InterfaceOrientation, PropertyListValue};
+
+/// A problem with the application's `Info.plist` that prevents multitasking, such as Split View,
+/// Stage Manager or multiple windows, from working.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MultitaskingIssue {
+    /// `UIApplicationSceneManifest` is missing, so the application does not use scenes, and can
+    /// only have a single window.
+    MissingSceneManifest,
+    /// `UIApplicationSupportsMultipleScenes` is not enabled in the scene manifest, so only a
+    /// single window can be opened.
+    MultipleScenesNotSupported,
+    /// `UIRequiresFullScreen` is enabled, which opts the application out of multitasking on iPad.
+    RequiresFullScreen,
+    /// Not all interface orientations are supported, which is required for multitasking on iPad.
+    MissingOrientations {
+        /// The orientations that are not listed in `UISupportedInterfaceOrientations`.
+        missing: Vec<InterfaceOrientation>,
+    },
+}
+
+/// The application is misconfigured for multitasking, see [`MultitaskingIssue`].
+///
+/// Emitted once at startup (and also logged as warnings) if any issues were found.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct MultitaskingIssuesDetected {
+    /// The issues.
+    pub issues: Vec<MultitaskingIssue>,
+}
+
+/// Check the application's `Info.plist` for [`MultitaskingIssue`]s.
+pub fn audit_multitasking_support(
+    mut detected: MessageWriter<MultitaskingIssuesDetected>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    let Some(info) = info_dictionary() else {
+        warn!("could not read Info.plist, skipping multitasking audit");
+        return;
+    };
+    let idiom = UIDevice::currentDevice(mtm).userInterfaceIdiom();
+    let is_pad = idiom == UIUserInterfaceIdiom::Pad;
+    // Mac Catalyst applications may also use the iPad idiom.
+    let supports_multiple_windows =
+        is_pad || idiom == UIUserInterfaceIdiom::Mac || cfg!(target_os = "visionos");
+
+    let mut issues = Vec::new();
+
+    if !cfg!(feature = "no-scene") && available!(ios = 13.0, tvos = 13.0, visionos = 1.0, ..) {
+        match info.get("UIApplicationSceneManifest") {
+            Some(PropertyListValue::Dictionary(manifest)) => {
+                if !is_true(manifest, "UIApplicationSupportsMultipleScenes")
+                    && supports_multiple_windows
+                {
+                    issues.push(MultitaskingIssue::MultipleScenesNotSupported);
+                }
+            }
+            _ => issues.push(MultitaskingIssue::MissingSceneManifest),
+        }
+    }
+
+    if is_pad {
+        if is_true(&info, "UIRequiresFullScreen") {
+            issues.push(MultitaskingIssue::RequiresFullScreen);
+        } else {
+            // The iPad-specific key takes precedence.
+            let orientations = info
+                .get("UISupportedInterfaceOrientations~ipad")
+                .or_else(|| info.get("UISupportedInterfaceOrientations"));
+            let supported: Vec<&str> = match orientations {
+                Some(PropertyListValue::Array(orientations)) => orientations
+                    .iter()
+                    .filter_map(|orientation| match orientation {
+                        PropertyListValue::String(orientation) => Some(&**orientation),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+            let missing: Vec<_> = [
+                (
+                    "UIInterfaceOrientationPortrait",
+                    InterfaceOrientation::Portrait,
+                ),
+                (
+                    "UIInterfaceOrientationPortraitUpsideDown",
+                    InterfaceOrientation::PortraitUpsideDown,
+                ),
+                (
+                    "UIInterfaceOrientationLandscapeLeft",
+                    InterfaceOrientation::LandscapeLeft,
+                ),
+                (
+                    "UIInterfaceOrientationLandscapeRight",
+                    InterfaceOrientation::LandscapeRight,
+                ),
+            ]
+            .into_iter()
+            .filter(|(key, _)| !supported.contains(key))
+            .map(|(_, orientation)| orientation)
+            .collect();
+            if !missing.is_empty() {
+                issues.push(MultitaskingIssue::MissingOrientations { missing });
+            }
+        }
+    }
+
+    if issues.is_empty() {
+        debug!("application is configured for multitasking");
+        return;
+    }
+    for issue in &issues {
+        warn!(?issue, "application is misconfigured for multitasking");
+    }
+    detected.write(MultitaskingIssuesDetected { issues });
+}
+
+fn is_true(dictionary: &HashMap<String, PropertyListValue>, key: &str) -> bool {
+    matches!(dictionary.get(key), Some(PropertyListValue::Bool(true)))
+}
@@ -0,0 +1,36 @@
+use bevy_ecs::message::{MessageReader, MessageWriter};
+use bevy_window::{WindowBackground, WindowEvent, WindowForeground, WindowOccluded};
+use tracing::trace;
+
+/// Emit [`WindowOccluded`] as windows move to the background and foreground.
+///
+/// A scene moves to the background when it's no longer visible at all, such as when its window is
+/// closed on Mac Catalyst, or when the user switches to another application. Games can use this to
+/// pause the simulation while fully hidden.
+///
+/// UIKit does not tell scenes when their window is minimized on Mac Catalyst, or when it's covered
+/// by other windows, so neither is reported as occlusion.
+pub fn emit_window_occlusion(
+    mut background: MessageReader<WindowBackground>,
+    mut foreground: MessageReader<WindowForeground>,
+    mut occluded: MessageWriter<WindowOccluded>,
+    mut window_events: MessageWriter<WindowEvent>,
+) {
+    let changes = background
+        .read()
+        .map(|WindowBackground { window }| (*window, true))
+        .chain(
+            foreground
+                .read()
+                .map(|WindowForeground { window }| (*window, false)),
+        );
+    for (window, is_occluded) in changes {
+        trace!(?window, is_occluded, "window occlusion changed");
+        let message = WindowOccluded {
+            window,
+            occluded: is_occluded,
+        };
+        occluded.write(message.clone());
+        window_events.write(message.into());
+    }
+}
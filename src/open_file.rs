@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+
+use bevy_ecs::{entity::Entity, message::Message, world::World};
+use objc2_foundation::NSURL;
+use tracing::trace;
+
+use crate::SecurityScopedUrl;
+
+/// How a file opened with [`FileOpened`] was delivered to the application.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileOpenMode {
+    /// The file was copied into the application's `Documents/Inbox` directory, and is owned by the
+    /// application.
+    ///
+    /// Move the file elsewhere if it should be kept, or delete it once processed.
+    Copied,
+    /// The file is opened in place, and lives outside the application's sandbox.
+    ///
+    /// Changes are written back to the original document. Access is granted through the
+    /// [`SecurityScopedUrl`] component on the [`FileOpened::access`] entity.
+    InPlace,
+}
+
+/// A file was opened in the application, e.g. from the Files app, or with "Open in" from another
+/// application.
+///
+/// Requires declaring the supported types with `CFBundleDocumentTypes` in `Info.plist`. Setting
+/// `LSSupportsOpeningDocumentsInPlace` allows files to be opened in place.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct FileOpened {
+    /// The window whose scene received the file, or the primary window if the application does
+    /// not use scenes.
+    pub window: Option<Entity>,
+    /// The path to the file.
+    pub path: PathBuf,
+    /// Whether the file was copied or opened in place.
+    pub mode: FileOpenMode,
+    /// For files opened in place, an entity with a [`SecurityScopedUrl`] component that grants
+    /// access to the file.
+    ///
+    /// Access is stopped when the entity is despawned.
+    pub access: Option<Entity>,
+}
+
+/// Emit [`FileOpened`] if the URL is a file URL.
+///
+/// Returns whether the URL was handled.
+pub(crate) fn open_file(
+    world: &mut World,
+    window: Option<Entity>,
+    url: &NSURL,
+    in_place: bool,
+) -> bool {
+    let Some(path) = url.to_file_path() else {
+        trace!(?url, "ignoring non-file URL");
+        return false;
+    };
+    let (mode, access) = if in_place {
+        let access = world.spawn(SecurityScopedUrl::new(url)).id();
+        (FileOpenMode::InPlace, Some(access))
+    } else {
+        (FileOpenMode::Copied, None)
+    };
+    trace!(?window, ?path, ?mode, "file opened");
+    world.write_message(FileOpened {
+        window,
+        path,
+        mode,
+        access,
+    });
+    true
+}
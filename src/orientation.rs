@@ -0,0 +1,175 @@
+#[cfg(not(target_os = "tvos"))]
+use std::ptr::NonNull;
+
+use bevy_ecs::{
+    component::Component, entity::Entity, message::Message, resource::Resource, world::World,
+};
+#[cfg(not(target_os = "tvos"))]
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::MainThreadMarker;
+use objc2_foundation::NSObjectProtocol;
+#[cfg(not(target_os = "tvos"))]
+use objc2_foundation::{NSNotification, NSNotificationCenter, NSOperationQueue};
+#[cfg(not(target_os = "tvos"))]
+use objc2_ui_kit::{UIDevice, UIDeviceOrientation, UIDeviceOrientationDidChangeNotification};
+use objc2_ui_kit::{UIInterfaceOrientation, UIWindowScene};
+use tracing::trace;
+
+#[cfg(not(target_os = "tvos"))]
+use crate::app::send_message;
+
+/// The orientation of a window's user interface.
+///
+/// This is the direction the interface is rotated in, which may differ from the physical
+/// orientation of the device (e.g. when rotation is locked, or when the device is lying flat).
+///
+/// Only present on windows backed by a scene.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterfaceOrientation {
+    /// The interface is upright, with the home button (if any) at the bottom.
+    Portrait,
+    /// The interface is upside down, with the home button (if any) at the top.
+    PortraitUpsideDown,
+    /// The interface is rotated such that the home button (if any) is on the left.
+    LandscapeLeft,
+    /// The interface is rotated such that the home button (if any) is on the right.
+    LandscapeRight,
+}
+
+impl InterfaceOrientation {
+    fn from_uikit(orientation: UIInterfaceOrientation) -> Option<Self> {
+        match orientation {
+            UIInterfaceOrientation::Portrait => Some(Self::Portrait),
+            UIInterfaceOrientation::PortraitUpsideDown => Some(Self::PortraitUpsideDown),
+            UIInterfaceOrientation::LandscapeLeft => Some(Self::LandscapeLeft),
+            UIInterfaceOrientation::LandscapeRight => Some(Self::LandscapeRight),
+            _ => None,
+        }
+    }
+
+    /// Whether the interface is in one of the portrait orientations.
+    pub fn is_portrait(self) -> bool {
+        matches!(self, Self::Portrait | Self::PortraitUpsideDown)
+    }
+
+    /// Whether the interface is in one of the landscape orientations.
+    pub fn is_landscape(self) -> bool {
+        matches!(self, Self::LandscapeLeft | Self::LandscapeRight)
+    }
+}
+
+/// Insert, update or remove [`InterfaceOrientation`] based on the scene's current orientation.
+pub(crate) fn update_interface_orientation(
+    world: &mut World,
+    entity: Entity,
+    scene: &UIWindowScene,
+) {
+    let orientation = InterfaceOrientation::from_uikit(scene.interfaceOrientation());
+
+    let Ok(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+    match orientation {
+        Some(orientation) => {
+            if entity.get::<InterfaceOrientation>() != Some(&orientation) {
+                trace!(?orientation, "updating InterfaceOrientation");
+                entity.insert(orientation);
+            }
+        }
+        // Unknown, e.g. when the scene is not yet visible.
+        None if entity.contains::<InterfaceOrientation>() => {
+            trace!("removing InterfaceOrientation");
+            entity.remove::<InterfaceOrientation>();
+        }
+        None => {}
+    }
+}
+
+/// The physical orientation of the device.
+///
+/// This is distinct from [`InterfaceOrientation`], and is reported even when the interface does
+/// not rotate, e.g. when rotation is locked or the application only supports a single orientation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeviceOrientation {
+    /// The device is upright, with the home button (if any) at the bottom.
+    Portrait,
+    /// The device is upside down, with the home button (if any) at the top.
+    PortraitUpsideDown,
+    /// The device is held sideways, with the home button (if any) on the right.
+    LandscapeLeft,
+    /// The device is held sideways, with the home button (if any) on the left.
+    LandscapeRight,
+    /// The device is lying flat, with the screen facing upwards.
+    FaceUp,
+    /// The device is lying flat, with the screen facing downwards.
+    FaceDown,
+}
+
+impl DeviceOrientation {
+    #[cfg(not(target_os = "tvos"))]
+    fn from_uikit(orientation: UIDeviceOrientation) -> Option<Self> {
+        match orientation {
+            UIDeviceOrientation::Portrait => Some(Self::Portrait),
+            UIDeviceOrientation::PortraitUpsideDown => Some(Self::PortraitUpsideDown),
+            UIDeviceOrientation::LandscapeLeft => Some(Self::LandscapeLeft),
+            UIDeviceOrientation::LandscapeRight => Some(Self::LandscapeRight),
+            UIDeviceOrientation::FaceUp => Some(Self::FaceUp),
+            UIDeviceOrientation::FaceDown => Some(Self::FaceDown),
+            _ => None,
+        }
+    }
+}
+
+/// The physical orientation of the device changed.
+///
+/// Not emitted on tvOS, where devices have no orientation.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeviceOrientationChanged {
+    /// The new orientation of the device.
+    pub orientation: DeviceOrientation,
+}
+
+/// Keeps the device orientation notification observer alive.
+#[derive(Resource, Debug)]
+pub(crate) struct DeviceOrientationObserver(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    Option<MainThreadBound<Retained<ProtocolObject<dyn NSObjectProtocol>>>>,
+);
+
+impl DeviceOrientationObserver {
+    /// Start generating device orientation notifications, and forward them as
+    /// [`DeviceOrientationChanged`].
+    pub(crate) fn new(mtm: MainThreadMarker) -> Self {
+        #[cfg(not(target_os = "tvos"))]
+        {
+            let device = UIDevice::currentDevice(mtm);
+            device.beginGeneratingDeviceOrientationNotifications();
+
+            let block = RcBlock::new(move |_notification: NonNull<NSNotification>| {
+                let orientation = UIDevice::currentDevice(mtm).orientation();
+                trace!(?orientation, "UIDeviceOrientationDidChangeNotification");
+                if let Some(orientation) = DeviceOrientation::from_uikit(orientation) {
+                    send_message(mtm, DeviceOrientationChanged { orientation });
+                }
+            });
+            // SAFETY: The block has the correct signature, and is run on the main queue.
+            let observer = unsafe {
+                NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+                    Some(UIDeviceOrientationDidChangeNotification),
+                    None,
+                    Some(&NSOperationQueue::mainQueue()),
+                    &block,
+                )
+            };
+            Self(Some(MainThreadBound::new(observer, mtm)))
+        }
+        #[cfg(target_os = "tvos")]
+        {
+            let _ = mtm;
+            Self(None)
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Bindings to the unified logging system (`os_log`), which is what Console.app and
+//! `log stream` show.
+//!
+//! `os_log` is implemented with C macros, so we have to emit the log messages manually, like in
+//! the [`signpost`](crate::signpost) module.
+use std::ffi::{c_char, c_void, CString};
+
+#[repr(C)]
+struct OsLogObject {
+    _priv: [u8; 0],
+}
+
+extern "C" {
+    static __dso_handle: c_void;
+
+    fn os_log_create(subsystem: *const c_char, category: *const c_char) -> *mut OsLogObject;
+    fn _os_log_impl(
+        dso: *const c_void,
+        log: *mut OsLogObject,
+        r#type: u8,
+        format: *const c_char,
+        buf: *mut u8,
+        size: u32,
+    );
+}
+
+// The format string must be in the `__oslogstring` section.
+#[link_section = "__TEXT,__oslogstring,cstring_literals"]
+static FORMAT: [u8; 11] = *b"%{public}s\0";
+
+/// The type (level) of a log message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+#[allow(dead_code, reason = "not every type is used with every feature")]
+pub(crate) enum OsLogType {
+    Default = 0x00,
+    Info = 0x01,
+    Debug = 0x02,
+    Error = 0x10,
+    Fault = 0x11,
+}
+
+/// An `os_log_t` for a subsystem and category.
+#[derive(Debug)]
+pub(crate) struct OsLog(*mut OsLogObject);
+
+// SAFETY: `os_log_t` is thread-safe.
+unsafe impl Send for OsLog {}
+unsafe impl Sync for OsLog {}
+
+impl OsLog {
+    pub(crate) fn new(subsystem: &str, category: &str) -> Self {
+        let subsystem = CString::new(subsystem).unwrap_or_default();
+        let category = CString::new(category).unwrap_or_default();
+        // SAFETY: The strings are NUL-terminated. The log is never freed, but the system caches
+        // them per subsystem and category anyhow.
+        Self(unsafe { os_log_create(subsystem.as_ptr(), category.as_ptr()) })
+    }
+
+    /// Log a message, which is marked public such that it isn't redacted.
+    pub(crate) fn log(&self, r#type: OsLogType, message: &str) {
+        let Ok(message) = CString::new(message.replace('\0', "\\0")) else {
+            return;
+        };
+        // The encoded arguments of the format string: A summary byte (with "has non-scalar"), the
+        // argument count, and then the public string argument's descriptor, size and pointer.
+        let mut buf = [0u8; 12];
+        buf[..4].copy_from_slice(&[0x02, 0x01, 0x22, 0x08]);
+        buf[4..].copy_from_slice(&(message.as_ptr() as usize as u64).to_le_bytes());
+        // SAFETY: The format is NUL-terminated and placed in `__oslogstring`, the buffer matches
+        // the format, and the message outlives the call.
+        unsafe {
+            _os_log_impl(
+                &__dso_handle,
+                self.0,
+                r#type as u8,
+                FORMAT.as_ptr().cast(),
+                buf.as_mut_ptr(),
+                buf.len() as u32,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "os-log")]
+pub use layer::{os_log_layer, OsLogLayer};
+
+#[cfg(feature = "os-log")]
+mod layer {
+    use std::collections::HashMap;
+    use std::fmt::{self, Write as _};
+    use std::sync::{Arc, Mutex};
+
+    use bevy_app::App;
+    use bevy_log::tracing_subscriber::layer::{Context, Layer};
+    use bevy_log::BoxedLayer;
+    use objc2_foundation::NSBundle;
+    use tracing::field::{Field, Visit};
+    use tracing::{Event, Level, Subscriber};
+
+    use super::{OsLog, OsLogType};
+
+    /// A [`tracing`] layer that writes to the unified logging system, such that logs show up in
+    /// Console.app with proper levels.
+    ///
+    /// Events are logged with the layer's subsystem, and the crate that emitted them (the first
+    /// component of the event's target) as the category, so they can be filtered by either.
+    ///
+    /// Use [`os_log_layer`] to install this with Bevy's `LogPlugin`.
+    #[derive(Debug)]
+    pub struct OsLogLayer {
+        subsystem: String,
+        logs: Mutex<HashMap<String, Arc<OsLog>>>,
+    }
+
+    impl OsLogLayer {
+        /// Create a layer that logs with the given subsystem, usually a reverse-DNS identifier
+        /// such as `"com.example.game"`.
+        pub fn new(subsystem: impl Into<String>) -> Self {
+            Self {
+                subsystem: subsystem.into(),
+                logs: Mutex::new(HashMap::new()),
+            }
+        }
+
+        fn log(&self, category: &str) -> Arc<OsLog> {
+            let mut logs = self.logs.lock().unwrap_or_else(|err| err.into_inner());
+            logs.entry(category.to_string())
+                .or_insert_with(|| Arc::new(OsLog::new(&self.subsystem, category)))
+                .clone()
+        }
+    }
+
+    impl Default for OsLogLayer {
+        /// Log with the application's bundle identifier as the subsystem.
+        fn default() -> Self {
+            let subsystem = NSBundle::mainBundle()
+                .bundleIdentifier()
+                .map(|identifier| identifier.to_string())
+                .unwrap_or_else(|| "org.bevyengine.bevy".to_string());
+            Self::new(subsystem)
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for OsLogLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let metadata = event.metadata();
+            let r#type = match *metadata.level() {
+                Level::ERROR => OsLogType::Error,
+                Level::WARN => OsLogType::Default,
+                Level::INFO => OsLogType::Info,
+                Level::DEBUG | Level::TRACE => OsLogType::Debug,
+            };
+            let category = metadata.target().split("::").next().unwrap_or_default();
+
+            let mut visitor = MessageVisitor::default();
+            event.record(&mut visitor);
+            self.log(category).log(r#type, &visitor.0);
+        }
+    }
+
+    /// Format an event's message, followed by its other fields.
+    #[derive(Default)]
+    struct MessageVisitor(String);
+
+    impl Visit for MessageVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "message" {
+                let fields = std::mem::take(&mut self.0);
+                let _ = write!(self.0, "{value:?}{fields}");
+            } else {
+                let _ = write!(self.0, " {}={value:?}", field.name());
+            }
+        }
+    }
+
+    /// Create an [`OsLogLayer`] with the default subsystem, for use as `LogPlugin::custom_layer`.
+    ///
+    /// Note that on iOS, Bevy's `LogPlugin` also logs to the unified logging system by itself,
+    /// though without a subsystem.
+    pub fn os_log_layer(_app: &mut App) -> Option<BoxedLayer> {
+        Some(Box::new(OsLogLayer::default()))
+    }
+}
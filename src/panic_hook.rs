@@ -0,0 +1,61 @@
+//! A panic hook that reports panics to the unified logging system, and stops UIKit callbacks from
+//! re-entering the application after a panic escaped into it.
+//!
+//! Without this, a panic is only printed to stderr (which is not captured on devices), and a panic
+//! that unwound out of the application may leave it running in a broken state until something
+//! else faults, which makes the resulting crash report misleading.
+use std::panic::{self, PanicHookInfo};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Once, OnceLock};
+
+use crate::os_log::{OsLog, OsLogType};
+
+static POISONED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the application has panicked, and should not be used anymore.
+pub(crate) fn is_poisoned() -> bool {
+    POISONED.load(Ordering::Acquire)
+}
+
+/// Mark the application as unusable, because a panic escaped into it.
+///
+/// Panics that are caught and recovered from (such as in a background task whose result is
+/// handled) are only logged, and don't poison the application.
+pub(crate) fn poison() {
+    POISONED.store(true, Ordering::Release);
+}
+
+/// Poisons the application if dropped while unwinding from a panic, such as one that escaped
+/// from [`App::update`](bevy_app::App::update).
+pub(crate) struct PoisonOnUnwind;
+
+impl Drop for PoisonOnUnwind {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            poison();
+        }
+    }
+}
+
+/// Install the panic hook, keeping the previous hook to also run it.
+///
+/// Only installs the hook once, even if called multiple times (such as when building several
+/// `App`s).
+pub(crate) fn install() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            log_fault(info);
+            previous(info);
+        }));
+    });
+}
+
+fn log_fault(info: &PanicHookInfo<'_>) {
+    static LOG: OnceLock<OsLog> = OnceLock::new();
+    let log = LOG.get_or_init(|| OsLog::new("org.bevyengine.bevy_uikit", "panic"));
+    let thread = std::thread::current();
+    let message = format!("thread '{}' {info}", thread.name().unwrap_or("<unnamed>"));
+    log.log(OsLogType::Fault, &message);
+}
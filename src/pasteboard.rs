@@ -0,0 +1,114 @@
+use bevy_ecs::message::{Message, MessageReader};
+use block2::RcBlock;
+use dispatch2::DispatchQueue;
+use objc2::{available, MainThreadMarker};
+use objc2_foundation::{NSError, NSSet};
+use objc2_ui_kit::{
+    UIPasteboard, UIPasteboardDetectionPattern, UIPasteboardDetectionPatternNumber,
+    UIPasteboardDetectionPatternProbableWebSearch, UIPasteboardDetectionPatternProbableWebURL,
+};
+use tracing::{trace, warn};
+
+use crate::app::with_world;
+
+/// A kind of content that can be detected on the pasteboard, see [`DetectPasteboardPatterns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PasteboardPattern {
+    /// A string that is likely a web URL.
+    ProbableWebUrl,
+    /// A string that is likely a web search, suitable for "Paste and Search".
+    ProbableWebSearch,
+    /// A number.
+    Number,
+}
+
+impl PasteboardPattern {
+    fn to_uikit(self) -> &'static UIPasteboardDetectionPattern {
+        // SAFETY: The patterns are immutable constants.
+        unsafe {
+            match self {
+                Self::ProbableWebUrl => UIPasteboardDetectionPatternProbableWebURL,
+                Self::ProbableWebSearch => UIPasteboardDetectionPatternProbableWebSearch,
+                Self::Number => UIPasteboardDetectionPatternNumber,
+            }
+        }
+    }
+
+    fn from_uikit(pattern: &UIPasteboardDetectionPattern) -> Option<Self> {
+        [Self::ProbableWebUrl, Self::ProbableWebSearch, Self::Number]
+            .into_iter()
+            .find(|candidate| candidate.to_uikit() == pattern)
+    }
+}
+
+/// Check which patterns the contents of the general pasteboard match, without reading the
+/// contents, and thus without showing the paste notification to the user.
+///
+/// Useful for only offering e.g. a "Paste Invite Code" button when the pasteboard contains a
+/// number. The result is delivered as a [`PasteboardPatternsDetected`] message.
+///
+/// Requires iOS 14.0.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct DetectPasteboardPatterns {
+    /// The patterns to check for.
+    pub patterns: Vec<PasteboardPattern>,
+}
+
+/// The outcome of a [`DetectPasteboardPatterns`] request.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct PasteboardPatternsDetected {
+    /// The patterns that were checked for.
+    pub requested: Vec<PasteboardPattern>,
+    /// The patterns that the pasteboard's contents match, or the error description if detection
+    /// failed.
+    pub result: Result<Vec<PasteboardPattern>, String>,
+}
+
+/// Start detecting patterns requested with [`DetectPasteboardPatterns`].
+pub fn detect_pasteboard_patterns(mut requests: MessageReader<DetectPasteboardPatterns>) {
+    for DetectPasteboardPatterns { patterns } in requests.read() {
+        if !available!(ios = 14.0, ..) {
+            warn!("detecting pasteboard patterns requires iOS 14.0");
+            forward_to_main_thread(PasteboardPatternsDetected {
+                requested: patterns.clone(),
+                result: Err("detecting pasteboard patterns requires iOS 14.0".into()),
+            });
+            continue;
+        }
+
+        trace!(?patterns, "detecting pasteboard patterns");
+        let uikit_patterns: Vec<_> = patterns.iter().map(|pattern| pattern.to_uikit()).collect();
+        let requested = patterns.clone();
+        let completion_handler = RcBlock::new(
+            move |detected: *mut NSSet<UIPasteboardDetectionPattern>, error: *mut NSError| {
+                // SAFETY: The pointers are valid for the duration of the block, if non-null.
+                let result = match unsafe { (detected.as_ref(), error.as_ref()) } {
+                    (Some(detected), _) => Ok(detected
+                        .iter()
+                        .filter_map(|pattern| PasteboardPattern::from_uikit(&pattern))
+                        .collect()),
+                    (None, Some(error)) => Err(error.localizedDescription().to_string()),
+                    (None, None) => Ok(Vec::new()),
+                };
+                forward_to_main_thread(PasteboardPatternsDetected {
+                    requested: requested.clone(),
+                    result,
+                });
+            },
+        );
+        UIPasteboard::generalPasteboard().detectPatternsForPatterns_completionHandler(
+            &NSSet::from_slice(&uikit_patterns),
+            &completion_handler,
+        );
+    }
+}
+
+/// The completion handler is called on a background queue.
+fn forward_to_main_thread(message: PasteboardPatternsDetected) {
+    DispatchQueue::main().exec_async(move || {
+        let mtm = MainThreadMarker::new().expect("main queue runs on the main thread");
+        with_world(mtm, move |world| {
+            world.write_message(message);
+        });
+    });
+}
@@ -0,0 +1,72 @@
+use std::time::Instant;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    message::Message,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use objc2::DefinedClass as _;
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Deliver presses of the Menu button as [`PauseRequested`] messages.
+///
+/// This is the Menu button on game controllers (often labelled "Options" or "≡") and the Siri
+/// Remote, which UIKit delivers through the responder chain as a press. Add this to the entity of
+/// the [`Window`](bevy_window::Window).
+///
+/// On tvOS, the system returns to the Home Screen when the Menu button is not handled. The App
+/// Store requires this to still happen from the game's main menu, so switch to
+/// [`PauseButton::PassThrough`] (or remove the component) while it's shown.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PauseButton {
+    /// Send [`PauseRequested`], and prevent the system from handling the press.
+    #[default]
+    Intercept,
+    /// Send [`PauseRequested`], and let the system handle the press as well.
+    PassThrough,
+}
+
+/// The user pressed the Menu button, see [`PauseButton`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PauseRequested {
+    /// The window that received the press.
+    pub window: Entity,
+    /// The time when the press began, derived from `UIPress.timestamp`.
+    pub instant: Instant,
+}
+
+/// Propagate [`PauseButton`] to the window's view controller.
+pub fn update_pause_buttons(
+    pause_buttons: Query<(Entity, Ref<PauseButton>)>,
+    mut removed: RemovedComponents<PauseButton>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, pause_button) in &pause_buttons {
+        // Also update when the window was only just registered with UIKit.
+        if !pause_button.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(uikit_window) = uikit_windows.get(entity, mtm) {
+            trace!(?entity, ?pause_button, "routing pause button");
+            uikit_window
+                .view_controller
+                .ivars()
+                .pause_button
+                .set(Some(*pause_button));
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(uikit_window) = uikit_windows.get(entity, mtm) {
+            uikit_window.view_controller.ivars().pause_button.set(None);
+        }
+    }
+}
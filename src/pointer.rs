@@ -0,0 +1,253 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use std::cell::{Cell, OnceCell, RefCell};
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_math::{Rect, Vec2};
+use bevy_window::CursorOptions;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, ProtocolObject};
+use objc2::{
+    available, define_class, msg_send, DefinedClass as _, MainThreadMarker, MainThreadOnly,
+    Message as _,
+};
+use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+use objc2_foundation::{NSNumber, NSObject, NSObjectProtocol};
+use objc2_ui_kit::{
+    UIPointerInteraction, UIPointerInteractionDelegate, UIPointerRegion, UIPointerRegionRequest,
+    UIPointerStyle, UIView,
+};
+use tracing::trace;
+
+use crate::view::View;
+use crate::{main_thread_marker, UIKitWindows};
+
+/// How the pointer behaves inside a [`PointerRegion`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PointerRegionBehavior {
+    /// The pointer is hidden while inside the region.
+    ///
+    /// Useful for areas where pointer movement is captured by the game, such as virtual joysticks,
+    /// where the pointer would otherwise obscure the content.
+    Hidden,
+    /// The pointer is shown as the plain system pointer, without any effects.
+    ///
+    /// Useful for carving out UI areas from a larger [`Hidden`](Self::Hidden) region.
+    Disabled,
+}
+
+/// A region of a window with custom pointer behavior, see [`PointerRegions`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointerRegion {
+    /// The region, in logical coordinates relative to the top-left corner of the window.
+    pub rect: Rect,
+    /// How the pointer behaves inside the region.
+    pub behavior: PointerRegionBehavior,
+}
+
+/// Regions of a window where the iPad or Mac Catalyst pointer behaves differently.
+///
+/// The regions are checked in order, and the first region containing the pointer is used.
+/// Outside of all regions, the system pointer is shown. Add this to the entity of the
+/// [`Window`](bevy_window::Window).
+///
+/// Note that UIKit does not allow confining the pointer to a region, only locking it entirely.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct PointerRegions {
+    /// The regions.
+    pub regions: Vec<PointerRegion>,
+}
+
+/// Propagate changes to [`PointerRegions`] to the native view.
+pub fn update_pointer_regions(
+    pointer_regions: Query<(Entity, Ref<PointerRegions>)>,
+    mut removed: RemovedComponents<PointerRegions>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    if !available!(ios = 13.4, visionos = 1.0, ..) {
+        return;
+    }
+
+    for (entity, pointer_regions) in &pointer_regions {
+        // Also update when the window was only just registered with UIKit.
+        if !pointer_regions.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            trace!(?entity, regions = ?pointer_regions.regions, "setting pointer regions");
+            let mut delegate = view.ivars().pointer_delegate.borrow_mut();
+            let delegate = delegate.get_or_insert_with(|| PointerDelegate::install(&view, mtm));
+            *delegate.ivars().regions.borrow_mut() = pointer_regions.regions.clone();
+            delegate.invalidate();
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            if let Some(delegate) = &*view.ivars().pointer_delegate.borrow() {
+                delegate.ivars().regions.borrow_mut().clear();
+                delegate.invalidate();
+            }
+        }
+    }
+}
+
+/// Propagate [`CursorOptions::visible`] to the native view, by hiding the iPad or Mac Catalyst
+/// pointer over the entire view.
+///
+/// This takes precedence over any [`PointerRegions`].
+pub fn update_cursor_visibility(
+    cursor_options: Query<(Entity, Ref<CursorOptions>)>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    if !available!(ios = 13.4, visionos = 1.0, ..) {
+        return;
+    }
+
+    for (entity, cursor_options) in &cursor_options {
+        // Also update when the window was only just registered with UIKit.
+        if !cursor_options.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        else {
+            continue;
+        };
+        let mut delegate = view.ivars().pointer_delegate.borrow_mut();
+        // Avoid installing a pointer interaction on every window just to show the cursor.
+        if delegate.is_none() && cursor_options.visible {
+            continue;
+        }
+        let delegate = delegate.get_or_insert_with(|| PointerDelegate::install(&view, mtm));
+        let hidden = !cursor_options.visible;
+        if delegate.ivars().cursor_hidden.replace(hidden) != hidden {
+            trace!(?entity, hidden, "setting cursor visibility");
+            delegate.invalidate();
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct PointerDelegateIvars {
+    regions: RefCell<Vec<PointerRegion>>,
+    /// Whether the pointer is hidden over the entire view, see [`CursorOptions::visible`].
+    cursor_hidden: Cell<bool>,
+    interaction: OnceCell<Retained<UIPointerInteraction>>,
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "BevyPointerInteractionDelegate"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = PointerDelegateIvars]
+    #[derive(Debug)]
+    pub(crate) struct PointerDelegate;
+
+    unsafe impl NSObjectProtocol for PointerDelegate {}
+
+    unsafe impl UIPointerInteractionDelegate for PointerDelegate {
+        #[unsafe(method_id(pointerInteraction:regionForRequest:defaultRegion:))]
+        fn pointerInteraction_regionForRequest_defaultRegion(
+            &self,
+            _interaction: &UIPointerInteraction,
+            request: &UIPointerRegionRequest,
+            default_region: &UIPointerRegion,
+        ) -> Option<Retained<UIPointerRegion>> {
+            if self.ivars().cursor_hidden.get() {
+                return Some(default_region.retain());
+            }
+            let location = request.location();
+            let position = Vec2::new(location.x as f32, location.y as f32);
+            let regions = self.ivars().regions.borrow();
+            let index = regions
+                .iter()
+                .position(|region| region.rect.contains(position))?;
+            let rect = regions[index].rect;
+            let rect = CGRect {
+                origin: CGPoint {
+                    x: rect.min.x as _,
+                    y: rect.min.y as _,
+                },
+                size: CGSize {
+                    width: rect.width() as _,
+                    height: rect.height() as _,
+                },
+            };
+            // Identify the region by its index, such that the style can be looked up.
+            let identifier = NSNumber::new_usize(index);
+            // SAFETY: The identifier is an immutable object.
+            Some(unsafe {
+                UIPointerRegion::regionWithRect_identifier(
+                    rect,
+                    Some(ProtocolObject::from_ref(&*identifier)),
+                    self.mtm(),
+                )
+            })
+        }
+
+        #[unsafe(method_id(pointerInteraction:styleForRegion:))]
+        fn pointerInteraction_styleForRegion(
+            &self,
+            _interaction: &UIPointerInteraction,
+            region: &UIPointerRegion,
+        ) -> Option<Retained<UIPointerStyle>> {
+            if self.ivars().cursor_hidden.get() {
+                return Some(UIPointerStyle::hiddenPointerStyle(self.mtm()));
+            }
+            let identifier = region.identifier()?;
+            let identifier: &AnyObject = (*identifier).as_ref();
+            let index = identifier.downcast_ref::<NSNumber>()?.as_usize();
+            let regions = self.ivars().regions.borrow();
+            match regions.get(index)?.behavior {
+                PointerRegionBehavior::Hidden => {
+                    Some(UIPointerStyle::hiddenPointerStyle(self.mtm()))
+                }
+                // The system pointer.
+                PointerRegionBehavior::Disabled => None,
+            }
+        }
+    }
+);
+
+impl PointerDelegate {
+    /// Create the delegate, and add a pointer interaction using it to the view.
+    fn install(view: &View, mtm: MainThreadMarker) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(PointerDelegateIvars::default());
+        let this: Retained<Self> = unsafe { msg_send![super(this), init] };
+        // The interaction only holds a weak reference to its delegate.
+        let interaction = UIPointerInteraction::initWithDelegate(
+            UIPointerInteraction::alloc(mtm),
+            Some(ProtocolObject::from_ref(&*this)),
+        );
+        let view: &UIView = view;
+        view.addInteraction(ProtocolObject::from_ref(&*interaction));
+        let _ = this.ivars().interaction.set(interaction);
+        this
+    }
+
+    /// Request the region and style again, since UIKit otherwise keeps using the current ones
+    /// until the pointer leaves the region.
+    fn invalidate(&self) {
+        if let Some(interaction) = self.ivars().interaction.get() {
+            interaction.invalidate();
+        }
+    }
+}
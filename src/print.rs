@@ -0,0 +1,157 @@
+use std::ptr::NonNull;
+
+use bevy_ecs::{
+    entity::Entity,
+    message::{Message, MessageReader, MessageWriter},
+    system::{NonSendMarker, Res},
+};
+use block2::RcBlock;
+use objc2::runtime::{AnyObject, Bool};
+use objc2::MainThreadMarker;
+use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+use objc2_foundation::{NSData, NSError, NSString};
+use objc2_ui_kit::{
+    UIDevice, UIPrintInfo, UIPrintInfoOutputType, UIPrintInteractionController,
+    UIUserInterfaceIdiom,
+};
+use tracing::{trace, warn};
+
+use crate::app::send_message;
+use crate::{main_thread_marker, UIKitWindows};
+
+/// What kind of content is printed with [`PrintDocument`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrintContentKind {
+    /// A photo or other image, printed on photo paper if available.
+    Photo,
+    /// A document, such as a PDF or an image containing mostly text and line art.
+    Document,
+}
+
+/// Request that the system print sheet is presented for some content.
+///
+/// On iPad and Mac Catalyst, the sheet is presented as a popover in the middle of the window,
+/// elsewhere it is presented modally. The result is delivered as a [`PrintCompleted`] message
+/// once the sheet is dismissed.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct PrintDocument {
+    /// The window to present the print sheet in.
+    pub window: Entity,
+    /// The content to print, either PDF or image data (e.g. from
+    /// [`WindowScreenshotCaptured`](crate::WindowScreenshotCaptured)).
+    pub data: Vec<u8>,
+    /// What kind of content is printed.
+    pub kind: PrintContentKind,
+    /// The name of the print job, shown in the print center.
+    pub job_name: String,
+}
+
+/// The outcome of a [`PrintDocument`] request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrintResult {
+    /// The content was sent to the printer.
+    Completed,
+    /// The user dismissed the print sheet without printing.
+    Cancelled,
+    /// Printing failed, with the given error description.
+    Failed(String),
+}
+
+/// The print sheet requested with [`PrintDocument`] was dismissed.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct PrintCompleted {
+    /// The window the print sheet was presented in.
+    pub window: Entity,
+    /// Whether the content was printed.
+    pub result: PrintResult,
+}
+
+/// Present the print sheet for content requested with [`PrintDocument`].
+pub fn print_documents(
+    mut requests: MessageReader<PrintDocument>,
+    mut completed: MessageWriter<PrintCompleted>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for request in requests.read() {
+        let window = request.window;
+        let Some(uikit_window) = uikit_windows.get(window, mtm) else {
+            warn!(?window, "tried to print in window not known to UIKit");
+            continue;
+        };
+        let data = NSData::with_bytes(&request.data);
+        if !UIPrintInteractionController::canPrintData(&data, mtm) {
+            warn!(?window, "tried to print data that is not a PDF or an image");
+            completed.write(PrintCompleted {
+                window,
+                result: PrintResult::Failed("unsupported content".into()),
+            });
+            continue;
+        }
+        trace!(?window, kind = ?request.kind, job_name = request.job_name, "presenting print sheet");
+
+        let info = UIPrintInfo::printInfo(mtm);
+        info.setJobName(&NSString::from_str(&request.job_name));
+        info.setOutputType(match request.kind {
+            PrintContentKind::Photo => UIPrintInfoOutputType::Photo,
+            PrintContentKind::Document => UIPrintInfoOutputType::General,
+        });
+
+        let controller = UIPrintInteractionController::sharedPrintController(mtm);
+        controller.setPrintInfo(Some(&info));
+        let item: &AnyObject = &data;
+        // SAFETY: The item is a PDF or an image, as checked above.
+        unsafe { controller.setPrintingItem(Some(item)) };
+
+        let handler = RcBlock::new(
+            move |_controller: NonNull<UIPrintInteractionController>,
+                  printed: Bool,
+                  error: *mut NSError| {
+                // SAFETY: The error is either NULL or a valid `NSError`.
+                let result = if let Some(error) = unsafe { error.as_ref() } {
+                    PrintResult::Failed(error.localizedDescription().to_string())
+                } else if printed.as_bool() {
+                    PrintResult::Completed
+                } else {
+                    PrintResult::Cancelled
+                };
+                trace!(?window, ?result, "print sheet dismissed");
+                send_message(mtm, PrintCompleted { window, result });
+            },
+        );
+
+        let popover = matches!(
+            UIDevice::currentDevice(mtm).userInterfaceIdiom(),
+            UIUserInterfaceIdiom::Pad | UIUserInterfaceIdiom::Mac
+        );
+        // SAFETY: The handler has the correct signature.
+        let presented = unsafe {
+            if popover {
+                let bounds = uikit_window.uiwindow.bounds();
+                let rect = CGRect {
+                    origin: CGPoint {
+                        x: bounds.size.width / 2.0,
+                        y: bounds.size.height / 2.0,
+                    },
+                    size: CGSize::ZERO,
+                };
+                controller.presentFromRect_inView_animated_completionHandler(
+                    rect,
+                    &uikit_window.uiwindow,
+                    true,
+                    RcBlock::as_ptr(&handler),
+                )
+            } else {
+                controller.presentAnimated_completionHandler(true, RcBlock::as_ptr(&handler))
+            }
+        };
+        if !presented {
+            warn!(?window, "failed presenting print sheet");
+            completed.write(PrintCompleted {
+                window,
+                result: PrintResult::Failed("failed presenting print sheet".into()),
+            });
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::{Encoding, Message};
+use objc2_foundation::{
+    NSArray, NSData, NSDate, NSDictionary, NSMutableDictionary, NSNumber, NSString,
+};
+use tracing::warn;
+
+/// A value that can be stored in a property list, such as in user defaults.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyListValue {
+    /// A boolean.
+    Bool(bool),
+    /// An integer.
+    Integer(i64),
+    /// A floating point number.
+    Real(f64),
+    /// A string.
+    String(String),
+    /// Binary data.
+    Data(Vec<u8>),
+    /// A date.
+    Date(SystemTime),
+    /// An ordered list of values.
+    Array(Vec<PropertyListValue>),
+    /// A mapping from strings to values.
+    Dictionary(HashMap<String, PropertyListValue>),
+}
+
+impl PropertyListValue {
+    /// Convert from an Objective-C property list object.
+    ///
+    /// Returns `None` (and warns) if the object is not a property list type.
+    pub(crate) fn from_object(object: &AnyObject) -> Option<Self> {
+        if let Some(string) = object.downcast_ref::<NSString>() {
+            Some(Self::String(string.to_string()))
+        } else if let Some(number) = object.downcast_ref::<NSNumber>() {
+            Some(match number.encoding() {
+                // Booleans are stored as `char`s, other integer types are not used in property
+                // lists.
+                Encoding::Char | Encoding::Bool => Self::Bool(number.as_bool()),
+                Encoding::Float | Encoding::Double => Self::Real(number.as_f64()),
+                _ => Self::Integer(number.as_i64()),
+            })
+        } else if let Some(data) = object.downcast_ref::<NSData>() {
+            Some(Self::Data(data.to_vec()))
+        } else if let Some(date) = object.downcast_ref::<NSDate>() {
+            let interval = date.timeIntervalSince1970();
+            let time = if interval >= 0.0 {
+                SystemTime::UNIX_EPOCH + Duration::from_secs_f64(interval)
+            } else {
+                SystemTime::UNIX_EPOCH - Duration::from_secs_f64(-interval)
+            };
+            Some(Self::Date(time))
+        } else if let Some(array) = object.downcast_ref::<NSArray>() {
+            Some(Self::Array(
+                array
+                    .iter()
+                    .filter_map(|value| Self::from_object(&value))
+                    .collect(),
+            ))
+        } else if let Some(dictionary) = object.downcast_ref::<NSDictionary>() {
+            Some(Self::Dictionary(dictionary_from_object(dictionary)))
+        } else {
+            warn!(?object, "unsupported property list value");
+            None
+        }
+    }
+
+    /// Convert to an Objective-C property list object.
+    pub(crate) fn to_object(&self) -> Retained<AnyObject> {
+        match self {
+            Self::Bool(value) => upcast(NSNumber::new_bool(*value)),
+            Self::Integer(value) => upcast(NSNumber::new_i64(*value)),
+            Self::Real(value) => upcast(NSNumber::new_f64(*value)),
+            Self::String(value) => upcast(NSString::from_str(value)),
+            Self::Data(value) => upcast(NSData::with_bytes(value)),
+            Self::Date(value) => {
+                let interval = match value.duration_since(SystemTime::UNIX_EPOCH) {
+                    Ok(duration) => duration.as_secs_f64(),
+                    Err(err) => -err.duration().as_secs_f64(),
+                };
+                upcast(NSDate::dateWithTimeIntervalSince1970(interval))
+            }
+            Self::Array(values) => {
+                let values: Vec<_> = values.iter().map(Self::to_object).collect();
+                upcast(NSArray::from_retained_slice(&values))
+            }
+            Self::Dictionary(values) => upcast(dictionary_to_object(values)),
+        }
+    }
+}
+
+/// Convert a dictionary with string keys, ignoring other keys.
+fn dictionary_from_object(dictionary: &NSDictionary) -> HashMap<String, PropertyListValue> {
+    let (keys, values) = dictionary.to_vecs();
+    keys.iter()
+        .zip(values.iter())
+        .filter_map(|(key, value)| {
+            let Some(key) = key.downcast_ref::<NSString>() else {
+                warn!(?key, "unsupported property list dictionary key");
+                return None;
+            };
+            Some((key.to_string(), PropertyListValue::from_object(value)?))
+        })
+        .collect()
+}
+
+fn dictionary_to_object(
+    values: &HashMap<String, PropertyListValue>,
+) -> Retained<NSMutableDictionary<NSString, AnyObject>> {
+    let dictionary = NSMutableDictionary::new();
+    for (key, value) in values {
+        dictionary.insert(&*NSString::from_str(key), &*value.to_object());
+    }
+    dictionary
+}
+
+/// Convert an object to [`AnyObject`].
+pub(crate) fn upcast<T: Message>(object: Retained<T>) -> Retained<AnyObject> {
+    // SAFETY: All objects are `AnyObject`s.
+    unsafe { Retained::cast_unchecked(object) }
+}
+
+#[cfg(test)]
+mod tests {
+    use objc2_foundation::NSObject;
+
+    use super::*;
+
+    fn round_trip(value: PropertyListValue) {
+        assert_eq!(
+            PropertyListValue::from_object(&value.to_object()),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn scalars() {
+        round_trip(PropertyListValue::Bool(true));
+        round_trip(PropertyListValue::Bool(false));
+        round_trip(PropertyListValue::Integer(-42));
+        round_trip(PropertyListValue::Integer(i64::MAX));
+        round_trip(PropertyListValue::Real(0.5));
+        round_trip(PropertyListValue::String("héllo".into()));
+        round_trip(PropertyListValue::Data(vec![0, 1, 255]));
+    }
+
+    #[test]
+    fn dates() {
+        round_trip(PropertyListValue::Date(
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+        ));
+        round_trip(PropertyListValue::Date(
+            SystemTime::UNIX_EPOCH - Duration::from_secs(86_400),
+        ));
+    }
+
+    #[test]
+    fn collections() {
+        let dictionary = HashMap::from([
+            ("name".to_string(), PropertyListValue::String("Bevy".into())),
+            (
+                "scores".to_string(),
+                PropertyListValue::Array(vec![
+                    PropertyListValue::Integer(1),
+                    PropertyListValue::Real(2.5),
+                ]),
+            ),
+        ]);
+        round_trip(PropertyListValue::Dictionary(dictionary));
+        round_trip(PropertyListValue::Array(Vec::new()));
+    }
+
+    #[test]
+    fn numbers_keep_their_type() {
+        let one = PropertyListValue::from_object(&upcast(NSNumber::new_i64(1)));
+        assert_eq!(one, Some(PropertyListValue::Integer(1)));
+        let yes = PropertyListValue::from_object(&upcast(NSNumber::new_bool(true)));
+        assert_eq!(yes, Some(PropertyListValue::Bool(true)));
+    }
+
+    #[test]
+    fn unsupported_values() {
+        let object = upcast(NSObject::new());
+        assert_eq!(PropertyListValue::from_object(&object), None);
+
+        let array = NSArray::from_retained_slice(&[
+            upcast(NSString::from_str("kept")),
+            upcast(NSObject::new()),
+        ]);
+        assert_eq!(
+            PropertyListValue::from_object(&array),
+            Some(PropertyListValue::Array(vec![PropertyListValue::String(
+                "kept".into()
+            )]))
+        );
+    }
+}
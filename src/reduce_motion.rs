@@ -0,0 +1,96 @@
+use std::ptr::NonNull;
+
+use bevy_ecs::{message::Message, resource::Resource};
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{available, MainThreadMarker};
+use objc2_foundation::{NSNotification, NSNotificationCenter, NSObjectProtocol, NSOperationQueue};
+use objc2_ui_kit::{
+    UIAccessibilityIsReduceMotionEnabled, UIAccessibilityPrefersCrossFadeTransitions,
+    UIAccessibilityPrefersCrossFadeTransitionsStatusDidChangeNotification,
+    UIAccessibilityReduceMotionStatusDidChangeNotification,
+};
+use tracing::trace;
+
+use crate::app::with_world;
+
+/// The user's Reduce Motion accessibility settings.
+///
+/// When enabled, applications should avoid effects such as parallax, zooming, screen shake and
+/// large camera movements, and prefer fading between states instead. The plugin also avoids the
+/// more elaborate [`WindowDismissalAnimation`](crate::WindowDismissalAnimation)s.
+///
+/// Kept up to date while the application is running, see [`ReduceMotionChanged`].
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ReduceMotion {
+    /// Whether Reduce Motion is enabled.
+    pub enabled: bool,
+    /// Whether the user prefers cross-fade transitions instead of sliding ones.
+    ///
+    /// Requires iOS 14.0, and is always `false` when Reduce Motion is disabled.
+    pub prefers_cross_fade_transitions: bool,
+}
+
+impl ReduceMotion {
+    fn current() -> Self {
+        Self {
+            enabled: UIAccessibilityIsReduceMotionEnabled(),
+            prefers_cross_fade_transitions: available!(ios = 14.0, tvos = 14.0, visionos = 1.0, ..)
+                && UIAccessibilityPrefersCrossFadeTransitions(),
+        }
+    }
+}
+
+/// The [`ReduceMotion`] settings changed.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReduceMotionChanged {
+    /// The new settings.
+    pub reduce_motion: ReduceMotion,
+}
+
+/// Keeps the accessibility notification observers alive.
+#[derive(Resource, Debug)]
+pub(crate) struct ReduceMotionObserver(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<Vec<Retained<ProtocolObject<dyn NSObjectProtocol>>>>,
+);
+
+/// Read the current [`ReduceMotion`] settings, and observe changes to them.
+pub(crate) fn observe_reduce_motion(mtm: MainThreadMarker) -> (ReduceMotion, ReduceMotionObserver) {
+    let block = RcBlock::new(move |_notification: NonNull<NSNotification>| {
+        let reduce_motion = ReduceMotion::current();
+        with_world(mtm, move |world| {
+            if *world.resource::<ReduceMotion>() != reduce_motion {
+                trace!(?reduce_motion, "Reduce Motion changed");
+                world.insert_resource(reduce_motion);
+                world.write_message(ReduceMotionChanged { reduce_motion });
+            }
+        });
+    });
+    // SAFETY: The notification names are immutable constants.
+    let mut names = vec![unsafe { UIAccessibilityReduceMotionStatusDidChangeNotification }];
+    if available!(ios = 14.0, tvos = 14.0, visionos = 1.0, ..) {
+        names
+            .push(unsafe { UIAccessibilityPrefersCrossFadeTransitionsStatusDidChangeNotification });
+    }
+    let observers = names
+        .into_iter()
+        .map(|name| {
+            // SAFETY: The block has the correct signature, and is run on the main queue.
+            unsafe {
+                NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+                    Some(name),
+                    None,
+                    Some(&NSOperationQueue::mainQueue()),
+                    &block,
+                )
+            }
+        })
+        .collect();
+    (
+        ReduceMotion::current(),
+        ReduceMotionObserver(MainThreadBound::new(observers, mtm)),
+    )
+}
@@ -0,0 +1,140 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    message::{Message, MessageReader, MessageWriter},
+    system::{Commands, NonSendMarker, Query, Res},
+    world::World,
+};
+use objc2::MainThreadMarker;
+use tracing::trace;
+
+use crate::display::metal_layer;
+use crate::{main_thread_marker, UIKitSettings, UIKitWindows};
+
+/// A window started changing size, such as when the device is rotated, or when the user
+/// interactively resizes it with Stage Manager or on Mac Catalyst.
+///
+/// The size changes over a number of updates (with [`WindowResized`](bevy_window::WindowResized)
+/// as usual), until [`WindowResizeEnded`]. In the meantime, the window has a [`WindowResizing`]
+/// component, and its Metal layer presents in sync with Core Animation transactions, such that
+/// new frames appear together with the window's size changes instead of being stretched.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowResizeBegan {
+    /// The window that is being resized.
+    pub window: Entity,
+}
+
+/// A window finished changing size, see [`WindowResizeBegan`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowResizeEnded {
+    /// The window that was resized.
+    pub window: Entity,
+}
+
+/// Tell UIKit that the renderer has presented a frame at the final size of a resized window.
+///
+/// Only needed with [`UIKitSettings::acknowledge_window_resizes`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AcknowledgeWindowResize {
+    /// The window that was resized.
+    pub window: Entity,
+}
+
+/// A window is being resized, see [`WindowResizeBegan`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowResizing {
+    /// Whether the user is resizing the window, as opposed to a rotation or other animated
+    /// transition.
+    pub interactive: bool,
+    /// Whether UIKit has finished resizing the window, and is only waiting for
+    /// [`AcknowledgeWindowResize`].
+    pub finished: bool,
+    /// Whether [`AcknowledgeWindowResize`] was received.
+    pub acknowledged: bool,
+}
+
+/// Mark a window as being resized, if it isn't already.
+pub(crate) fn begin_window_resize(
+    world: &mut World,
+    mtm: MainThreadMarker,
+    entity: Entity,
+    interactive: bool,
+) {
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if let Some(mut resizing) = entity_mut.get_mut::<WindowResizing>() {
+        // A new resize started before the renderer acknowledged the previous one.
+        if resizing.finished {
+            *resizing = WindowResizing {
+                interactive,
+                finished: false,
+                acknowledged: false,
+            };
+        }
+        return;
+    }
+    trace!(?entity, interactive, "window resize began");
+    entity_mut.insert(WindowResizing {
+        interactive,
+        finished: false,
+        acknowledged: false,
+    });
+    world.write_message(WindowResizeBegan { window: entity });
+    if let Some(layer) = world
+        .resource::<UIKitWindows>()
+        .get(entity, mtm)
+        .and_then(|window| window.view())
+        .and_then(|view| metal_layer(&view))
+    {
+        layer.setPresentsWithTransaction(true);
+    }
+}
+
+/// Mark the resize of a window as finished by UIKit.
+///
+/// The resize is only ended later in [`end_window_resizes`], once the window has been updated to
+/// its final size.
+pub(crate) fn finish_window_resize(world: &mut World, entity: Entity, interactive: bool) {
+    if let Some(mut resizing) = world.get_mut::<WindowResizing>(entity) {
+        if resizing.interactive == interactive && !resizing.finished {
+            trace!(?entity, interactive, "window resize finished");
+            resizing.finished = true;
+        }
+    }
+}
+
+/// End window resizes once UIKit has finished them, and they've been acknowledged (if
+/// [`UIKitSettings::acknowledge_window_resizes`] is enabled).
+pub fn end_window_resizes(
+    mut resizing: Query<(Entity, &mut WindowResizing)>,
+    mut acknowledgements: MessageReader<AcknowledgeWindowResize>,
+    mut ended: MessageWriter<WindowResizeEnded>,
+    mut commands: Commands,
+    settings: Res<UIKitSettings>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for AcknowledgeWindowResize { window } in acknowledgements.read() {
+        if let Ok((_, mut resizing)) = resizing.get_mut(*window) {
+            resizing.acknowledged = true;
+        }
+    }
+
+    for (entity, resizing) in &resizing {
+        if !resizing.finished || (settings.acknowledge_window_resizes && !resizing.acknowledged) {
+            continue;
+        }
+        trace!(?entity, "window resize ended");
+        commands.entity(entity).remove::<WindowResizing>();
+        ended.write(WindowResizeEnded { window: entity });
+        if let Some(layer) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+            .and_then(|view| metal_layer(&view))
+        {
+            layer.setPresentsWithTransaction(false);
+        }
+    }
+}
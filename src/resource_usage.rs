@@ -0,0 +1,90 @@
+use std::mem;
+use std::time::{Duration, Instant};
+
+use bevy_ecs::{
+    resource::Resource,
+    system::{Res, ResMut},
+};
+use tracing::{trace, warn};
+
+use crate::UIKitSettings;
+
+/// The CPU and memory usage of the process, sampled periodically as configured with
+/// [`UIKitSettings::resource_usage_interval`].
+///
+/// The system terminates applications whose memory footprint exceeds a device-specific limit
+/// (without any warning if the limit is hit quickly), so games can use this to proactively unload
+/// assets as the footprint grows.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct ResourceUsage {
+    /// When the usage was last sampled, or `None` if it hasn't been yet.
+    pub sampled_at: Option<Instant>,
+    /// The physical memory footprint in bytes, which is what the system's memory limit applies
+    /// to.
+    ///
+    /// This is the same value as shown in Xcode's memory gauge.
+    pub memory_footprint: u64,
+    /// The total CPU time (user and system) that the process has used.
+    pub cpu_time: Duration,
+    /// The CPU time used since the previous sample, relative to the time passed, where `1.0` is
+    /// one fully utilized core.
+    pub cpu_usage: f32,
+}
+
+/// Sample [`ResourceUsage`] when the configured interval has passed.
+pub fn sample_resource_usage(settings: Res<UIKitSettings>, mut usage: ResMut<ResourceUsage>) {
+    let Some(interval) = settings.resource_usage_interval else {
+        return;
+    };
+    let now = Instant::now();
+    if usage
+        .sampled_at
+        .is_some_and(|sampled_at| now.duration_since(sampled_at) < interval)
+    {
+        return;
+    }
+
+    let (Some(memory_footprint), Some(cpu_time)) = (memory_footprint(), cpu_time()) else {
+        warn!("failed sampling resource usage");
+        // Don't retry until the next interval.
+        usage.sampled_at = Some(now);
+        return;
+    };
+    let cpu_usage = match usage.sampled_at {
+        Some(sampled_at) => {
+            let elapsed = now.duration_since(sampled_at).as_secs_f32();
+            cpu_time.saturating_sub(usage.cpu_time).as_secs_f32() / elapsed
+        }
+        None => 0.0,
+    };
+    *usage = ResourceUsage {
+        sampled_at: Some(now),
+        memory_footprint,
+        cpu_time,
+        cpu_usage,
+    };
+    trace!(?usage, "sampled resource usage");
+}
+
+fn memory_footprint() -> Option<u64> {
+    // SAFETY: `rusage_info_v2` is plain old data, so all zeroes is a valid value.
+    let mut info: libc::rusage_info_v2 = unsafe { mem::zeroed() };
+    // SAFETY: The buffer is valid for writes of the requested flavor.
+    let res = unsafe {
+        libc::proc_pid_rusage(libc::getpid(), libc::RUSAGE_INFO_V2, (&raw mut info).cast())
+    };
+    (res == 0).then_some(info.ri_phys_footprint)
+}
+
+fn cpu_time() -> Option<Duration> {
+    // SAFETY: `rusage` is plain old data, so all zeroes is a valid value.
+    let mut usage: libc::rusage = unsafe { mem::zeroed() };
+    // SAFETY: The pointer is valid for writes.
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return None;
+    }
+    let to_duration = |time: libc::timeval| {
+        Duration::from_secs(time.tv_sec as u64) + Duration::from_micros(time.tv_usec as u64)
+    };
+    Some(to_duration(usage.ru_utime) + to_duration(usage.ru_stime))
+}
@@ -0,0 +1,49 @@
+use bevy_ecs::component::Component;
+use objc2_core_foundation::CGFloat;
+use objc2_ui_kit::UIScreen;
+
+/// Which of the screen's scale factors to size the drawable with, see
+/// [`UIKitSettings::scale_mode`](crate::UIKitSettings::scale_mode).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ScaleMode {
+    /// Use `UIScreen.scale`, the scale factor that UIKit itself renders with.
+    ///
+    /// On devices where the screen's resolution is not an integer multiple of the logical
+    /// resolution, or when Display Zoom is enabled, the result is downsampled by the system.
+    #[default]
+    Logical,
+    /// Use `UIScreen.nativeScale`, the scale factor of the physical screen.
+    ///
+    /// This renders at exactly the screen's resolution, avoiding blurriness or rendering more
+    /// pixels than the screen has.
+    Native,
+}
+
+impl ScaleMode {
+    pub(crate) fn scale_factor(self, screen: &UIScreen) -> CGFloat {
+        match self {
+            Self::Logical => screen.scale(),
+            Self::Native => screen.nativeScale(),
+        }
+    }
+}
+
+/// The scale factors of the screen that a window is shown on.
+///
+/// These only differ on some devices, and when Display Zoom is enabled.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ScreenScale {
+    /// The screen's logical scale factor, `UIScreen.scale`.
+    pub scale: f64,
+    /// The screen's physical scale factor, `UIScreen.nativeScale`.
+    pub native_scale: f64,
+}
+
+impl ScreenScale {
+    pub(crate) fn new(screen: &UIScreen) -> Self {
+        Self {
+            scale: screen.scale() as f64,
+            native_scale: screen.nativeScale() as f64,
+        }
+    }
+}
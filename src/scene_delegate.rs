@@ -1,16 +1,19 @@
 #![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
 use std::cell::Cell;
+use std::time::SystemTime;
 
 use bevy_ecs::entity::Entity;
-use bevy_ecs::query::{QuerySingleError, With};
+use bevy_ecs::query::{QuerySingleError, With, Without};
 use bevy_window::{
     PrimaryWindow, Window, WindowActivate, WindowBackground, WindowCreated, WindowDeactivate,
     WindowDestroyed, WindowForeground,
 };
+#[cfg(not(target_os = "tvos"))]
+use objc2::available;
 use objc2::rc::{Allocated, Retained};
 use objc2::runtime::ProtocolObject;
 use objc2::{define_class, msg_send, DefinedClass as _, MainThreadOnly, Message as _};
-use objc2_foundation::{ns_string, NSNumber, NSObjectProtocol, NSSet};
+use objc2_foundation::{ns_string, NSNumber, NSObjectProtocol, NSSet, NSUserActivity};
 use objc2_ui_kit::{
     UICoordinateSpace, UIInterfaceOrientation, UIOpenURLContext, UIResponder, UIScene,
     UISceneConnectionOptions, UISceneDelegate, UISceneSession, UITraitCollection, UIWindow,
@@ -18,9 +21,26 @@ use objc2_ui_kit::{
 };
 use tracing::trace;
 
-use crate::app::access_app;
-use crate::windows::{setup_window, WorldHelper};
-use crate::{UIKitWindows, USER_INFO_WINDOW_ENTITY_ID, WINDOW_ACTIVITY_TYPE};
+use crate::app::{
+    guard_callback, send_message, update_app, update_app_for_window, with_world,
+    ApplicationDelegate,
+};
+use crate::app_clip::{invocation_url, AppClipInvocation};
+use crate::deep_link::{open_deep_link, DeepLink};
+use crate::launch::LaunchTimings;
+use crate::open_file::open_file;
+use crate::orientation::update_interface_orientation;
+#[cfg(not(target_os = "tvos"))]
+use crate::resize::{begin_window_resize, finish_window_resize};
+use crate::scene_session::{
+    DelayWindowDespawn, DisconnectedWindow, SceneDisconnected, SceneSession,
+};
+#[cfg(feature = "core-spotlight")]
+use crate::spotlight::{spotlight_item_identifier, SpotlightItemOpened};
+use crate::stage_manager::update_stage_manager_window;
+use crate::view::become_first_responder;
+use crate::windows::{handle_resize, setup_window, WorldHelper};
+use crate::{UIKitSettings, UIKitWindows, USER_INFO_WINDOW_ENTITY_ID, WINDOW_ACTIVITY_TYPE};
 
 pub(crate) struct Ivars {
     entity: Cell<Option<Entity>>,
@@ -55,159 +75,303 @@ define_class!(
             session: &UISceneSession,
             connection_options: &UISceneConnectionOptions,
         ) {
-            trace!(
-                scene = ?scene.session().persistentIdentifier(),
-                user_info = ?session.userInfo(),
-                configuration = ?session.configuration(),
-                ?connection_options,
-                "scene:willConnectToSession:options:"
-            );
-
-            let scene = scene.downcast_ref::<UIWindowScene>().unwrap();
-
-            let mut app = access_app(self.mtm());
-            let world = app.world_mut();
-
-            // Try to get `Entity` that was passed by `create_windows`.
-            let entity = connection_options
-                .userActivities()
-                .iter()
-                .find(|activity| &*activity.activityType() == ns_string!(WINDOW_ACTIVITY_TYPE))
-                .and_then(|activity| activity.userInfo())
-                .and_then(|user_info| {
-                    user_info.objectForKey(ns_string!(USER_INFO_WINDOW_ENTITY_ID))
-                })
-                .and_then(|obj| obj.downcast::<NSNumber>().ok())
-                .map(|number| Entity::from_bits(number.as_u64()));
-
-            let (entity, uikit_window) = if let Some(entity) = entity {
-                trace!("creating requested window");
-                let window = world
-                    .get::<Window>(entity)
-                    .expect("failed fetching Window component on newly created window");
-                let uikit_window = setup_window(Some(scene), entity, window, self.mtm());
-                (entity, uikit_window)
-            } else {
-                // The entity can be missing in two scenarios:
-                // - This is the initial launch.
-                // - The user decided to launch a new window using system buttons.
-                let query = world
-                    .query_filtered::<Entity, With<PrimaryWindow>>()
-                    .single(&world);
-                match query {
-                    Ok(entity) => {
-                        // If we have a primary window, check if we have already initialized it.
-                        let uikit_windows = world.non_send_resource_mut::<UIKitWindows>();
-                        if !uikit_windows.is_initialized(entity) {
-                            trace!("initializing primary window");
-                            // If we have not, assume this is the initial launch, and configure the entity.
+            guard_callback("scene:willConnectToSession:options:", || {
+                trace!(
+                    scene = ?scene.session().persistentIdentifier(),
+                    user_info = ?session.userInfo(),
+                    configuration = ?session.configuration(),
+                    ?connection_options,
+                    "scene:willConnectToSession:options:"
+                );
+
+                let scene = scene.downcast_ref::<UIWindowScene>().unwrap();
+
+                let delegate = ApplicationDelegate::get(self.mtm());
+                let mut app = delegate.app();
+                let world = app.world_mut();
+                let settings = world.resource::<UIKitSettings>().clone();
+
+                // Try to get `Entity` that was passed by `create_windows`.
+                let entity = connection_options
+                    .userActivities()
+                    .iter()
+                    .find(|activity| &*activity.activityType() == ns_string!(WINDOW_ACTIVITY_TYPE))
+                    .and_then(|activity| activity.userInfo())
+                    .and_then(|user_info| {
+                        user_info.objectForKey(ns_string!(USER_INFO_WINDOW_ENTITY_ID))
+                    })
+                    .and_then(|obj| obj.downcast::<NSNumber>().ok())
+                    .map(|number| Entity::from_bits(number.as_u64()));
+
+                let (entity, uikit_window) = if let Some(entity) = entity {
+                    trace!("creating requested window");
+                    let window = world
+                        .get::<Window>(entity)
+                        .expect("failed fetching Window component on newly created window");
+                    let uikit_window =
+                        setup_window(Some(scene), entity, window, &settings, self.mtm());
+                    (entity, uikit_window)
+                } else {
+                    // The entity can be missing in two scenarios:
+                    // - This is the initial launch.
+                    // - The user decided to launch a new window using system buttons.
+                    // A disconnected primary window may still be waiting to be despawned.
+                    let query = world
+                        .query_filtered::<
+                            Entity,
+                            (With<PrimaryWindow>, Without<DisconnectedWindow>),
+                        >()
+                        .single(&world);
+                    match query {
+                        Ok(entity) => {
+                            // If we have a primary window, check if we have already initialized it.
+                            let uikit_windows = world.resource::<UIKitWindows>();
+                            if !uikit_windows.is_initialized(entity) {
+                                trace!("initializing primary window");
+                                // If we have not, assume this is the initial launch, and configure the entity.
+                                let window = world.get::<Window>(entity).unwrap();
+                                let uikit_window = setup_window(
+                                    Some(scene),
+                                    entity,
+                                    window,
+                                    &settings,
+                                    self.mtm(),
+                                );
+                                (entity, uikit_window)
+                            } else {
+                                trace!("creating system-requested window");
+                                // Otherwise, assume that this is a user-launched window.
+                                let entity = match settings.spawn_system_window {
+                                    Some(spawn) => spawn(world),
+                                    None => world.spawn(Window::default()).id(),
+                                };
+                                let window = world.get::<Window>(entity).expect(
+                                    "window spawned by spawn_system_window must have a Window",
+                                );
+                                let uikit_window = setup_window(
+                                    Some(scene),
+                                    entity,
+                                    window,
+                                    &settings,
+                                    self.mtm(),
+                                );
+                                (entity, uikit_window)
+                            }
+                        }
+                        Err(QuerySingleError::NoEntities(_)) => {
+                            trace!("creating primary window");
+                            // If there was no primary window, let's create it ourselves.
+                            let entity = settings.spawn_primary_window(world);
                             let window = world.get::<Window>(entity).unwrap();
                             let uikit_window =
-                                setup_window(Some(scene), entity, window, self.mtm());
+                                setup_window(Some(scene), entity, window, &settings, self.mtm());
                             (entity, uikit_window)
-                        } else {
-                            trace!("creating system-requested window");
-                            // Otherwise, assume that this is a user-launched window.
-                            let entity = world.spawn(Window::default());
-                            let window = entity.get::<Window>().unwrap();
-                            let uikit_window =
-                                setup_window(Some(scene), entity.id(), window, self.mtm());
-                            (entity.id(), uikit_window)
                         }
+                        Err(e) => panic!("failed fetching primary window: {e}"),
+                    }
+                };
+
+                self.ivars().entity.set(Some(entity));
+                let uiwindow = uikit_window.uiwindow.retain().into_super();
+                self.ivars().window.set(Some(uiwindow));
+
+                world
+                    .resource_mut::<UIKitWindows>()
+                    .insert(entity, uikit_window);
+                let mut timings = world.resource_mut::<LaunchTimings>();
+                if timings.first_scene_connected.is_none() {
+                    timings.first_scene_connected = Some(SystemTime::now());
+                }
+                update_interface_orientation(world, entity, scene);
+                world.entity_mut(entity).insert(SceneSession {
+                    identifier: session.persistentIdentifier().to_string(),
+                });
+                world.send_window_message(WindowCreated { window: entity });
+
+                for activity in connection_options.userActivities() {
+                    if &*activity.activityType() != ns_string!(WINDOW_ACTIVITY_TYPE) {
+                        let link = DeepLink::from_activity(&activity);
+                        open_deep_link(world, Some(entity), link, true);
+                    }
+                    if let Some(url) = invocation_url(&activity) {
+                        trace!(?url, "launched with App Clip invocation");
+                        world.write_message(AppClipInvocation {
+                            window: entity,
+                            url,
+                        });
                     }
-                    Err(QuerySingleError::NoEntities(_)) => {
-                        trace!("creating primary window");
-                        // If there was no primary window, let's create it ourselves.
-                        let entity = world.spawn((Window::default(), PrimaryWindow));
-                        let window = entity.get::<Window>().unwrap();
-                        let uikit_window =
-                            setup_window(Some(scene), entity.id(), window, self.mtm());
-                        (entity.id(), uikit_window)
+                    #[cfg(feature = "core-spotlight")]
+                    if let Some(identifier) = spotlight_item_identifier(&activity) {
+                        trace!(?identifier, "launched from Spotlight item");
+                        world.write_message(SpotlightItemOpened {
+                            window: entity,
+                            identifier,
+                        });
                     }
-                    Err(e) => panic!("failed fetching primary window: {e}"),
                 }
-            };
 
-            self.ivars().entity.set(Some(entity));
-            let uiwindow = uikit_window.uiwindow.retain().into_super();
-            self.ivars().window.set(Some(uiwindow));
+                for context in connection_options.URLContexts() {
+                    let url = context.URL();
+                    if !open_file(world, Some(entity), &url, context.options().openInPlace()) {
+                        if let Some(link) = DeepLink::from_url(&url) {
+                            open_deep_link(world, Some(entity), link, true);
+                        }
+                    }
+                }
 
-            world
-                .non_send_resource_mut::<UIKitWindows>()
-                .insert(entity, uikit_window);
-            world.send_window_message(WindowCreated { window: entity });
-            app.update();
+                update_app(&mut app);
+            })
         }
 
         #[unsafe(method(sceneWillEnterForeground:))]
         fn sceneWillEnterForeground(&self, scene: &UIScene) {
-            trace!(scene = ?scene.session().persistentIdentifier(), "sceneWillEnterForeground:");
+            guard_callback("sceneWillEnterForeground:", || {
+                trace!(scene = ?scene.session().persistentIdentifier(), "sceneWillEnterForeground:");
 
-            let mut app = access_app(self.mtm());
-            if let Some(window) = self.ivars().entity.get() {
-                app.world_mut()
-                    .send_window_message(WindowForeground { window });
-            }
-            app.update();
+                let delegate = ApplicationDelegate::get(self.mtm());
+                let mut app = delegate.app();
+                let window = self.ivars().entity.get();
+                if let Some(window) = window {
+                    app.world_mut()
+                        .send_window_message(WindowForeground { window });
+                }
+                update_app_for_window(&mut app, window);
+            })
         }
 
         #[unsafe(method(sceneDidBecomeActive:))]
         fn sceneDidBecomeActive(&self, scene: &UIScene) {
-            trace!(scene = ?scene.session().persistentIdentifier(), "sceneDidBecomeActive:");
+            guard_callback("sceneDidBecomeActive:", || {
+                trace!(scene = ?scene.session().persistentIdentifier(), "sceneDidBecomeActive:");
 
-            let mut app = access_app(self.mtm());
-            if let Some(window) = self.ivars().entity.get() {
-                app.world_mut()
-                    .send_window_message(WindowActivate { window });
-            }
-            app.update();
+                let delegate = ApplicationDelegate::get(self.mtm());
+                let mut app = delegate.app();
+                let window = self.ivars().entity.get();
+                if let Some(window) = window {
+                    app.world_mut()
+                        .send_window_message(WindowActivate { window });
+                }
+                update_app_for_window(&mut app, window);
+
+                let automatic_first_responder = app
+                    .world()
+                    .resource::<UIKitSettings>()
+                    .automatic_first_responder;
+                // Release the app, becoming first responder emits messages.
+                drop(app);
+                if automatic_first_responder {
+                    if let Some(window) = self.window() {
+                        become_first_responder(&window);
+                    }
+                }
+            })
         }
 
         #[unsafe(method(sceneWillResignActive:))]
         fn sceneWillResignActive(&self, scene: &UIScene) {
-            trace!(scene = ?scene.session().persistentIdentifier(), "sceneWillResignActive:");
+            guard_callback("sceneWillResignActive:", || {
+                trace!(scene = ?scene.session().persistentIdentifier(), "sceneWillResignActive:");
 
-            let mut app = access_app(self.mtm());
-            if let Some(window) = self.ivars().entity.get() {
-                app.world_mut()
-                    .send_window_message(WindowDeactivate { window });
-            }
-            app.update();
+                let delegate = ApplicationDelegate::get(self.mtm());
+                let mut app = delegate.app();
+                let window = self.ivars().entity.get();
+                if let Some(window) = window {
+                    app.world_mut()
+                        .send_window_message(WindowDeactivate { window });
+                }
+                update_app_for_window(&mut app, window);
+            })
         }
 
         #[unsafe(method(sceneDidEnterBackground:))]
         fn sceneDidEnterBackground(&self, scene: &UIScene) {
-            trace!(scene = ?scene.session().persistentIdentifier(), "sceneDidEnterBackground:");
+            guard_callback("sceneDidEnterBackground:", || {
+                trace!(scene = ?scene.session().persistentIdentifier(), "sceneDidEnterBackground:");
 
-            let mut app = access_app(self.mtm());
-            if let Some(window) = self.ivars().entity.get() {
-                app.world_mut()
-                    .send_window_message(WindowBackground { window });
-            }
-            app.update();
+                let delegate = ApplicationDelegate::get(self.mtm());
+                let mut app = delegate.app();
+                let window = self.ivars().entity.get();
+                if let Some(window) = window {
+                    app.world_mut()
+                        .send_window_message(WindowBackground { window });
+                }
+                update_app_for_window(&mut app, window);
+            })
         }
 
         #[unsafe(method(sceneDidDisconnect:))]
         fn sceneDidDisconnect(&self, scene: &UIScene) {
-            trace!(scene = ?scene.session().persistentIdentifier(), "sceneDidDisconnect:");
-
-            let mut app = access_app(self.mtm());
-            // User/system may have requested scene destruction; if so, we remove it from the world.
-            if let Some(entity) = self.ivars().entity.get() {
-                // despawn_windows will take care of unregistering from UIKitWindows.
-                // Ignore if it doesn't exist, that's likely because someone else despawned it.
-                let _ = app.world_mut().try_despawn(entity);
-                app.world_mut()
-                    .send_window_message(WindowDestroyed { window: entity });
-                self.ivars().entity.set(None);
-            }
-            app.update();
+            guard_callback("sceneDidDisconnect:", || {
+                trace!(scene = ?scene.session().persistentIdentifier(), "sceneDidDisconnect:");
+
+                let delegate = ApplicationDelegate::get(self.mtm());
+                let mut app = delegate.app();
+                // User/system may have requested scene destruction; if so, we remove it from the world.
+                if let Some(entity) = self.ivars().entity.get() {
+                    let world = app.world_mut();
+                    // Unregister here, as despawn_windows would otherwise request destruction of
+                    // the session, which should persist.
+                    world
+                        .resource_mut::<UIKitWindows>()
+                        .remove(entity, self.mtm());
+                    world.write_message(SceneDisconnected {
+                        window: entity,
+                        session: scene.session().persistentIdentifier().to_string(),
+                    });
+                    if let Some(delay) = world.get::<DelayWindowDespawn>(entity).copied() {
+                        trace!(?entity, ?delay, "delaying despawn of disconnected window");
+                        world.entity_mut(entity).insert(DisconnectedWindow {
+                            remaining_frames: delay.max_frames,
+                        });
+                    } else {
+                        // Ignore if it doesn't exist, that's likely because someone else despawned it.
+                        let _ = world.try_despawn(entity);
+                        world.send_window_message(WindowDestroyed { window: entity });
+                    }
+                    self.ivars().entity.set(None);
+                }
+                update_app(&mut app);
+            })
         }
 
         #[unsafe(method(scene:openURLContexts:))]
         fn scene_openURLContexts(&self, scene: &UIScene, url_contexts: &NSSet<UIOpenURLContext>) {
             trace!(scene = ?scene.session().persistentIdentifier(), ?url_contexts, "scene:openURLContexts:");
-            // TODO: Handle URL opening
+
+            let window = self.ivars().entity.get();
+            let urls: Vec<_> = url_contexts
+                .iter()
+                .map(|context| (context.URL(), context.options().openInPlace()))
+                .collect();
+            with_world(self.mtm(), move |world| {
+                for (url, in_place) in urls {
+                    if !open_file(world, window, &url, in_place) {
+                        if let Some(link) = DeepLink::from_url(&url) {
+                            open_deep_link(world, window, link, false);
+                        }
+                    }
+                }
+            });
+        }
+
+        #[unsafe(method(scene:continueUserActivity:))]
+        fn scene_continueUserActivity(&self, scene: &UIScene, user_activity: &NSUserActivity) {
+            trace!(scene = ?scene.session().persistentIdentifier(), ?user_activity, "scene:continueUserActivity:");
+
+            let Some(window) = self.ivars().entity.get() else {
+                return;
+            };
+            let link = DeepLink::from_activity(user_activity);
+            with_world(self.mtm(), move |world| {
+                open_deep_link(world, Some(window), link, false);
+            });
+            if let Some(url) = invocation_url(user_activity) {
+                send_message(self.mtm(), AppClipInvocation { window, url });
+            }
+            #[cfg(feature = "core-spotlight")]
+            if let Some(identifier) = spotlight_item_identifier(user_activity) {
+                send_message(self.mtm(), SpotlightItemOpened { window, identifier });
+            }
         }
     }
 
@@ -225,19 +389,41 @@ define_class!(
         #[unsafe(method(windowScene:didUpdateCoordinateSpace:interfaceOrientation:traitCollection:))]
         fn windowScene_didUpdateCoordinateSpace_interfaceOrientation_traitCollection(
             &self,
-            _scene: &UIWindowScene,
+            scene: &UIWindowScene,
             _previous_coordinate_space: &ProtocolObject<dyn UICoordinateSpace>,
             _previous_interface_orientation: UIInterfaceOrientation,
             _previous_trait_collection: &UITraitCollection,
         ) {
             // Happens quite often apparently?
             // trace!(
-            //     scene = ?_scene.session().persistentIdentifier(),
+            //     scene = ?scene.session().persistentIdentifier(),
             //     ?_previous_coordinate_space,
             //     ?_previous_interface_orientation,
             //     ?_previous_trait_collection,
             //     "windowScene:didUpdateCoordinateSpace:interfaceOrientation:traitCollection:",
             // );
+
+            let Some(entity) = self.ivars().entity.get() else {
+                return;
+            };
+            // Keep the window's size up to date, including while it's being interactively resized.
+            let scene = scene.retain();
+            let mtm = self.mtm();
+            with_world(mtm, move |world| {
+                // `isInteractivelyResizing` is unavailable on tvOS.
+                #[cfg(not(target_os = "tvos"))]
+                if available!(ios = 17.0, visionos = 1.0, ..) {
+                    if scene.effectiveGeometry().isInteractivelyResizing() {
+                        begin_window_resize(world, mtm, entity, true);
+                    } else {
+                        finish_window_resize(world, entity, true);
+                    }
+                }
+                let size = scene.coordinateSpace().bounds().size;
+                handle_resize(world, entity, size, &scene.screen());
+                update_stage_manager_window(world, entity, &scene);
+                update_interface_orientation(world, entity, &scene);
+            });
         }
     }
 );
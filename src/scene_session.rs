@@ -0,0 +1,92 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    message::{Message, MessageWriter},
+    query::Has,
+    system::{Commands, Query},
+};
+use bevy_window::{WindowDestroyed, WindowEvent};
+use tracing::trace;
+
+/// The identifier of the scene session that a window is shown in.
+///
+/// The identifier is stable across launches, as long as the session is not discarded, so it can
+/// be used as a key for per-window state restoration data, such as a save slot. Inserted on the
+/// entity of the [`Window`](bevy_window::Window) when its scene connects.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SceneSession {
+    /// The session's `persistentIdentifier`.
+    pub identifier: String,
+}
+
+/// The scene of a window was disconnected, and the window was despawned (or will be, see
+/// [`DelayWindowDespawn`]).
+///
+/// This usually happens when the system reclaims the scene's resources while it's in the
+/// background. The session persists, and the user may reconnect to it later, at which point a new
+/// window is created with the same [`SceneSession`], so restoration data should be kept.
+///
+/// If the user closed the window instead, this is followed by [`SceneSessionsDiscarded`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SceneDisconnected {
+    /// The window that was shown in the scene.
+    pub window: Entity,
+    /// The [`SceneSession::identifier`] of the scene.
+    pub session: String,
+}
+
+/// The user closed windows, such as in the app switcher, and their scene sessions were discarded.
+///
+/// These sessions will never be reconnected, so restoration data for them should be deleted.
+///
+/// Note that if the sessions were discarded while the application was not running, this is
+/// emitted shortly after the next launch.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SceneSessionsDiscarded {
+    /// The [`SceneSession::identifier`]s of the discarded sessions.
+    pub sessions: Vec<String>,
+}
+
+/// Delay despawning the window after its scene is disconnected, e.g. to finish writing
+/// per-window state on [`SceneDisconnected`].
+///
+/// While delayed, the window has a [`DisconnectedWindow`] component, but is no longer shown.
+/// Remove this component once done to despawn the window, otherwise it is despawned after
+/// `max_frames` updates. Add this to the entity of the [`Window`](bevy_window::Window).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DelayWindowDespawn {
+    /// The maximum number of updates to delay despawning the window for.
+    pub max_frames: u32,
+}
+
+/// Marks a window whose scene was disconnected, and whose despawn is delayed with
+/// [`DelayWindowDespawn`].
+///
+/// The window keeps its [`Window`](bevy_window::Window) component (and
+/// [`PrimaryWindow`](bevy_window::PrimaryWindow), if any), but is no longer managed by the backend:
+/// changes to it are ignored, its size is no longer updated, and a newly connected scene does not
+/// reuse it as the primary window.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisconnectedWindow {
+    /// The number of updates left until the window is despawned.
+    pub remaining_frames: u32,
+}
+
+/// Despawn disconnected windows once [`DelayWindowDespawn`] is removed, or the delay ran out.
+pub fn despawn_disconnected_windows(
+    mut windows: Query<(Entity, &mut DisconnectedWindow, Has<DelayWindowDespawn>)>,
+    mut commands: Commands,
+    mut destroyed: MessageWriter<WindowDestroyed>,
+    mut window_events: MessageWriter<WindowEvent>,
+) {
+    for (entity, mut disconnected, delayed) in &mut windows {
+        if delayed && disconnected.remaining_frames > 0 {
+            disconnected.remaining_frames -= 1;
+            continue;
+        }
+        trace!(?entity, delayed, "despawning disconnected window");
+        commands.entity(entity).despawn();
+        destroyed.write(WindowDestroyed { window: entity });
+        window_events.write(WindowDestroyed { window: entity }.into());
+    }
+}
@@ -0,0 +1,72 @@
+use std::ptr::NonNull;
+
+use bevy_ecs::{
+    entity::Entity,
+    message::{Message, MessageReader, MessageWriter},
+    system::{NonSendMarker, Res},
+};
+use block2::RcBlock;
+use objc2::rc::Retained;
+use objc2::AllocAnyThread;
+use objc2_foundation::NSData;
+use objc2_ui_kit::{UIGraphicsImageRenderer, UIGraphicsImageRendererContext, UIWindow};
+use tracing::{trace, warn};
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Request that the current contents of a window is captured.
+///
+/// The result is delivered as a [`WindowScreenshotCaptured`] message once done.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureWindowScreenshot {
+    /// The window to capture.
+    pub window: Entity,
+}
+
+/// The contents of a window, as requested by [`CaptureWindowScreenshot`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct WindowScreenshotCaptured {
+    /// The window that was captured.
+    pub window: Entity,
+    /// The PNG-encoded contents of the window, in its native resolution.
+    pub png: Vec<u8>,
+}
+
+/// Capture the contents of windows requested with [`CaptureWindowScreenshot`].
+pub fn capture_window_screenshots(
+    mut requests: MessageReader<CaptureWindowScreenshot>,
+    mut captured: MessageWriter<WindowScreenshotCaptured>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for CaptureWindowScreenshot { window } in requests.read().copied() {
+        let Some(uikit_window) = uikit_windows.get(window, mtm) else {
+            warn!(
+                ?window,
+                "tried to capture screenshot of window not known to UIKit"
+            );
+            continue;
+        };
+        trace!(?window, "capturing screenshot");
+        let png = window_png(uikit_window.uiwindow.clone().into_super());
+        captured.write(WindowScreenshotCaptured {
+            window,
+            png: png.to_vec(),
+        });
+    }
+}
+
+/// Render the current contents of the window as PNG.
+pub(crate) fn window_png(uiwindow: Retained<UIWindow>) -> Retained<NSData> {
+    let bounds = uiwindow.bounds();
+    let renderer =
+        UIGraphicsImageRenderer::initWithBounds(UIGraphicsImageRenderer::alloc(), bounds);
+
+    // `afterScreenUpdates: false`, we want whatever is currently shown on screen (and doing
+    // otherwise would require UIKit to render a new frame while we're inside a Bevy update).
+    let actions = RcBlock::new(move |_context: NonNull<UIGraphicsImageRendererContext>| {
+        let _ = uiwindow.drawViewHierarchyInRect_afterScreenUpdates(bounds, false);
+    });
+    renderer.PNGDataWithActions(&actions)
+}
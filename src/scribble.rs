@@ -0,0 +1,292 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use std::cell::{Cell, RefCell};
+use std::ptr::NonNull;
+
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    message::Message,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_math::{Rect, Vec2};
+use block2::DynBlock;
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, ProtocolObject};
+use objc2::{
+    available, define_class, msg_send, sel, DefinedClass as _, MainThreadMarker, MainThreadOnly,
+};
+use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+use objc2_foundation::{NSArray, NSNumber, NSObject, NSObjectProtocol, NSString};
+use objc2_ui_kit::{
+    UIControlEvents, UIIndirectScribbleInteraction, UIIndirectScribbleInteractionDelegate,
+    UIResponder, UITextField, UIView,
+};
+use tracing::trace;
+
+use crate::app::send_message;
+use crate::property_list::upcast;
+use crate::view::View;
+use crate::{main_thread_marker, UIKitWindows};
+
+/// A text field drawn by the application that Apple Pencil handwriting (Scribble) can write into,
+/// see [`ScribbleFields`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScribbleField {
+    /// An identifier for the field, reported back in [`ScribbleInput`].
+    pub id: u64,
+    /// The frame of the field, in logical coordinates relative to the top-left corner of the
+    /// window.
+    pub rect: Rect,
+    /// The current text of the field, which handwriting is inserted into.
+    pub text: String,
+}
+
+/// Text fields in a window that can be written into with Apple Pencil on iPad.
+///
+/// When the user starts writing over a field, the handwritten text is recognized by the system
+/// and delivered as [`ScribbleInput`] messages. Add this to the entity of the
+/// [`Window`](bevy_window::Window).
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ScribbleFields {
+    /// The fields.
+    pub fields: Vec<ScribbleField>,
+}
+
+/// The text of a [`ScribbleField`] was changed by handwriting.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ScribbleInput {
+    /// The window containing the field.
+    pub window: Entity,
+    /// The [`ScribbleField::id`] of the field.
+    pub field: u64,
+    /// The full new text of the field.
+    pub text: String,
+}
+
+/// Propagate changes to [`ScribbleFields`] to the native view.
+pub fn update_scribble_fields(
+    scribble_fields: Query<(Entity, Ref<ScribbleFields>)>,
+    mut removed: RemovedComponents<ScribbleFields>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    if !available!(ios = 14.0, visionos = 1.0, ..) {
+        return;
+    }
+
+    for (entity, scribble_fields) in &scribble_fields {
+        // Also update when the window was only just registered with UIKit.
+        if !scribble_fields.is_changed() && !uikit_windows.is_changed() {
+            continue;
+        }
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            trace!(?entity, fields = ?scribble_fields.fields, "setting Scribble fields");
+            let mut delegate = view.ivars().scribble_delegate.borrow_mut();
+            let delegate =
+                delegate.get_or_insert_with(|| ScribbleDelegate::install(&view, entity, mtm));
+            *delegate.ivars().fields.borrow_mut() = scribble_fields.fields.clone();
+        }
+    }
+
+    for entity in removed.read() {
+        if let Some(view) = uikit_windows
+            .get(entity, mtm)
+            .and_then(|window| window.view())
+        {
+            if let Some(delegate) = &*view.ivars().scribble_delegate.borrow() {
+                delegate.ivars().fields.borrow_mut().clear();
+                delegate.ivars().text_field.resignFirstResponder();
+            }
+        }
+    }
+}
+
+pub(crate) fn rect_to_uikit(rect: Rect) -> CGRect {
+    CGRect {
+        origin: CGPoint {
+            x: rect.min.x as _,
+            y: rect.min.y as _,
+        },
+        size: CGSize {
+            width: rect.width() as _,
+            height: rect.height() as _,
+        },
+    }
+}
+
+fn rect_from_uikit(rect: CGRect) -> Rect {
+    Rect::from_corners(
+        Vec2::new(rect.origin.x as f32, rect.origin.y as f32),
+        Vec2::new(
+            (rect.origin.x + rect.size.width) as f32,
+            (rect.origin.y + rect.size.height) as f32,
+        ),
+    )
+}
+
+#[derive(Debug)]
+pub(crate) struct ScribbleDelegateIvars {
+    window: Entity,
+    fields: RefCell<Vec<ScribbleField>>,
+    /// The field that is currently being written into.
+    focused: Cell<Option<u64>>,
+    /// An invisible native text field that handwriting is inserted into while a field is focused.
+    ///
+    /// Scribble requires a `UITextInput` to write into, so this avoids implementing that on
+    /// Bevy's view.
+    text_field: Retained<UITextField>,
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "BevyScribbleDelegate"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = ScribbleDelegateIvars]
+    #[derive(Debug)]
+    pub(crate) struct ScribbleDelegate;
+
+    unsafe impl NSObjectProtocol for ScribbleDelegate {}
+
+    unsafe impl UIIndirectScribbleInteractionDelegate for ScribbleDelegate {
+        #[unsafe(method(indirectScribbleInteraction:requestElementsInRect:completion:))]
+        fn indirectScribbleInteraction_requestElementsInRect_completion(
+            &self,
+            _interaction: &UIIndirectScribbleInteraction,
+            rect: CGRect,
+            completion: &DynBlock<dyn Fn(NonNull<NSArray<AnyObject>>)>,
+        ) {
+            let rect = rect_from_uikit(rect);
+            let elements: Vec<Retained<AnyObject>> = self
+                .ivars()
+                .fields
+                .borrow()
+                .iter()
+                .filter(|field| !field.rect.intersect(rect).is_empty())
+                .map(|field| upcast(NSNumber::new_u64(field.id)))
+                .collect();
+            let elements = NSArray::from_retained_slice(&elements);
+            completion.call((NonNull::from(&*elements),));
+        }
+
+        #[unsafe(method(indirectScribbleInteraction:isElementFocused:))]
+        fn indirectScribbleInteraction_isElementFocused(
+            &self,
+            _interaction: &UIIndirectScribbleInteraction,
+            element: &AnyObject,
+        ) -> bool {
+            let ivars = self.ivars();
+            field_id(element).is_some_and(|id| ivars.focused.get() == Some(id))
+                && ivars.text_field.isFirstResponder()
+        }
+
+        #[unsafe(method(indirectScribbleInteraction:frameForElement:))]
+        fn indirectScribbleInteraction_frameForElement(
+            &self,
+            _interaction: &UIIndirectScribbleInteraction,
+            element: &AnyObject,
+        ) -> CGRect {
+            field_id(element)
+                .and_then(|id| self.field(id))
+                .map(|field| rect_to_uikit(field.rect))
+                .unwrap_or(CGRect::ZERO)
+        }
+
+        #[unsafe(method(indirectScribbleInteraction:focusElementIfNeeded:referencePoint:completion:))]
+        fn indirectScribbleInteraction_focusElementIfNeeded_referencePoint_completion(
+            &self,
+            _interaction: &UIIndirectScribbleInteraction,
+            element: &AnyObject,
+            _reference_point: CGPoint,
+            completion: &DynBlock<dyn Fn(*mut UIResponder)>,
+        ) {
+            let ivars = self.ivars();
+            let Some(field) = field_id(element).and_then(|id| self.field(id)) else {
+                completion.call((std::ptr::null_mut(),));
+                return;
+            };
+            trace!(id = field.id, "focusing Scribble field");
+            ivars.focused.set(Some(field.id));
+            ivars.text_field.setFrame(rect_to_uikit(field.rect));
+            ivars.text_field.setText(Some(&NSString::from_str(&field.text)));
+            ivars.text_field.becomeFirstResponder();
+
+            let text_field: &UIResponder = &ivars.text_field;
+            completion.call((text_field as *const UIResponder as *mut UIResponder,));
+        }
+    }
+
+    impl ScribbleDelegate {
+        #[unsafe(method(textFieldDidChange:))]
+        fn textFieldDidChange(&self, text_field: &UITextField) {
+            let Some(field) = self.ivars().focused.get() else {
+                return;
+            };
+            let text = text_field.text().map(|text| text.to_string()).unwrap_or_default();
+            trace!(field, text, "Scribble input");
+            send_message(
+                self.mtm(),
+                ScribbleInput {
+                    window: self.ivars().window,
+                    field,
+                    text,
+                },
+            );
+        }
+    }
+);
+
+fn field_id(element: &AnyObject) -> Option<u64> {
+    Some(element.downcast_ref::<NSNumber>()?.as_u64())
+}
+
+impl ScribbleDelegate {
+    /// Create the delegate, and add an indirect Scribble interaction using it to the view.
+    fn install(view: &View, window: Entity, mtm: MainThreadMarker) -> Retained<Self> {
+        let text_field = UITextField::new(mtm);
+        text_field.setAlpha(0.0);
+        let view: &UIView = view;
+        view.addSubview(&text_field);
+
+        let this = Self::alloc(mtm).set_ivars(ScribbleDelegateIvars {
+            window,
+            fields: RefCell::new(Vec::new()),
+            focused: Cell::new(None),
+            text_field,
+        });
+        let this: Retained<Self> = unsafe { msg_send![super(this), init] };
+
+        let target: &AnyObject = &this;
+        // SAFETY: The target has a method with the given selector, which takes the text field.
+        unsafe {
+            this.ivars().text_field.addTarget_action_forControlEvents(
+                Some(target),
+                sel!(textFieldDidChange:),
+                UIControlEvents::EditingChanged,
+            );
+        }
+
+        // The interaction only holds a weak reference to its delegate.
+        let interaction = UIIndirectScribbleInteraction::initWithDelegate(
+            UIIndirectScribbleInteraction::alloc(mtm),
+            ProtocolObject::from_ref(&*this),
+        );
+        view.addInteraction(ProtocolObject::from_ref(&*interaction));
+        this
+    }
+
+    fn field(&self, id: u64) -> Option<ScribbleField> {
+        self.ivars()
+            .fields
+            .borrow()
+            .iter()
+            .find(|field| field.id == id)
+            .cloned()
+    }
+}
@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use bevy_ecs::component::Component;
+use objc2::{rc::Retained, Message as _};
+use objc2_foundation::NSURL;
+use tracing::trace;
+
+/// Access to a security-scoped resource, such as a file opened from the Files app, a document
+/// picker or from drag and drop.
+///
+/// Files outside of the application's sandbox can only be read while access has been started, so
+/// keep this around for as long as the file is in use. Access is stopped again when this is
+/// dropped, so it can either be used as a scope guard, or inserted as a component to tie the
+/// access to the lifetime of an entity.
+#[derive(Component, Debug)]
+pub struct SecurityScopedUrl {
+    url: Retained<NSURL>,
+    accessing: bool,
+}
+
+impl SecurityScopedUrl {
+    /// Start accessing the resource at the given URL.
+    ///
+    /// This succeeds even if the URL is not security-scoped (such as for files inside the
+    /// application's sandbox), use [`is_accessing`](Self::is_accessing) to check whether access
+    /// was actually granted.
+    pub fn new(url: &NSURL) -> Self {
+        let accessing = url.startAccessingSecurityScopedResource();
+        if accessing {
+            trace!(?url, "started accessing security-scoped resource");
+        } else {
+            // Expected for resources inside the sandbox, reads only fail if it was outside.
+            trace!(?url, "did not get access to security-scoped resource");
+        }
+        Self {
+            url: url.retain(),
+            accessing,
+        }
+    }
+
+    /// Whether access to the security-scoped resource was granted.
+    pub fn is_accessing(&self) -> bool {
+        self.accessing
+    }
+
+    /// The underlying URL.
+    pub fn url(&self) -> &NSURL {
+        &self.url
+    }
+
+    /// The file system path of the resource, if it is a file URL.
+    pub fn path(&self) -> Option<PathBuf> {
+        self.url.to_file_path()
+    }
+}
+
+impl Drop for SecurityScopedUrl {
+    fn drop(&mut self) {
+        if self.accessing {
+            trace!(url = ?self.url, "stopped accessing security-scoped resource");
+            self.url.stopAccessingSecurityScopedResource();
+        }
+    }
+}
@@ -1,5 +1,165 @@
-use bevy_ecs::resource::Resource;
+use std::time::Duration;
+
+use bevy_ecs::{
+    entity::Entity,
+    resource::Resource,
+    schedule::InternedScheduleLabel,
+    world::{EntityWorldMut, World},
+};
+use bevy_window::{PrimaryWindow, Window, WindowTheme};
+use objc2_ui_kit::UIEvent;
+
+use crate::{
+    DocumentBrowserSettings, GestureSettings, InactiveUpdates, ScaleMode, ThermalQualityScaling,
+    TouchCoordinates,
+};
+
+/// A filter for events that UIKit delivers to a window, see
+/// [`UIKitSettings::raw_event_filter`].
+///
+/// Returns whether the event should be dispatched further by UIKit.
+pub type RawEventFilter = fn(window: Entity, event: &UIEvent) -> bool;
+
+/// Spawns a window for a scene that the system created, see [`UIKitSettings::spawn_system_window`].
+///
+/// Returns the spawned entity, which must have a [`Window`] component.
+pub type SpawnSystemWindow = fn(world: &mut World) -> Entity;
+
+/// Inserts extra components on the primary window, see [`UIKitSettings::insert_primary_window`].
+pub type InsertPrimaryWindow = fn(entity: &mut EntityWorldMut<'_>);
 
 /// Settings for the [`UIKitPlugin`](super::UIKitPlugin).
-#[derive(Debug, Default, Resource, Clone)]
-pub struct UIKitSettings {}
+///
+/// Most settings are applied when a window is created, so they should be configured before the
+/// application is run.
+#[derive(Debug, Resource, Clone)]
+pub struct UIKitSettings {
+    /// Make Bevy's view the first responder whenever a window becomes active.
+    ///
+    /// The first responder is the one receiving key input and motion (e.g. shake) events, so
+    /// without this, those will not be delivered unless some other code makes the view first
+    /// responder.
+    ///
+    /// Defaults to `true`.
+    pub automatic_first_responder: bool,
+    /// Emit a [`RawUIEvent`](crate::RawUIEvent) message for every event delivered to a window.
+    ///
+    /// The messages are buffered, and delivered with the next update, instead of causing one.
+    pub raw_events: bool,
+    /// Called with every event delivered to a window, before UIKit dispatches it further.
+    ///
+    /// This runs outside of Bevy's schedule, and must not block.
+    pub raw_event_filter: Option<RawEventFilter>,
+    /// Buffer input events, and deliver them at the start of the next update, instead of updating
+    /// the application once for every event.
+    ///
+    /// This reduces the number of redundant frames under heavy touch input, at the cost of some
+    /// latency.
+    pub batched_input: bool,
+    /// Run this schedule immediately when touches are received, instead of waiting for the next
+    /// full update to deliver them.
+    ///
+    /// Put latency-sensitive touch handling, such as drawing, in this schedule. Only has an effect
+    /// when [`batched_input`](Self::batched_input) is enabled, as the application is otherwise
+    /// fully updated for every touch anyway. The touches are still delivered to the rest of the
+    /// application in the next full update.
+    pub touch_schedule: Option<InternedScheduleLabel>,
+    /// Show a document browser as the root of each window, for document-based applications.
+    ///
+    /// Bevy's view is presented when the user picks a document, see
+    /// [`DocumentOpened`](crate::DocumentOpened).
+    pub document_browser: Option<DocumentBrowserSettings>,
+    /// Which of the screen's scale factors to render with.
+    ///
+    /// Defaults to [`ScaleMode::Logical`].
+    pub scale_mode: ScaleMode,
+    /// How touch and cursor positions are reported.
+    ///
+    /// Defaults to logical points relative to the top-left corner, like other Bevy backends.
+    pub touch_coordinates: TouchCoordinates,
+    /// How the plugin's gesture recognizers interact with each other and with touches.
+    pub gestures: GestureSettings,
+    /// Force a light or dark appearance for all windows, including system-presented view
+    /// controllers such as alerts and the document browser.
+    ///
+    /// Individual windows can still override this with [`Window::window_theme`].
+    ///
+    /// [`Window::window_theme`]: bevy_window::Window::window_theme
+    pub window_theme: Option<WindowTheme>,
+    /// Lower the [`SuggestedQuality`](crate::SuggestedQuality) when the device reports a serious
+    /// or critical [`ThermalState`](crate::ThermalState), and limit the
+    /// [`DisplayLink`](crate::DisplayLink)'s frame rate to it.
+    ///
+    /// Defaults to `None`, which always suggests full quality.
+    pub thermal_quality_scaling: Option<ThermalQualityScaling>,
+    /// What happens to the per-frame updates of the [`DisplayLink`](crate::DisplayLink) while the
+    /// application is inactive, such as while a system alert, Control Center or an incoming call
+    /// covers it.
+    ///
+    /// Defaults to [`InactiveUpdates::Continue`].
+    pub inactive_updates: InactiveUpdates,
+    /// How often to sample the [`ResourceUsage`](crate::ResourceUsage), or `None` to not sample
+    /// it.
+    ///
+    /// Defaults to once per second.
+    pub resource_usage_interval: Option<Duration>,
+    /// Keep windows in the [`WindowResizing`](crate::WindowResizing) state after UIKit has
+    /// finished resizing them, until the renderer sends
+    /// [`AcknowledgeWindowResize`](crate::AcknowledgeWindowResize).
+    ///
+    /// Defaults to `false`, which ends the resize in the first update at the final size.
+    pub acknowledge_window_resizes: bool,
+    /// Called to spawn the window when the system creates a new scene on its own, such as when the
+    /// user opens a new window with system buttons.
+    ///
+    /// Use this to spawn windows from a template, or with extra components. The primary window is
+    /// still spawned as usual.
+    ///
+    /// Defaults to `None`, which spawns a bare [`Window::default()`].
+    pub spawn_system_window: Option<SpawnSystemWindow>,
+    /// The window to spawn as the [`PrimaryWindow`] when the application is launched, if none was
+    /// spawned by the application itself.
+    ///
+    /// Defaults to [`Window::default()`].
+    pub primary_window: Window,
+    /// Called with the primary window when it's spawned from [`primary_window`], to insert
+    /// further components.
+    ///
+    /// [`primary_window`]: Self::primary_window
+    pub insert_primary_window: Option<InsertPrimaryWindow>,
+}
+
+impl Default for UIKitSettings {
+    fn default() -> Self {
+        Self {
+            automatic_first_responder: true,
+            raw_events: false,
+            raw_event_filter: None,
+            batched_input: false,
+            touch_schedule: None,
+            document_browser: None,
+            scale_mode: ScaleMode::Logical,
+            touch_coordinates: TouchCoordinates::default(),
+            gestures: GestureSettings::default(),
+            window_theme: None,
+            thermal_quality_scaling: None,
+            inactive_updates: InactiveUpdates::Continue,
+            resource_usage_interval: Some(Duration::from_secs(1)),
+            acknowledge_window_resizes: false,
+            spawn_system_window: None,
+            primary_window: Window::default(),
+            insert_primary_window: None,
+        }
+    }
+}
+
+impl UIKitSettings {
+    /// Spawn the primary window from the template in the settings.
+    pub(crate) fn spawn_primary_window(&self, world: &mut World) -> Entity {
+        let mut entity = world.spawn((self.primary_window.clone(), PrimaryWindow));
+        if let Some(insert) = self.insert_primary_window {
+            insert(&mut entity);
+        }
+        entity.id()
+    }
+}
@@ -0,0 +1,138 @@
+use std::time::SystemTime;
+
+use bevy_ecs::{
+    entity::Entity,
+    message::{Message, MessageReader, MessageWriter},
+    system::{NonSendMarker, Res},
+};
+use block2::RcBlock;
+use objc2::runtime::{AnyObject, Bool};
+use objc2::MainThreadOnly;
+use objc2_core_foundation::{CGPoint, CGRect, CGSize};
+use objc2_foundation::{NSArray, NSError, NSString, NSURL};
+use objc2_ui_kit::UIActivityViewController;
+use tracing::{trace, warn};
+
+use crate::app::send_message;
+use crate::screenshot::window_png;
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Capture the current contents of a window, and present the system share sheet with it.
+///
+/// The screenshot is shared as a PNG file, which is removed again once the share sheet is
+/// dismissed. The result is delivered as a [`ScreenshotShared`] message.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShareScreenshot {
+    /// The window to capture and present the share sheet in.
+    pub window: Entity,
+}
+
+/// The outcome of a [`ShareScreenshot`] request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShareResult {
+    /// The screenshot was shared.
+    Completed {
+        /// The activity that the user chose, such as `"com.apple.UIKit.activity.Message"`.
+        activity_type: Option<String>,
+    },
+    /// The user dismissed the share sheet without sharing.
+    Cancelled,
+    /// Sharing failed, with the given error description.
+    Failed(String),
+}
+
+/// The share sheet requested with [`ShareScreenshot`] was dismissed.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ScreenshotShared {
+    /// The window the share sheet was presented in.
+    pub window: Entity,
+    /// Whether the screenshot was shared.
+    pub result: ShareResult,
+}
+
+/// Capture and share screenshots of windows requested with [`ShareScreenshot`].
+pub fn share_screenshots(
+    mut requests: MessageReader<ShareScreenshot>,
+    mut shared: MessageWriter<ScreenshotShared>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for ShareScreenshot { window } in requests.read().copied() {
+        let Some(uikit_window) = uikit_windows.get(window, mtm) else {
+            warn!(
+                ?window,
+                "tried to share screenshot of window not known to UIKit"
+            );
+            continue;
+        };
+        let png = window_png(uikit_window.uiwindow.clone().into_super());
+
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let path = std::env::temp_dir().join(format!("Screenshot {timestamp}.png"));
+        if let Err(err) = std::fs::write(&path, png.to_vec()) {
+            warn!(?window, ?path, ?err, "failed writing screenshot");
+            shared.write(ScreenshotShared {
+                window,
+                result: ShareResult::Failed(err.to_string()),
+            });
+            continue;
+        }
+        trace!(?window, ?path, "presenting share sheet for screenshot");
+
+        let url = NSURL::fileURLWithPath(&NSString::from_str(&path.to_string_lossy()));
+        let item: &AnyObject = &url;
+        // SAFETY: File URLs are valid activity items.
+        let controller = unsafe {
+            UIActivityViewController::initWithActivityItems_applicationActivities(
+                UIActivityViewController::alloc(mtm),
+                &NSArray::from_slice(&[item]),
+                None,
+            )
+        };
+
+        let handler = RcBlock::new(
+            move |activity_type: *mut NSString,
+                  completed: Bool,
+                  _returned_items: *mut NSArray,
+                  error: *mut NSError| {
+                let _ = std::fs::remove_file(&path);
+                // SAFETY: The error is either NULL or a valid `NSError`.
+                let result = if let Some(error) = unsafe { error.as_ref() } {
+                    ShareResult::Failed(error.localizedDescription().to_string())
+                } else if completed.as_bool() {
+                    ShareResult::Completed {
+                        // SAFETY: The activity type is either NULL or a valid `NSString`.
+                        activity_type: unsafe { activity_type.as_ref() }.map(|s| s.to_string()),
+                    }
+                } else {
+                    ShareResult::Cancelled
+                };
+                trace!(?window, ?result, "share sheet dismissed");
+                send_message(mtm, ScreenshotShared { window, result });
+            },
+        );
+        // SAFETY: The handler has the correct signature.
+        unsafe { controller.setCompletionWithItemsHandler(RcBlock::as_ptr(&handler)) };
+
+        // The share sheet is a popover on iPad and Mac Catalyst, which must be anchored to
+        // something; use the middle of the window.
+        let view_controller = &uikit_window.view_controller;
+        if let Some(popover) = controller.popoverPresentationController() {
+            let view = view_controller.viewIfLoaded();
+            let bounds = uikit_window.uiwindow.bounds();
+            popover.setSourceView(view.as_deref());
+            popover.setSourceRect(CGRect {
+                origin: CGPoint {
+                    x: bounds.size.width / 2.0,
+                    y: bounds.size.height / 2.0,
+                },
+                size: CGSize::ZERO,
+            });
+        }
+        view_controller.presentViewController_animated_completion(&controller, true, None);
+    }
+}
@@ -0,0 +1,153 @@
+//! `os_signpost` instrumentation, for investigating frame hitches in Instruments.
+//!
+//! The intervals are emitted in the "Points of Interest" category, so they show up without any
+//! further configuration in Instruments.
+//!
+//! `os_signpost` is implemented with C macros, so we have to emit the signposts manually. The
+//! names must be placed in the `__TEXT,__oslogstring` section for Instruments to read them, which
+//! is why [`signpost_interval!`] is a macro.
+
+/// Begin a signpost interval with the given name, which ends when the returned guard is dropped.
+///
+/// Does nothing unless the `signpost` feature is enabled.
+macro_rules! signpost_interval {
+    ($name:literal) => {{
+        #[cfg(feature = "signpost")]
+        {
+            const NAME: &str = concat!($name, "\0");
+            #[link_section = "__TEXT,__oslogstring,cstring_literals"]
+            static NAME_BYTES: [u8; NAME.len()] = $crate::signpost::to_bytes(NAME);
+            $crate::signpost::Interval::begin(&NAME_BYTES)
+        }
+        #[cfg(not(feature = "signpost"))]
+        {
+            $crate::signpost::Interval
+        }
+    }};
+}
+
+pub(crate) use signpost_interval;
+
+#[cfg(feature = "signpost")]
+mod imp {
+    use std::ffi::{c_char, c_void};
+    use std::sync::OnceLock;
+
+    #[repr(C)]
+    struct OsLog {
+        _priv: [u8; 0],
+    }
+
+    const OS_SIGNPOST_INTERVAL_BEGIN: u8 = 1;
+    const OS_SIGNPOST_INTERVAL_END: u8 = 2;
+
+    extern "C" {
+        static __dso_handle: c_void;
+
+        fn os_log_create(subsystem: *const c_char, category: *const c_char) -> *mut OsLog;
+        fn os_signpost_enabled(log: *mut OsLog) -> bool;
+        fn os_signpost_id_generate(log: *mut OsLog) -> u64;
+        fn _os_signpost_emit_with_name_impl(
+            dso: *const c_void,
+            log: *mut OsLog,
+            r#type: u8,
+            spid: u64,
+            name: *const c_char,
+            format: *const c_char,
+            buf: *mut u8,
+            size: u32,
+        );
+    }
+
+    // The format string must also be in the `__oslogstring` section.
+    #[link_section = "__TEXT,__oslogstring,cstring_literals"]
+    static EMPTY_FORMAT: [u8; 1] = [0];
+
+    struct Log(*mut OsLog);
+
+    // SAFETY: `os_log_t` is thread-safe.
+    unsafe impl Send for Log {}
+    unsafe impl Sync for Log {}
+
+    fn log() -> *mut OsLog {
+        static LOG: OnceLock<Log> = OnceLock::new();
+        LOG.get_or_init(|| {
+            // SAFETY: The strings are NUL-terminated.
+            Log(unsafe {
+                os_log_create(
+                    c"org.bevyengine.bevy_uikit".as_ptr(),
+                    c"PointsOfInterest".as_ptr(),
+                )
+            })
+        })
+        .0
+    }
+
+    fn emit(r#type: u8, spid: u64, name: &'static [u8]) {
+        let log = log();
+        // SAFETY: The log is valid.
+        if !unsafe { os_signpost_enabled(log) } {
+            return;
+        }
+        // The encoded arguments of the (empty) format string: A summary byte and an argument
+        // count, both zero.
+        let mut buf = [0u8; 2];
+        // SAFETY: The name and format are NUL-terminated and placed in `__oslogstring`, and the
+        // buffer matches the format.
+        unsafe {
+            _os_signpost_emit_with_name_impl(
+                &__dso_handle,
+                log,
+                r#type,
+                spid,
+                name.as_ptr().cast(),
+                EMPTY_FORMAT.as_ptr().cast(),
+                buf.as_mut_ptr(),
+                buf.len() as u32,
+            )
+        }
+    }
+
+    /// A running signpost interval, see [`signpost_interval!`](super::signpost_interval).
+    #[derive(Debug)]
+    #[must_use = "the interval ends when dropped"]
+    pub(crate) struct Interval {
+        spid: u64,
+        name: &'static [u8],
+    }
+
+    impl Interval {
+        pub(crate) fn begin(name: &'static [u8]) -> Self {
+            // SAFETY: The log is valid.
+            let spid = unsafe { os_signpost_id_generate(log()) };
+            emit(OS_SIGNPOST_INTERVAL_BEGIN, spid, name);
+            Self { spid, name }
+        }
+    }
+
+    impl Drop for Interval {
+        fn drop(&mut self) {
+            emit(OS_SIGNPOST_INTERVAL_END, self.spid, self.name);
+        }
+    }
+
+    pub(crate) const fn to_bytes<const N: usize>(s: &str) -> [u8; N] {
+        let bytes = s.as_bytes();
+        let mut res = [0; N];
+        let mut i = 0;
+        while i < N {
+            res[i] = bytes[i];
+            i += 1;
+        }
+        res
+    }
+}
+
+#[cfg(feature = "signpost")]
+pub(crate) use imp::{to_bytes, Interval};
+
+/// A no-op signpost interval, used when the `signpost` feature is disabled.
+#[cfg(not(feature = "signpost"))]
+#[derive(Debug)]
+#[must_use = "the interval ends when dropped"]
+pub(crate) struct Interval;
@@ -0,0 +1,99 @@
+use bevy_color::{Color, Srgba};
+use bevy_ecs::{
+    component::Component,
+    message::MessageReader,
+    system::{NonSendMarker, Query, Res},
+};
+use bevy_window::{WindowBackground, WindowForeground};
+use objc2::{DefinedClass as _, MainThreadOnly};
+use objc2_core_foundation::CGFloat;
+use objc2_ui_kit::{UIColor, UIView, UIViewAutoresizing};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Cover the window with a placeholder while it is in the background, such that its content is
+/// not shown in the snapshot that the system takes for the app switcher.
+///
+/// Useful for applications showing sensitive content, such as banking or medical data. The live
+/// content is left untouched, and the placeholder is removed when the window enters the
+/// foreground again. Add this to the entity of the [`Window`](bevy_window::Window).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SnapshotPlaceholder {
+    /// The color of the placeholder.
+    pub color: Color,
+}
+
+impl Default for SnapshotPlaceholder {
+    fn default() -> Self {
+        Self {
+            color: Color::BLACK,
+        }
+    }
+}
+
+/// Show the [`SnapshotPlaceholder`] of windows entering the background, and remove it from
+/// windows entering the foreground.
+///
+/// The system takes the snapshot after the scene has entered the background, so this must run
+/// in the update triggered by [`WindowBackground`].
+pub fn update_snapshot_placeholders(
+    placeholders: Query<&SnapshotPlaceholder>,
+    mut background: MessageReader<WindowBackground>,
+    mut foreground: MessageReader<WindowForeground>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+
+    for WindowForeground { window } in foreground.read() {
+        let Some(view) = uikit_windows
+            .get(*window, mtm)
+            .and_then(|uikit_window| uikit_window.view())
+        else {
+            continue;
+        };
+        if let Some(placeholder) = view.ivars().snapshot_placeholder.take() {
+            trace!(?window, "removing snapshot placeholder");
+            placeholder.removeFromSuperview();
+        }
+    }
+
+    for WindowBackground { window } in background.read() {
+        let Ok(placeholder) = placeholders.get(*window) else {
+            continue;
+        };
+        let Some(uikit_window) = uikit_windows.get(*window, mtm) else {
+            continue;
+        };
+        let Some(view) = uikit_window.view() else {
+            continue;
+        };
+        let mut placeholder_view = view.ivars().snapshot_placeholder.borrow_mut();
+        if placeholder_view.is_some() {
+            continue;
+        }
+
+        trace!(?window, "showing snapshot placeholder");
+        let uiwindow = &uikit_window.uiwindow;
+        let cover = UIView::initWithFrame(UIView::alloc(mtm), uiwindow.bounds());
+        cover.setAutoresizingMask(
+            UIViewAutoresizing::FlexibleWidth | UIViewAutoresizing::FlexibleHeight,
+        );
+        let Srgba {
+            red,
+            green,
+            blue,
+            alpha,
+        } = placeholder.color.to_srgba();
+        cover.setBackgroundColor(Some(&UIColor::colorWithRed_green_blue_alpha(
+            red as CGFloat,
+            green as CGFloat,
+            blue as CGFloat,
+            alpha as CGFloat,
+        )));
+        // Above everything else in the window, including presented view controllers.
+        uiwindow.addSubview(&cover);
+        *placeholder_view = Some(cover);
+    }
+}
@@ -0,0 +1,184 @@
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::{
+    entity::Entity,
+    message::{Message, MessageReader},
+};
+use block2::RcBlock;
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::AllocAnyThread;
+use objc2_core_spotlight::{
+    CSSearchableIndex, CSSearchableItem, CSSearchableItemActionType,
+    CSSearchableItemActivityIdentifier, CSSearchableItemAttributeSet,
+};
+use objc2_foundation::{NSArray, NSError, NSString, NSUserActivity};
+use tracing::{trace, warn};
+
+/// Index application content with Spotlight, such that it shows up in system-wide search.
+///
+/// Items are added with [`IndexSpotlightItems`] and removed with [`DeleteSpotlightItems`]. When
+/// the user selects an item in search results, the application is opened and a
+/// [`SpotlightItemOpened`] message is emitted.
+#[derive(Debug, Default)]
+pub struct SpotlightPlugin;
+
+impl Plugin for SpotlightPlugin {
+    fn name(&self) -> &str {
+        "bevy_uikit::SpotlightPlugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_message::<IndexSpotlightItems>()
+            .add_message::<DeleteSpotlightItems>()
+            .add_systems(Last, update_spotlight_index);
+    }
+}
+
+/// An item of content, such as a level or a document, that can be found in Spotlight.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchableItem {
+    /// A unique identifier for the item, reported back in [`SpotlightItemOpened`].
+    pub identifier: String,
+    /// An optional group that the item belongs to, which allows deleting items in bulk.
+    pub domain: Option<String>,
+    /// The title shown in search results.
+    pub title: String,
+    /// A description shown below the title in search results.
+    pub content_description: Option<String>,
+    /// Additional words that the item should be found by.
+    pub keywords: Vec<String>,
+}
+
+/// Add or update items in the Spotlight index.
+///
+/// Items with the same [`SearchableItem::identifier`] as an already indexed item replace it.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct IndexSpotlightItems {
+    /// The items to index.
+    pub items: Vec<SearchableItem>,
+}
+
+/// Remove items from the Spotlight index.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub enum DeleteSpotlightItems {
+    /// Remove the items with the given identifiers.
+    Identifiers(Vec<String>),
+    /// Remove all items in the given domains.
+    Domains(Vec<String>),
+    /// Remove all items indexed by the application.
+    All,
+}
+
+/// The user selected an item indexed with [`IndexSpotlightItems`] in Spotlight.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct SpotlightItemOpened {
+    /// The window whose scene the item was opened in.
+    pub window: Entity,
+    /// The [`SearchableItem::identifier`] of the item.
+    pub identifier: String,
+}
+
+/// Extract the item identifier from a user activity, if it was continued from Spotlight.
+pub(crate) fn spotlight_item_identifier(activity: &NSUserActivity) -> Option<String> {
+    // SAFETY: The statics are valid `NSString`s.
+    let (action_type, identifier_key) = unsafe {
+        (
+            CSSearchableItemActionType,
+            CSSearchableItemActivityIdentifier,
+        )
+    };
+    if &*activity.activityType() != action_type {
+        return None;
+    }
+    let identifier_key: &AnyObject = identifier_key;
+    let identifier = activity.userInfo()?.objectForKey(identifier_key)?;
+    Some(identifier.downcast_ref::<NSString>()?.to_string())
+}
+
+/// Forward [`IndexSpotlightItems`] and [`DeleteSpotlightItems`] to the searchable index.
+pub fn update_spotlight_index(
+    mut index_requests: MessageReader<IndexSpotlightItems>,
+    mut delete_requests: MessageReader<DeleteSpotlightItems>,
+) {
+    if index_requests.is_empty() && delete_requests.is_empty() {
+        return;
+    }
+    if !CSSearchableIndex::isIndexingAvailable() {
+        warn!("Spotlight indexing is not available on this device");
+        index_requests.clear();
+        delete_requests.clear();
+        return;
+    }
+    let index = CSSearchableIndex::defaultSearchableIndex();
+
+    for IndexSpotlightItems { items } in index_requests.read() {
+        trace!(count = items.len(), "indexing Spotlight items");
+        let items: Vec<_> = items.iter().map(searchable_item).collect();
+        let items = NSArray::from_retained_slice(&items);
+        let completion = completion_handler("indexing");
+        // SAFETY: The completion handler may be called on any thread, which is fine since it
+        // only logs.
+        unsafe { index.indexSearchableItems_completionHandler(&items, Some(&completion)) };
+    }
+
+    for request in delete_requests.read() {
+        trace!(?request, "deleting Spotlight items");
+        let completion = completion_handler("deleting");
+        // SAFETY: Same as above.
+        unsafe {
+            match request {
+                DeleteSpotlightItems::Identifiers(identifiers) => index
+                    .deleteSearchableItemsWithIdentifiers_completionHandler(
+                        &strings_to_array(identifiers),
+                        Some(&completion),
+                    ),
+                DeleteSpotlightItems::Domains(domains) => index
+                    .deleteSearchableItemsWithDomainIdentifiers_completionHandler(
+                        &strings_to_array(domains),
+                        Some(&completion),
+                    ),
+                DeleteSpotlightItems::All => {
+                    index.deleteAllSearchableItemsWithCompletionHandler(Some(&completion))
+                }
+            }
+        }
+    }
+}
+
+fn searchable_item(item: &SearchableItem) -> Retained<CSSearchableItem> {
+    // `initWithContentType:` requires UniformTypeIdentifiers, which we don't otherwise need.
+    #[allow(deprecated, reason = "the replacement requires iOS 14.0")]
+    let attributes = CSSearchableItemAttributeSet::initWithItemContentType(
+        CSSearchableItemAttributeSet::alloc(),
+        &NSString::from_str("public.content"),
+    );
+    attributes.setTitle(Some(&NSString::from_str(&item.title)));
+    if let Some(description) = &item.content_description {
+        attributes.setContentDescription(Some(&NSString::from_str(description)));
+    }
+    if !item.keywords.is_empty() {
+        attributes.setKeywords(Some(&strings_to_array(&item.keywords)));
+    }
+
+    let domain = item.domain.as_deref().map(NSString::from_str);
+    CSSearchableItem::initWithUniqueIdentifier_domainIdentifier_attributeSet(
+        CSSearchableItem::alloc(),
+        Some(&NSString::from_str(&item.identifier)),
+        domain.as_deref(),
+        &attributes,
+    )
+}
+
+fn strings_to_array(strings: &[String]) -> Retained<NSArray<NSString>> {
+    let strings: Vec<_> = strings.iter().map(|s| NSString::from_str(s)).collect();
+    NSArray::from_retained_slice(&strings)
+}
+
+fn completion_handler(operation: &'static str) -> RcBlock<dyn Fn(*mut NSError)> {
+    RcBlock::new(move |error: *mut NSError| {
+        // SAFETY: The error is either NULL or a valid `NSError`.
+        if let Some(error) = unsafe { error.as_ref() } {
+            warn!(operation, ?error, "failed updating Spotlight index");
+        }
+    })
+}
@@ -0,0 +1,51 @@
+use bevy_ecs::{component::Component, entity::Entity, world::World};
+use objc2::{available, MainThreadOnly};
+use objc2_ui_kit::{UIDevice, UIUserInterfaceIdiom, UIWindowScene};
+use tracing::trace;
+
+/// Marks a window that is shown in a resizable window on iPadOS, instead of covering the whole
+/// screen.
+///
+/// This is the case with Stage Manager, but note that UIKit provides no way of distinguishing it
+/// from Split View and Slide Over, so this is inserted for those too.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StageManagerWindow {
+    /// Whether the user is currently resizing the window (requires iPadOS 17.0).
+    pub interactively_resizing: bool,
+}
+
+/// Insert, update or remove [`StageManagerWindow`] based on the scene's current geometry.
+pub(crate) fn update_stage_manager_window(
+    world: &mut World,
+    entity: Entity,
+    scene: &UIWindowScene,
+) {
+    // Stage Manager is only available on iPadOS 16.0 and above.
+    if !cfg!(target_os = "ios") || cfg!(target_abi = "macabi") || !available!(ios = 16.0, ..) {
+        return;
+    }
+    if UIDevice::currentDevice(scene.mtm()).userInterfaceIdiom() != UIUserInterfaceIdiom::Pad {
+        return;
+    }
+
+    // `UIScreen.bounds` follows the interface orientation, so this is comparable.
+    let is_windowed = scene.coordinateSpace().bounds().size != scene.screen().bounds().size;
+    let interactively_resizing =
+        available!(ios = 17.0, ..) && scene.effectiveGeometry().isInteractivelyResizing();
+
+    let Ok(mut entity) = world.get_entity_mut(entity) else {
+        return;
+    };
+    if is_windowed {
+        let stage_manager_window = StageManagerWindow {
+            interactively_resizing,
+        };
+        if entity.get::<StageManagerWindow>() != Some(&stage_manager_window) {
+            trace!(?stage_manager_window, "updating StageManagerWindow");
+            entity.insert(stage_manager_window);
+        }
+    } else if entity.contains::<StageManagerWindow>() {
+        trace!("removing StageManagerWindow");
+        entity.remove::<StageManagerWindow>();
+    }
+}
@@ -0,0 +1,63 @@
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    lifecycle::RemovedComponents,
+    system::{NonSendMarker, Query, Res},
+    world::Ref,
+};
+use bevy_window::Window;
+use objc2_ui_kit::UIStatusBarStyle;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// The style of the status bar while a window is in front.
+///
+/// Whether the status bar is shown is controlled with [`Window::prefers_status_bar_hidden`]. Both
+/// are per-window, so e.g. a fullscreen gameplay window can hide the status bar, while a tools
+/// window keeps it. Add this to the entity of the [`Window`].
+///
+/// Requires `UIViewControllerBasedStatusBarAppearance` to not be disabled in `Info.plist`.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StatusBarStyle {
+    /// Choose the style automatically based on the user interface style.
+    #[default]
+    Default,
+    /// Light content, for use on dark backgrounds.
+    LightContent,
+    /// Dark content, for use on light backgrounds.
+    DarkContent,
+}
+
+/// Propagate changes to [`Window::prefers_status_bar_hidden`] and [`StatusBarStyle`] to the
+/// window's view controller.
+pub fn update_status_bars(
+    windows: Query<(Entity, Ref<Window>, Option<Ref<StatusBarStyle>>)>,
+    mut removed: RemovedComponents<StatusBarStyle>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    let removed: Vec<Entity> = removed.read().collect();
+    for (entity, window, style) in &windows {
+        // Also update when the window was only just registered with UIKit.
+        if !window.is_changed()
+            && !style.as_ref().is_some_and(|style| style.is_changed())
+            && !removed.contains(&entity)
+            && !uikit_windows.is_changed()
+        {
+            continue;
+        }
+        let Some(uikit_window) = uikit_windows.get(entity, mtm) else {
+            continue;
+        };
+        let style = match style.as_deref().copied().unwrap_or_default() {
+            StatusBarStyle::Default => UIStatusBarStyle::Default,
+            StatusBarStyle::LightContent => UIStatusBarStyle::LightContent,
+            StatusBarStyle::DarkContent => UIStatusBarStyle::DarkContent,
+        };
+        uikit_window
+            .view_controller
+            .set_status_bar(window.prefers_status_bar_hidden, style);
+    }
+}
@@ -0,0 +1,47 @@
+use bevy_ecs::{
+    entity::Entity,
+    message::{Message, MessageReader},
+    system::{NonSendMarker, Res},
+};
+use objc2::available;
+use objc2_store_kit::SKStoreReviewController;
+use tracing::{trace, warn};
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// Ask the user to rate the application on the App Store, in the given window.
+///
+/// The system decides whether the prompt is actually shown, and limits it to a few times a year,
+/// so request it at natural moments (such as after completing a level) rather than in response to
+/// a button press. Nothing is shown in applications installed through TestFlight.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestReview {
+    /// The window to show the prompt in.
+    pub window: Entity,
+}
+
+/// Forward [`RequestReview`] messages to StoreKit.
+pub fn request_reviews(
+    mut requests: MessageReader<RequestReview>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for RequestReview { window } in requests.read() {
+        let Some(uikit_window) = uikit_windows.get(*window, mtm) else {
+            warn!(
+                ?window,
+                "tried to request review in window not registered with UIKit"
+            );
+            continue;
+        };
+        trace!(?window, "requesting review");
+        match &uikit_window.scene {
+            Some(scene) if available!(ios = 14.0, ..) => {
+                SKStoreReviewController::requestReviewInScene(scene);
+            }
+            #[allow(deprecated, reason = "the replacement requires scenes")]
+            _ => SKStoreReviewController::requestReview(),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    message::{Message, MessageReader, MessageWriter},
+    query::Has,
+    system::{Commands, Query},
+};
+use bevy_window::{WindowBackground, WindowForeground};
+use tracing::trace;
+
+/// The window moved to the background, and its render surface must no longer be presented to.
+///
+/// The system does not allow using the GPU in the background, so presenting fails until the window
+/// returns to the foreground. Renderers should drop the window's swapchain when receiving this,
+/// and recreate it on [`WindowSurfaceRecreated`], similar to how Bevy handles suspension on
+/// Android. While invalidated, the window has an [`InvalidatedSurface`] component.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowSurfaceInvalidated {
+    /// The window whose surface was invalidated.
+    pub window: Entity,
+}
+
+/// The window is about to return to the foreground, and its render surface can be recreated, see
+/// [`WindowSurfaceInvalidated`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowSurfaceRecreated {
+    /// The window whose surface can be recreated.
+    pub window: Entity,
+}
+
+/// Marks a window whose render surface is invalidated, see [`WindowSurfaceInvalidated`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct InvalidatedSurface;
+
+/// Marks a window that should not be rendered to, such as because it's hidden.
+///
+/// Inserted when the window moves to the background, and removed again when it returns to the
+/// foreground. It can also be inserted manually, such as for windows that are partially hidden
+/// behind others, in which case it is left alone when the window changes state.
+///
+/// While every window has this component, the [`DisplayLink`](crate::DisplayLink) is paused, so
+/// the application is no longer rendered and presented every frame. Bevy's renderer presents to all
+/// windows in each update, so while only some of the windows are paused, the application must
+/// skip rendering to them itself, such as by deactivating the cameras that target them.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RenderPaused {
+    /// Whether the component was inserted automatically because the window moved to the
+    /// background, and should thus be removed when it returns to the foreground.
+    pub automatic: bool,
+}
+
+/// Invalidate and recreate the render surfaces of windows as they move to the background and
+/// foreground, and pause rendering to them with [`RenderPaused`] while in the background.
+pub fn update_window_surfaces(
+    mut background: MessageReader<WindowBackground>,
+    mut foreground: MessageReader<WindowForeground>,
+    windows: Query<(Has<InvalidatedSurface>, Option<&RenderPaused>)>,
+    mut invalidated: MessageWriter<WindowSurfaceInvalidated>,
+    mut recreated: MessageWriter<WindowSurfaceRecreated>,
+    mut commands: Commands,
+) {
+    for WindowBackground { window } in background.read() {
+        let Ok((is_invalidated, paused)) = windows.get(*window) else {
+            continue;
+        };
+        if !is_invalidated {
+            trace!(?window, "invalidating window surface");
+            commands.entity(*window).insert(InvalidatedSurface);
+            invalidated.write(WindowSurfaceInvalidated { window: *window });
+        }
+        if paused.is_none() {
+            commands
+                .entity(*window)
+                .insert(RenderPaused { automatic: true });
+        }
+    }
+    for WindowForeground { window } in foreground.read() {
+        let Ok((is_invalidated, paused)) = windows.get(*window) else {
+            continue;
+        };
+        if is_invalidated {
+            trace!(?window, "recreating window surface");
+            commands.entity(*window).remove::<InvalidatedSurface>();
+            recreated.write(WindowSurfaceRecreated { window: *window });
+        }
+        if paused.is_some_and(|paused| paused.automatic) {
+            commands.entity(*window).remove::<RenderPaused>();
+        }
+    }
+}
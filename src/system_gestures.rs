@@ -0,0 +1,48 @@
+use bevy_ecs::{entity::Entity, message::Message};
+use bevy_math::Vec2;
+use bevy_window::ScreenEdge;
+use objc2_core_foundation::{CGPoint, CGSize};
+
+/// How close to an edge a touch must be for its cancellation to be attributed to that edge, in
+/// logical points.
+const EDGE_MARGIN: f64 = 30.0;
+
+/// A touch in a window was cancelled by the system, such as by the home indicator, Control Center
+/// or Notification Center taking over the gesture.
+///
+/// This is a diagnostic, sent along with the [`TouchInput`] with [`TouchPhase::Canceled`], for
+/// finding out which edges to defer system gestures on with
+/// [`Window::preferred_screen_edges_deferring_system_gestures`]. Cancellations caused by the
+/// plugin's own gesture recognizers, such as [`ScreenEdgeSwipes`](crate::ScreenEdgeSwipes), are
+/// not reported.
+///
+/// [`TouchInput`]: bevy_input::touch::TouchInput
+/// [`TouchPhase::Canceled`]: bevy_input::touch::TouchPhase::Canceled
+/// [`Window::preferred_screen_edges_deferring_system_gestures`]: bevy_window::Window::preferred_screen_edges_deferring_system_gestures
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct TouchCancelledBySystem {
+    /// The window that the touch was in.
+    pub window: Entity,
+    /// The [`TouchInput::id`](bevy_input::touch::TouchInput::id) of the touch.
+    pub id: u64,
+    /// The position of the touch when it was cancelled, in the same coordinates as touch input.
+    pub position: Vec2,
+    /// The edge of the window that the touch was closest to, or [`ScreenEdge::None`] if it was
+    /// not near any edge.
+    pub edge: ScreenEdge,
+}
+
+/// The edge of a view of the given size that the location is near, if any.
+pub(crate) fn edge_near(location: CGPoint, size: CGSize) -> ScreenEdge {
+    let distances = [
+        (location.y, ScreenEdge::Top),
+        (location.x, ScreenEdge::Left),
+        (size.height - location.y, ScreenEdge::Bottom),
+        (size.width - location.x, ScreenEdge::Right),
+    ];
+    distances
+        .into_iter()
+        .filter(|(distance, _)| *distance <= EDGE_MARGIN)
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map_or(ScreenEdge::None, |(_, edge)| edge)
+}
@@ -0,0 +1,182 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    message::{Message, MessageWriter},
+    system::{Commands, NonSendMarker, Query, Res},
+};
+use objc2_ui_kit::{
+    UIContentSizeCategory, UIContentSizeCategoryAccessibilityExtraExtraExtraLarge,
+    UIContentSizeCategoryAccessibilityExtraExtraLarge,
+    UIContentSizeCategoryAccessibilityExtraLarge, UIContentSizeCategoryAccessibilityLarge,
+    UIContentSizeCategoryAccessibilityMedium, UIContentSizeCategoryExtraExtraExtraLarge,
+    UIContentSizeCategoryExtraExtraLarge, UIContentSizeCategoryExtraLarge,
+    UIContentSizeCategoryExtraSmall, UIContentSizeCategoryLarge, UIContentSizeCategoryMedium,
+    UIContentSizeCategorySmall, UILegibilityWeight, UITraitEnvironment as _,
+};
+use tracing::trace;
+
+use crate::{main_thread_marker, UIKitWindows};
+
+/// The text size that the user prefers, as set in Settings, either system-wide or for this
+/// application only.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ContentSizeCategory {
+    /// The smallest size.
+    ExtraSmall,
+    /// A small size.
+    Small,
+    /// A medium size.
+    Medium,
+    /// The default size.
+    #[default]
+    Large,
+    /// A large size.
+    ExtraLarge,
+    /// A larger size.
+    ExtraExtraLarge,
+    /// The largest size without larger accessibility sizes enabled.
+    ExtraExtraExtraLarge,
+    /// A medium accessibility size.
+    AccessibilityMedium,
+    /// A large accessibility size.
+    AccessibilityLarge,
+    /// A larger accessibility size.
+    AccessibilityExtraLarge,
+    /// An even larger accessibility size.
+    AccessibilityExtraExtraLarge,
+    /// The largest accessibility size.
+    AccessibilityExtraExtraExtraLarge,
+}
+
+impl ContentSizeCategory {
+    fn from_uikit(category: &UIContentSizeCategory) -> Self {
+        // SAFETY: The categories are immutable constants.
+        let categories = unsafe {
+            [
+                (UIContentSizeCategoryExtraSmall, Self::ExtraSmall),
+                (UIContentSizeCategorySmall, Self::Small),
+                (UIContentSizeCategoryMedium, Self::Medium),
+                (UIContentSizeCategoryLarge, Self::Large),
+                (UIContentSizeCategoryExtraLarge, Self::ExtraLarge),
+                (UIContentSizeCategoryExtraExtraLarge, Self::ExtraExtraLarge),
+                (
+                    UIContentSizeCategoryExtraExtraExtraLarge,
+                    Self::ExtraExtraExtraLarge,
+                ),
+                (
+                    UIContentSizeCategoryAccessibilityMedium,
+                    Self::AccessibilityMedium,
+                ),
+                (
+                    UIContentSizeCategoryAccessibilityLarge,
+                    Self::AccessibilityLarge,
+                ),
+                (
+                    UIContentSizeCategoryAccessibilityExtraLarge,
+                    Self::AccessibilityExtraLarge,
+                ),
+                (
+                    UIContentSizeCategoryAccessibilityExtraExtraLarge,
+                    Self::AccessibilityExtraExtraLarge,
+                ),
+                (
+                    UIContentSizeCategoryAccessibilityExtraExtraExtraLarge,
+                    Self::AccessibilityExtraExtraExtraLarge,
+                ),
+            ]
+        };
+        categories
+            .into_iter()
+            .find(|(uikit, _)| **uikit == *category)
+            // `UIContentSizeCategoryUnspecified`, before the window has a trait collection.
+            .map_or(Self::Large, |(_, category)| category)
+    }
+
+    /// Whether this is one of the larger accessibility sizes.
+    pub fn is_accessibility_category(self) -> bool {
+        self >= Self::AccessibilityMedium
+    }
+
+    /// The scale of body text at this size, relative to the default size.
+    ///
+    /// Matches the point sizes of the system's body text style.
+    pub fn text_scale(self) -> f32 {
+        let body_size = match self {
+            Self::ExtraSmall => 14.0,
+            Self::Small => 15.0,
+            Self::Medium => 16.0,
+            Self::Large => 17.0,
+            Self::ExtraLarge => 19.0,
+            Self::ExtraExtraLarge => 21.0,
+            Self::ExtraExtraExtraLarge => 23.0,
+            Self::AccessibilityMedium => 28.0,
+            Self::AccessibilityLarge => 33.0,
+            Self::AccessibilityExtraLarge => 40.0,
+            Self::AccessibilityExtraExtraLarge => 47.0,
+            Self::AccessibilityExtraExtraExtraLarge => 53.0,
+        };
+        body_size / 17.0
+    }
+}
+
+/// The text settings of a window, which include per-application overrides made in Settings.
+///
+/// Inserted on the entity of the [`Window`](bevy_window::Window) once it is registered with UIKit,
+/// and kept up to date.
+///
+/// Note that Display Zoom is reflected in the window's size and [`ScreenScale`](crate::ScreenScale)
+/// instead.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TextSettings {
+    /// The preferred text size.
+    pub content_size_category: ContentSizeCategory,
+    /// Whether the user enabled Bold Text.
+    pub bold_text: bool,
+}
+
+/// The [`TextSettings`] of a window changed.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextSettingsChanged {
+    /// The window.
+    pub window: Entity,
+    /// The new settings.
+    pub settings: TextSettings,
+}
+
+/// Keep [`TextSettings`] in sync with the trait collections of the windows.
+///
+/// UIKit updates the application when the traits change, so this is checked on every update.
+pub fn update_text_settings(
+    mut text_settings: Query<&mut TextSettings>,
+    mut changed: MessageWriter<TextSettingsChanged>,
+    mut commands: Commands,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, uikit_window) in uikit_windows.iter(mtm) {
+        let traits = uikit_window.uiwindow.traitCollection();
+        // SAFETY: Accessed on the main thread.
+        let settings = unsafe {
+            TextSettings {
+                content_size_category: ContentSizeCategory::from_uikit(
+                    &traits.preferredContentSizeCategory(),
+                ),
+                bold_text: traits.legibilityWeight() == UILegibilityWeight::Bold,
+            }
+        };
+
+        match text_settings.get_mut(entity) {
+            Ok(current) if *current == settings => continue,
+            Ok(mut current) => *current = settings,
+            Err(_) => {
+                commands.entity(entity).try_insert(settings);
+            }
+        }
+        trace!(?entity, ?settings, "text settings changed");
+        changed.write(TextSettingsChanged {
+            window: entity,
+            settings,
+        });
+    }
+}
@@ -0,0 +1,161 @@
+use std::ptr::NonNull;
+
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    message::Message,
+    resource::Resource,
+    system::{Res, ResMut},
+};
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::MainThreadMarker;
+use objc2_foundation::{
+    NSNotification, NSNotificationCenter, NSObjectProtocol, NSOperationQueue, NSProcessInfo,
+    NSProcessInfoThermalState, NSProcessInfoThermalStateDidChangeNotification,
+};
+use tracing::trace;
+
+use crate::app::with_world;
+use crate::UIKitSettings;
+
+/// The thermal state of the device.
+///
+/// The system throttles the CPU and GPU as the state rises, so applications should reduce their
+/// workload (e.g. frame rate, resolution or effects) at [`Serious`](Self::Serious) and above.
+///
+/// Kept up to date while the application is running, see [`ThermalStateChanged`].
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ThermalState {
+    /// Within normal limits.
+    #[default]
+    Nominal,
+    /// Slightly elevated.
+    Fair,
+    /// High, and performance is impacted.
+    Serious,
+    /// Significantly impacting performance, and the device needs to cool down.
+    Critical,
+}
+
+impl ThermalState {
+    fn current() -> Self {
+        match NSProcessInfo::processInfo().thermalState() {
+            NSProcessInfoThermalState::Fair => Self::Fair,
+            NSProcessInfoThermalState::Serious => Self::Serious,
+            NSProcessInfoThermalState::Critical => Self::Critical,
+            _ => Self::Nominal,
+        }
+    }
+}
+
+/// The [`ThermalState`] changed.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThermalStateChanged {
+    /// The new state.
+    pub state: ThermalState,
+}
+
+/// How to reduce quality when the device heats up, see
+/// [`UIKitSettings::thermal_quality_scaling`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThermalQualityScaling {
+    /// The quality to suggest at [`ThermalState::Serious`].
+    pub serious: SuggestedQuality,
+    /// The quality to suggest at [`ThermalState::Critical`].
+    pub critical: SuggestedQuality,
+}
+
+impl Default for ThermalQualityScaling {
+    fn default() -> Self {
+        Self {
+            serious: SuggestedQuality {
+                render_scale: 0.75,
+                maximum_frames_per_second: Some(30),
+            },
+            critical: SuggestedQuality {
+                render_scale: 0.5,
+                maximum_frames_per_second: Some(20),
+            },
+        }
+    }
+}
+
+/// The render quality suggested for the current [`ThermalState`], as configured with
+/// [`UIKitSettings::thermal_quality_scaling`].
+///
+/// The frame rate is applied to the [`DisplayLink`](crate::DisplayLink) if there is one, while the
+/// render scale is only a suggestion; render code should apply it, e.g. by scaling the resolution
+/// of the main render target.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SuggestedQuality {
+    /// The factor to scale the render resolution by, between `0.0` and `1.0`.
+    pub render_scale: f32,
+    /// The frame rate to limit rendering to, or `None` to render at the screen's full rate.
+    pub maximum_frames_per_second: Option<u32>,
+}
+
+impl Default for SuggestedQuality {
+    fn default() -> Self {
+        Self {
+            render_scale: 1.0,
+            maximum_frames_per_second: None,
+        }
+    }
+}
+
+/// Keeps the thermal state notification observer alive.
+#[derive(Resource, Debug)]
+pub(crate) struct ThermalStateObserver(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<Retained<ProtocolObject<dyn NSObjectProtocol>>>,
+);
+
+/// Read the current [`ThermalState`], and observe changes to it.
+pub(crate) fn observe_thermal_state(mtm: MainThreadMarker) -> (ThermalState, ThermalStateObserver) {
+    let block = RcBlock::new(move |_notification: NonNull<NSNotification>| {
+        let state = ThermalState::current();
+        with_world(mtm, move |world| {
+            if *world.resource::<ThermalState>() != state {
+                trace!(?state, "thermal state changed");
+                world.insert_resource(state);
+                world.write_message(ThermalStateChanged { state });
+            }
+        });
+    });
+    // SAFETY: The block has the correct signature, and is run on the main queue (the notification
+    // itself may be posted on any thread).
+    let observer = unsafe {
+        NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+            Some(NSProcessInfoThermalStateDidChangeNotification),
+            None,
+            Some(&NSOperationQueue::mainQueue()),
+            &block,
+        )
+    };
+    (
+        ThermalState::current(),
+        ThermalStateObserver(MainThreadBound::new(observer, mtm)),
+    )
+}
+
+/// Update [`SuggestedQuality`] from the [`ThermalState`] and
+/// [`UIKitSettings::thermal_quality_scaling`].
+pub fn update_suggested_quality(
+    thermal_state: Res<ThermalState>,
+    settings: Res<UIKitSettings>,
+    mut suggested: ResMut<SuggestedQuality>,
+) {
+    if !thermal_state.is_changed() && !settings.is_changed() {
+        return;
+    }
+    let quality = match (*thermal_state, settings.thermal_quality_scaling) {
+        (ThermalState::Serious, Some(scaling)) => scaling.serious,
+        (ThermalState::Critical, Some(scaling)) => scaling.critical,
+        _ => SuggestedQuality::default(),
+    };
+    if suggested.set_if_neq(quality) {
+        trace!(thermal_state = ?*thermal_state, ?quality, "suggested quality changed");
+    }
+}
@@ -0,0 +1,131 @@
+#![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use bevy_input::gestures::{PinchGesture, RotationGesture};
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, ProtocolObject};
+use objc2::{
+    available, define_class, msg_send, sel, DefinedClass as _, MainThreadMarker, MainThreadOnly,
+};
+use objc2_foundation::{NSArray, NSNumber, NSObject, NSObjectProtocol};
+use objc2_ui_kit::{
+    UIGestureRecognizer, UIGestureRecognizerDelegate, UIGestureRecognizerState,
+    UIPinchGestureRecognizer, UIRotationGestureRecognizer, UITouchType, UIView,
+};
+use tracing::trace;
+
+use crate::app::send_input_message;
+use crate::{GestureSettings, PinchRotationConflict};
+
+define_class!(
+    /// The target and delegate of the trackpad gesture recognizers.
+    ///
+    /// Gesture recognizers do not retain their target or delegate.
+    #[unsafe(super(NSObject))]
+    #[name = "BevyTrackpadGestureHandler"]
+    #[thread_kind = MainThreadOnly]
+    #[ivars = GestureSettings]
+    #[derive(Debug)]
+    pub(crate) struct TrackpadGestureHandler;
+
+    unsafe impl NSObjectProtocol for TrackpadGestureHandler {}
+
+    unsafe impl UIGestureRecognizerDelegate for TrackpadGestureHandler {
+        // Allow pinching and rotating at the same time, unless configured otherwise.
+        #[unsafe(method(gestureRecognizer:shouldRecognizeSimultaneouslyWithGestureRecognizer:))]
+        fn gestureRecognizer_shouldRecognizeSimultaneouslyWithGestureRecognizer(
+            &self,
+            _recognizer: &UIGestureRecognizer,
+            _other: &UIGestureRecognizer,
+        ) -> bool {
+            self.ivars().pinch_and_rotation == PinchRotationConflict::Simultaneous
+        }
+    }
+
+    impl TrackpadGestureHandler {
+        #[unsafe(method(handlePinch:))]
+        fn handlePinch(&self, recognizer: &UIPinchGestureRecognizer) {
+            if !is_active(recognizer) {
+                return;
+            }
+            // Reset the scale, such that the next callback reports the delta.
+            let delta = recognizer.scale() - 1.0;
+            recognizer.setScale(1.0);
+            trace!(delta, "trackpad pinch");
+            send_input_message(self.mtm(), PinchGesture(delta as f32));
+        }
+
+        #[unsafe(method(handleRotation:))]
+        fn handleRotation(&self, recognizer: &UIRotationGestureRecognizer) {
+            if !is_active(recognizer) {
+                return;
+            }
+            // UIKit measures clockwise, Bevy counterclockwise.
+            let delta = -recognizer.rotation();
+            recognizer.setRotation(0.0);
+            trace!(delta, "trackpad rotation");
+            send_input_message(self.mtm(), RotationGesture(delta as f32));
+        }
+    }
+);
+
+fn is_active(recognizer: &UIGestureRecognizer) -> bool {
+    matches!(
+        recognizer.state(),
+        UIGestureRecognizerState::Changed | UIGestureRecognizerState::Ended
+    )
+}
+
+impl TrackpadGestureHandler {
+    /// Create the handler, if trackpad gestures are supported.
+    pub(crate) fn new(settings: GestureSettings, mtm: MainThreadMarker) -> Option<Retained<Self>> {
+        if !available!(ios = 13.4, visionos = 1.0, ..) {
+            return None;
+        }
+        let this = Self::alloc(mtm).set_ivars(settings);
+        Some(unsafe { msg_send![super(this), init] })
+    }
+
+    /// Add trackpad pinch and rotation gesture recognizers to the view.
+    ///
+    /// The recognizers only accept indirect pointer input, such that touches are unaffected.
+    pub(crate) fn add_to(&self, view: &UIView) {
+        let mtm = self.mtm();
+        let target: &AnyObject = self;
+        let delegate = ProtocolObject::from_ref(self);
+        let allowed_touch_types =
+            NSArray::from_retained_slice(&[NSNumber::new_isize(UITouchType::IndirectPointer.0)]);
+
+        // SAFETY: The target has a method with the given selector, which takes the recognizer.
+        let pinch = unsafe {
+            UIPinchGestureRecognizer::initWithTarget_action(
+                UIPinchGestureRecognizer::alloc(mtm),
+                Some(target),
+                Some(sel!(handlePinch:)),
+            )
+        };
+        pinch.setAllowedTouchTypes(&allowed_touch_types);
+        pinch.setDelegate(Some(delegate));
+        self.ivars().trackpad_touches.apply(&pinch);
+        view.addGestureRecognizer(&pinch);
+
+        // SAFETY: Same as above.
+        let rotation = unsafe {
+            UIRotationGestureRecognizer::initWithTarget_action(
+                UIRotationGestureRecognizer::alloc(mtm),
+                Some(target),
+                Some(sel!(handleRotation:)),
+            )
+        };
+        rotation.setAllowedTouchTypes(&allowed_touch_types);
+        rotation.setDelegate(Some(delegate));
+        self.ivars().trackpad_touches.apply(&rotation);
+        view.addGestureRecognizer(&rotation);
+
+        match self.ivars().pinch_and_rotation {
+            PinchRotationConflict::PreferPinch => rotation.requireGestureRecognizerToFail(&pinch),
+            PinchRotationConflict::PreferRotation => {
+                pinch.requireGestureRecognizerToFail(&rotation)
+            }
+            PinchRotationConflict::Simultaneous | PinchRotationConflict::Exclusive => {}
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use std::ptr::NonNull;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::{message::Message, resource::Resource};
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::MainThreadMarker;
+use objc2_foundation::{
+    NSInteger, NSNotification, NSNotificationCenter, NSObjectProtocol, NSOperationQueue, NSString,
+    NSUbiquitousKeyValueStore, NSUbiquitousKeyValueStoreAccountChange,
+    NSUbiquitousKeyValueStoreChangeReasonKey, NSUbiquitousKeyValueStoreChangedKeysKey,
+    NSUbiquitousKeyValueStoreDidChangeExternallyNotification,
+    NSUbiquitousKeyValueStoreInitialSyncChange, NSUbiquitousKeyValueStoreQuotaViolationChange,
+    NSUbiquitousKeyValueStoreServerChange,
+};
+use tracing::{trace, warn};
+
+use crate::app::send_message;
+use crate::PropertyListValue;
+
+/// Sync small amounts of data across the user's devices with the iCloud key-value store.
+///
+/// Values are accessed with the [`UbiquitousKeyValueStore`] resource, and changes made on other
+/// devices are forwarded as [`UbiquitousKeyValueStoreChanged`] messages.
+///
+/// Requires the `com.apple.developer.ubiquity-kvstore-identifier` entitlement. The store is
+/// limited to 1 MB and 1024 keys, so it's only suitable for lightweight data such as unlocks and
+/// settings.
+#[derive(Debug, Default)]
+pub struct UbiquitousKeyValueStorePlugin;
+
+impl Plugin for UbiquitousKeyValueStorePlugin {
+    fn name(&self) -> &str {
+        "bevy_uikit::UbiquitousKeyValueStorePlugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        let mtm = MainThreadMarker::new().expect("must build the App on the main thread");
+
+        let block = RcBlock::new(move |notification: NonNull<NSNotification>| {
+            // SAFETY: The notification is valid for the duration of the block.
+            let notification = unsafe { notification.as_ref() };
+            if let Some(message) = UbiquitousKeyValueStoreChanged::from_notification(notification) {
+                trace!(
+                    ?message,
+                    "NSUbiquitousKeyValueStoreDidChangeExternallyNotification"
+                );
+                send_message(mtm, message);
+            }
+        });
+        // SAFETY: The block has the correct signature, and is run on the main queue.
+        let observer = unsafe {
+            NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+                Some(NSUbiquitousKeyValueStoreDidChangeExternallyNotification),
+                None,
+                Some(&NSOperationQueue::mainQueue()),
+                &block,
+            )
+        };
+
+        // Fetch changes made while the application was not running.
+        if !NSUbiquitousKeyValueStore::defaultStore().synchronize() {
+            warn!("failed synchronizing iCloud key-value store, is the entitlement missing?");
+        }
+
+        app.insert_resource(UbiquitousKeyValueStore { _priv: () })
+            .insert_resource(UbiquitousKeyValueStoreObserver(MainThreadBound::new(
+                observer, mtm,
+            )))
+            .add_message::<UbiquitousKeyValueStoreChanged>();
+    }
+}
+
+/// Access to the iCloud key-value store, see [`UbiquitousKeyValueStorePlugin`].
+///
+/// Changes are written to disk immediately, and uploaded to iCloud at a time chosen by the
+/// system.
+#[derive(Resource, Debug)]
+pub struct UbiquitousKeyValueStore {
+    _priv: (),
+}
+
+impl UbiquitousKeyValueStore {
+    /// Get a value, or `None` if no value is stored for the key.
+    pub fn get(&self, key: &str) -> Option<PropertyListValue> {
+        NSUbiquitousKeyValueStore::defaultStore()
+            .objectForKey(&NSString::from_str(key))
+            .as_deref()
+            .and_then(PropertyListValue::from_object)
+    }
+
+    /// Store a value.
+    pub fn set(&self, key: &str, value: &PropertyListValue) {
+        let store = NSUbiquitousKeyValueStore::defaultStore();
+        // SAFETY: The object is a property list object.
+        unsafe { store.setObject_forKey(Some(&value.to_object()), &NSString::from_str(key)) };
+    }
+
+    /// Remove the value for a key.
+    pub fn remove(&self, key: &str) {
+        NSUbiquitousKeyValueStore::defaultStore().removeObjectForKey(&NSString::from_str(key));
+    }
+}
+
+/// Keeps the notification observer alive.
+#[derive(Resource, Debug)]
+struct UbiquitousKeyValueStoreObserver(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<Retained<ProtocolObject<dyn NSObjectProtocol>>>,
+);
+
+/// Why the [`UbiquitousKeyValueStore`] changed, see [`UbiquitousKeyValueStoreChanged`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UbiquitousKeyValueStoreChangeReason {
+    /// Values were changed on another device.
+    ServerChange,
+    /// The initial download from iCloud completed, e.g. on a newly set up device.
+    ///
+    /// Local values may have been overwritten, so applications may want to merge them.
+    InitialSyncChange,
+    /// The application exceeded its storage quota, and must remove values.
+    QuotaViolationChange,
+    /// The user changed their iCloud account, and all values were replaced.
+    AccountChange,
+}
+
+impl UbiquitousKeyValueStoreChangeReason {
+    fn from_foundation(reason: NSInteger) -> Option<Self> {
+        Some(match reason {
+            NSUbiquitousKeyValueStoreServerChange => Self::ServerChange,
+            NSUbiquitousKeyValueStoreInitialSyncChange => Self::InitialSyncChange,
+            NSUbiquitousKeyValueStoreQuotaViolationChange => Self::QuotaViolationChange,
+            NSUbiquitousKeyValueStoreAccountChange => Self::AccountChange,
+            _ => return None,
+        })
+    }
+}
+
+/// Values in the [`UbiquitousKeyValueStore`] were changed outside of the application.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct UbiquitousKeyValueStoreChanged {
+    /// Why the values changed.
+    pub reason: UbiquitousKeyValueStoreChangeReason,
+    /// The keys whose values changed.
+    pub keys: Vec<String>,
+}
+
+impl UbiquitousKeyValueStoreChanged {
+    fn from_notification(notification: &NSNotification) -> Option<Self> {
+        let user_info = notification.userInfo()?;
+        let Some(PropertyListValue::Dictionary(user_info)) =
+            PropertyListValue::from_object(&user_info)
+        else {
+            return None;
+        };
+
+        // SAFETY: The keys are valid strings.
+        let (reason_key, keys_key) = unsafe {
+            (
+                NSUbiquitousKeyValueStoreChangeReasonKey.to_string(),
+                NSUbiquitousKeyValueStoreChangedKeysKey.to_string(),
+            )
+        };
+
+        let reason = match user_info.get(&reason_key) {
+            Some(PropertyListValue::Integer(reason)) => *reason as NSInteger,
+            reason => {
+                warn!(?reason, "invalid iCloud key-value store change reason");
+                return None;
+            }
+        };
+        let Some(reason) = UbiquitousKeyValueStoreChangeReason::from_foundation(reason) else {
+            warn!(reason, "unknown iCloud key-value store change reason");
+            return None;
+        };
+
+        let keys = match user_info.get(&keys_key) {
+            Some(PropertyListValue::Array(keys)) => keys
+                .iter()
+                .filter_map(|key| match key {
+                    PropertyListValue::String(key) => Some(key.clone()),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Some(Self { reason, keys })
+    }
+}
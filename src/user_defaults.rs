@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::ptr::NonNull;
+
+use bevy_ecs::{
+    message::{Message, MessageReader, MessageWriter},
+    resource::Resource,
+    system::ResMut,
+};
+use block2::RcBlock;
+use dispatch2::MainThreadBound;
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+use objc2::{AllocAnyThread, MainThreadMarker};
+use objc2_foundation::{
+    NSNotification, NSNotificationCenter, NSObjectProtocol, NSOperationQueue, NSString,
+    NSUserDefaults, NSUserDefaultsDidChangeNotification,
+};
+use tracing::{trace, warn};
+
+use crate::app::with_world;
+use crate::PropertyListValue;
+
+/// Request that a value is read from user defaults.
+///
+/// The result is delivered as a [`UserDefaultRead`] message.
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ReadUserDefault {
+    /// The suite to read from, e.g. an app group identifier to share settings with extensions.
+    ///
+    /// `None` reads from the application's standard user defaults.
+    pub suite: Option<String>,
+    /// The key to read.
+    pub key: String,
+}
+
+/// A value from user defaults, as requested by [`ReadUserDefault`].
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct UserDefaultRead {
+    /// The suite that was read from.
+    pub suite: Option<String>,
+    /// The key that was read.
+    pub key: String,
+    /// The value, or `None` if no value is stored for the key.
+    pub value: Option<PropertyListValue>,
+}
+
+/// Request that a value is written to user defaults.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct WriteUserDefault {
+    /// The suite to write to, see [`ReadUserDefault::suite`].
+    pub suite: Option<String>,
+    /// The key to write.
+    pub key: String,
+    /// The value to store, or `None` to remove the value for the key.
+    pub value: Option<PropertyListValue>,
+}
+
+/// Request that a user defaults key is observed for changes.
+///
+/// Changes are delivered as [`UserDefaultChanged`] messages, including those made with
+/// [`WriteUserDefault`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct ObserveUserDefault {
+    /// The suite to observe, see [`ReadUserDefault::suite`].
+    pub suite: Option<String>,
+    /// The key to observe.
+    pub key: String,
+}
+
+/// A user defaults key observed with [`ObserveUserDefault`] changed.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct UserDefaultChanged {
+    /// The suite that changed.
+    pub suite: Option<String>,
+    /// The key that changed.
+    pub key: String,
+    /// The new value, or `None` if the value was removed.
+    pub value: Option<PropertyListValue>,
+}
+
+/// The keys requested with [`ObserveUserDefault`], along with their last known value.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct ObservedUserDefaults {
+    values: HashMap<(Option<String>, String), Option<PropertyListValue>>,
+}
+
+/// Keeps the user defaults notification observer alive.
+#[derive(Resource, Debug)]
+pub(crate) struct UserDefaultsObserver(
+    #[expect(dead_code, reason = "only kept for ownership")]
+    MainThreadBound<Retained<ProtocolObject<dyn NSObjectProtocol>>>,
+);
+
+impl UserDefaultsObserver {
+    /// Check the keys in [`ObservedUserDefaults`] whenever user defaults change, and forward
+    /// changes as [`UserDefaultChanged`].
+    pub(crate) fn new(mtm: MainThreadMarker) -> Self {
+        let block = RcBlock::new(move |_notification: NonNull<NSNotification>| {
+            with_world(mtm, |world| {
+                let mut observed = world.resource_mut::<ObservedUserDefaults>();
+                let mut changes = Vec::new();
+                for ((suite, key), last_value) in &mut observed.values {
+                    let value = read(suite.as_deref(), key);
+                    if *last_value != value {
+                        trace!(?suite, key, ?value, "user default changed");
+                        *last_value = value.clone();
+                        changes.push(UserDefaultChanged {
+                            suite: suite.clone(),
+                            key: key.clone(),
+                            value,
+                        });
+                    }
+                }
+                world.write_message_batch(changes);
+            });
+        });
+        // SAFETY: The block has the correct signature, and is run on the main queue.
+        let observer = unsafe {
+            NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+                Some(NSUserDefaultsDidChangeNotification),
+                None,
+                Some(&NSOperationQueue::mainQueue()),
+                &block,
+            )
+        };
+        Self(MainThreadBound::new(observer, mtm))
+    }
+}
+
+/// Get the user defaults for a suite, or the standard user defaults if `None`.
+fn user_defaults(suite: Option<&str>) -> Option<Retained<NSUserDefaults>> {
+    let Some(suite) = suite else {
+        return Some(NSUserDefaults::standardUserDefaults());
+    };
+    let defaults = NSUserDefaults::initWithSuiteName(
+        NSUserDefaults::alloc(),
+        Some(&NSString::from_str(suite)),
+    );
+    if defaults.is_none() {
+        warn!(suite, "invalid user defaults suite");
+    }
+    defaults
+}
+
+fn read(suite: Option<&str>, key: &str) -> Option<PropertyListValue> {
+    user_defaults(suite)?
+        .objectForKey(&NSString::from_str(key))
+        .as_deref()
+        .and_then(PropertyListValue::from_object)
+}
+
+/// Handle requests to read, write and observe user defaults.
+///
+/// Writes are applied before reads, so reading a key written in the same update returns the new
+/// value.
+pub fn handle_user_defaults(
+    mut writes: MessageReader<WriteUserDefault>,
+    mut reads: MessageReader<ReadUserDefault>,
+    mut observes: MessageReader<ObserveUserDefault>,
+    mut read_values: MessageWriter<UserDefaultRead>,
+    mut observed: ResMut<ObservedUserDefaults>,
+) {
+    for WriteUserDefault { suite, key, value } in writes.read() {
+        let Some(defaults) = user_defaults(suite.as_deref()) else {
+            continue;
+        };
+        trace!(?suite, key, ?value, "writing user default");
+        let key = NSString::from_str(key);
+        match value {
+            // SAFETY: The object is a property list object.
+            Some(value) => unsafe { defaults.setObject_forKey(Some(&value.to_object()), &key) },
+            None => defaults.removeObjectForKey(&key),
+        }
+    }
+
+    for ReadUserDefault { suite, key } in reads.read() {
+        read_values.write(UserDefaultRead {
+            suite: suite.clone(),
+            key: key.clone(),
+            value: read(suite.as_deref(), key),
+        });
+    }
+
+    for ObserveUserDefault { suite, key } in observes.read() {
+        observed
+            .values
+            .entry((suite.clone(), key.clone()))
+            .or_insert_with(|| read(suite.as_deref(), key));
+    }
+}
@@ -1,22 +1,75 @@
 #![expect(non_snake_case, reason = "UIKit does not use Rust naming conventions")]
+use std::cell::{Cell, RefCell};
+use std::ptr::NonNull;
+#[cfg(not(target_os = "tvos"))]
+use std::time::Instant;
+
 use bevy_ecs::entity::Entity;
-use bevy_window::WindowFocused;
-use objc2::{define_class, msg_send, rc::Retained, DefinedClass, MainThreadMarker, MainThreadOnly};
+use bevy_input::touch::{ForceTouch, TouchInput, TouchPhase};
+use bevy_math::{Rect, Vec2};
+#[cfg(not(target_os = "tvos"))]
+use bevy_window::ScreenEdge;
+use block2::RcBlock;
+#[cfg(not(target_os = "tvos"))]
+use objc2::runtime::Sel;
+use objc2::runtime::{AnyObject, ProtocolObject};
+use objc2::{
+    define_class, msg_send, rc::Retained, sel, DefinedClass, MainThreadMarker, MainThreadOnly,
+    Message as _,
+};
 use objc2_core_foundation::{CGPoint, CGRect, CGSize};
-use objc2_foundation::NSObjectProtocol;
+#[cfg(not(target_os = "tvos"))]
+use objc2_foundation::NSArray;
+use objc2_foundation::{NSObjectProtocol, NSRunLoop, NSRunLoopCommonModes, NSSet};
+use objc2_quartz_core::CADisplayLink;
+#[cfg(not(target_os = "tvos"))]
+use objc2_ui_kit::{
+    UIEditMenuInteraction, UIGestureRecognizerState, UIKeyCommand, UIStatusBarStyle,
+};
 use objc2_ui_kit::{
-    UIFocusAnimationCoordinator, UIFocusEnvironment, UIFocusUpdateContext, UIResponder, UIView,
-    UIViewController,
+    UIEvent, UIFocusAnimationCoordinator, UIFocusEnvironment, UIFocusUpdateContext, UIPress,
+    UIPressType, UIPressesEvent, UIRectEdge, UIResponder, UITouch, UITouchType, UIView,
+    UIViewController, UIViewControllerTransitionCoordinator,
+    UIViewControllerTransitionCoordinatorContext, UIWindow,
 };
-use tracing::trace;
+use tracing::{trace, warn};
 
-use crate::app::send_window_message;
+#[cfg(not(target_os = "tvos"))]
+use crate::app::send_message;
+use crate::app::{run_touch_schedule, send_input, send_input_message, with_world};
+#[cfg(not(target_os = "tvos"))]
+use crate::autofill::AutofillDelegate;
+#[cfg(not(target_os = "tvos"))]
+use crate::edge_swipe::EdgeSwipeRecognizers;
+#[cfg(not(target_os = "tvos"))]
+use crate::edit_menu::{EditAction, EditMenuActionPerformed};
+#[cfg(not(target_os = "tvos"))]
+use crate::find::Find;
+#[cfg(not(target_os = "tvos"))]
+use crate::ime::ImeTextField;
+use crate::input::{instant_from_timestamp, TouchTimestamp};
+#[cfg(not(target_os = "tvos"))]
+use crate::key_shortcuts::{find_shortcut, KeyShortcutPressed};
+#[cfg(not(target_os = "tvos"))]
+use crate::keyboard_navigation::{FocusDirection, FocusNavigationRequested};
+use crate::pause_button::{PauseButton, PauseRequested};
+#[cfg(not(target_os = "tvos"))]
+use crate::pointer::PointerDelegate;
+use crate::resize::{begin_window_resize, finish_window_resize};
+#[cfg(not(target_os = "tvos"))]
+use crate::scribble::ScribbleDelegate;
+#[cfg(not(target_os = "tvos"))]
+use crate::system_gestures::{edge_near, TouchCancelledBySystem};
+#[cfg(not(target_os = "tvos"))]
+use crate::trackpad::TrackpadGestureHandler;
+use crate::windows::handle_resize;
+use crate::{GestureSettings, TouchCoordinates};
 
 define_class!(
     #[unsafe(super(UIViewController))]
     #[name = "BevyViewController"]
     #[derive(Debug, PartialEq, Eq, Hash)]
-    #[ivars = Entity]
+    #[ivars = ViewControllerIvars]
     pub(crate) struct ViewController;
 
     unsafe impl NSObjectProtocol for ViewController {}
@@ -25,11 +78,143 @@ define_class!(
     impl ViewController {
         #[unsafe(method(loadView))]
         fn loadView(&self) {
-            let view = View::new(self.mtm(), *self.ivars(), self.preferredContentSize());
+            let ivars = self.ivars();
+            let view = View::new(
+                self.mtm(),
+                ivars.entity,
+                ivars.touch_coordinates,
+                ivars.gestures,
+                self.preferredContentSize(),
+            );
             self.setView(Some(&view));
 
             // Docs say to _not_ call super
         }
+
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(prefersStatusBarHidden))]
+        fn prefersStatusBarHidden(&self) -> bool {
+            self.ivars().status_bar_hidden.get()
+        }
+
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(preferredStatusBarStyle))]
+        fn preferredStatusBarStyle(&self) -> UIStatusBarStyle {
+            self.ivars().status_bar_style.get()
+        }
+
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(preferredScreenEdgesDeferringSystemGestures))]
+        fn preferredScreenEdgesDeferringSystemGestures(&self) -> UIRectEdge {
+            self.ivars().deferred_screen_edges.get()
+        }
+
+        #[unsafe(method(viewWillTransitionToSize:withTransitionCoordinator:))]
+        fn viewWillTransitionToSize_withTransitionCoordinator(
+            &self,
+            size: CGSize,
+            coordinator: &ProtocolObject<dyn UIViewControllerTransitionCoordinator>,
+        ) {
+            trace!(?size, "viewWillTransitionToSize:withTransitionCoordinator:");
+            unsafe {
+                msg_send![super(self), viewWillTransitionToSize: size, withTransitionCoordinator: coordinator]
+            }
+
+            // The view's bounds are set to the final size at the start of the animation, and the
+            // layer then animates towards it. Sample the animated size every frame, such that the
+            // rendered content tracks the rotation instead of snapping to the new size.
+            let this = self.retain();
+            let animation = RcBlock::new(
+                move |_context: NonNull<
+                    ProtocolObject<dyn UIViewControllerTransitionCoordinatorContext>,
+                >| {
+                    this.send_resize(Some(size), TransitionPhase::Began);
+                    this.start_transition_display_link();
+                },
+            );
+
+            // The transition may have been cancelled (e.g. when interactive), so re-synchronize
+            // with the actual size of the view afterwards.
+            let this = self.retain();
+            let completion = RcBlock::new(
+                move |_context: NonNull<
+                    ProtocolObject<dyn UIViewControllerTransitionCoordinatorContext>,
+                >| {
+                    this.stop_transition_display_link();
+                    let size = this.viewIfLoaded().map(|view| view.bounds().size);
+                    this.send_resize(size, TransitionPhase::Finished);
+                },
+            );
+
+            coordinator.animateAlongsideTransition_completion(Some(&animation), Some(&completion));
+        }
+    }
+
+    impl ViewController {
+        /// Called every frame during a size transition, see
+        /// [`ViewController::start_transition_display_link`].
+        #[unsafe(method(transitionStep:))]
+        fn transitionStep(&self, _display_link: &CADisplayLink) {
+            let size = self.viewIfLoaded().map(|view| {
+                let layer = view.layer();
+                // The presentation layer contains the values currently shown on screen.
+                unsafe { layer.presentationLayer() }
+                    .unwrap_or(layer)
+                    .bounds()
+                    .size
+            });
+            self.send_resize(size, TransitionPhase::Changed);
+        }
+    }
+
+    /// Overridden UIResponder methods.
+    ///
+    /// Presses go to the focused view, or the view controller if nothing is focused, so these
+    /// are handled here instead of in the view.
+    impl ViewController {
+        #[unsafe(method(pressesBegan:withEvent:))]
+        fn pressesBegan_withEvent(&self, presses: &NSSet<UIPress>, event: Option<&UIPressesEvent>) {
+            let forwarded = self.route_pause_presses(presses, true);
+            let presses = forwarded.as_deref().unwrap_or(presses);
+            if !presses.is_empty() {
+                unsafe { msg_send![super(self), pressesBegan: presses, withEvent: event] }
+            }
+        }
+
+        #[unsafe(method(pressesChanged:withEvent:))]
+        fn pressesChanged_withEvent(
+            &self,
+            presses: &NSSet<UIPress>,
+            event: Option<&UIPressesEvent>,
+        ) {
+            let forwarded = self.route_pause_presses(presses, false);
+            let presses = forwarded.as_deref().unwrap_or(presses);
+            if !presses.is_empty() {
+                unsafe { msg_send![super(self), pressesChanged: presses, withEvent: event] }
+            }
+        }
+
+        #[unsafe(method(pressesEnded:withEvent:))]
+        fn pressesEnded_withEvent(&self, presses: &NSSet<UIPress>, event: Option<&UIPressesEvent>) {
+            let forwarded = self.route_pause_presses(presses, false);
+            let presses = forwarded.as_deref().unwrap_or(presses);
+            if !presses.is_empty() {
+                unsafe { msg_send![super(self), pressesEnded: presses, withEvent: event] }
+            }
+        }
+
+        #[unsafe(method(pressesCancelled:withEvent:))]
+        fn pressesCancelled_withEvent(
+            &self,
+            presses: &NSSet<UIPress>,
+            event: Option<&UIPressesEvent>,
+        ) {
+            let forwarded = self.route_pause_presses(presses, false);
+            let presses = forwarded.as_deref().unwrap_or(presses);
+            if !presses.is_empty() {
+                unsafe { msg_send![super(self), pressesCancelled: presses, withEvent: event] }
+            }
+        }
     }
 
     unsafe impl UIFocusEnvironment for ViewController {
@@ -51,20 +236,246 @@ define_class!(
     }
 );
 
+#[derive(Debug)]
+pub(crate) struct ViewControllerIvars {
+    entity: Entity,
+    touch_coordinates: TouchCoordinates,
+    gestures: GestureSettings,
+    deferred_screen_edges: Cell<UIRectEdge>,
+    #[cfg(not(target_os = "tvos"))]
+    status_bar_hidden: Cell<bool>,
+    #[cfg(not(target_os = "tvos"))]
+    status_bar_style: Cell<UIStatusBarStyle>,
+    /// Set while the window has a [`PauseButton`].
+    pub(crate) pause_button: Cell<Option<PauseButton>>,
+    /// Set while the view is animating to a new size.
+    transition_display_link: RefCell<Option<Retained<CADisplayLink>>>,
+}
+
 impl ViewController {
-    pub(crate) fn new(mtm: MainThreadMarker, window: Entity) -> Retained<Self> {
-        let this = Self::alloc(mtm).set_ivars(window);
+    pub(crate) fn new(
+        mtm: MainThreadMarker,
+        window: Entity,
+        touch_coordinates: TouchCoordinates,
+        gestures: GestureSettings,
+    ) -> Retained<Self> {
+        let this = Self::alloc(mtm).set_ivars(ViewControllerIvars {
+            entity: window,
+            touch_coordinates,
+            gestures,
+            deferred_screen_edges: Cell::new(UIRectEdge::None),
+            #[cfg(not(target_os = "tvos"))]
+            status_bar_hidden: Cell::new(false),
+            #[cfg(not(target_os = "tvos"))]
+            status_bar_style: Cell::new(UIStatusBarStyle::Default),
+            pause_button: Cell::new(None),
+            transition_display_link: RefCell::new(None),
+        });
         unsafe { msg_send![super(this), init] }
     }
+
+    /// Set the screen edges where system gestures (such as the home indicator or Control Center)
+    /// require a second swipe, such that the first swipe is delivered to the application.
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) fn set_deferred_screen_edges(&self, edges: ScreenEdge) {
+        let edges = match edges {
+            ScreenEdge::None => UIRectEdge::None,
+            ScreenEdge::Top => UIRectEdge::Top,
+            ScreenEdge::Left => UIRectEdge::Left,
+            ScreenEdge::Bottom => UIRectEdge::Bottom,
+            ScreenEdge::Right => UIRectEdge::Right,
+            ScreenEdge::All => UIRectEdge::All,
+        };
+        if self.ivars().deferred_screen_edges.replace(edges) != edges {
+            trace!(
+                ?edges,
+                "setting preferredScreenEdgesDeferringSystemGestures"
+            );
+            self.setNeedsUpdateOfScreenEdgesDeferringSystemGestures();
+        }
+    }
+
+    /// Set whether the status bar is hidden, and its style, while this view controller is shown.
+    ///
+    /// This only applies when the window is in front, so each window controls its own status bar.
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) fn set_status_bar(&self, hidden: bool, style: UIStatusBarStyle) {
+        let ivars = self.ivars();
+        let hidden_changed = ivars.status_bar_hidden.replace(hidden) != hidden;
+        let style_changed = ivars.status_bar_style.replace(style) != style;
+        if hidden_changed || style_changed {
+            trace!(hidden, ?style, "updating status bar appearance");
+            self.setNeedsStatusBarAppearanceUpdate();
+        }
+    }
+
+    /// Send [`PauseRequested`] when the Menu button begins being pressed, if the window has a
+    /// [`PauseButton`].
+    ///
+    /// Returns the presses to forward to the system instead, if they differ from the given ones.
+    /// UIKit requires that intercepted presses are withheld in every phase, not just when they
+    /// begin.
+    fn route_pause_presses(
+        &self,
+        presses: &NSSet<UIPress>,
+        began: bool,
+    ) -> Option<Retained<NSSet<UIPress>>> {
+        let pause_button = self.ivars().pause_button.get()?;
+        let is_menu = |press: &UIPress| press.r#type() == UIPressType::Menu;
+        let menu = presses.iter().find(|press| is_menu(press))?;
+        if began {
+            trace!(?pause_button, "pause button pressed");
+            send_input_message(
+                self.mtm(),
+                PauseRequested {
+                    window: self.ivars().entity,
+                    instant: instant_from_timestamp(menu.timestamp()),
+                },
+            );
+        }
+        match pause_button {
+            PauseButton::Intercept => {
+                let remaining: Vec<_> = presses.iter().filter(|press| !is_menu(press)).collect();
+                Some(NSSet::from_retained_slice(&remaining))
+            }
+            PauseButton::PassThrough => None,
+        }
+    }
+
+    /// Start resizing to the size of the view's presentation layer every frame.
+    ///
+    /// The display link retains the view controller, so this must be balanced with
+    /// [`ViewController::stop_transition_display_link`].
+    fn start_transition_display_link(&self) {
+        let mut transition_display_link = self.ivars().transition_display_link.borrow_mut();
+        if transition_display_link.is_some() {
+            return;
+        }
+        let target: &AnyObject = self;
+        // SAFETY: The target has a method with the given selector, which takes the display link.
+        let display_link =
+            unsafe { CADisplayLink::displayLinkWithTarget_selector(target, sel!(transitionStep:)) };
+        // SAFETY: Added to the main run loop, which is the one we're on.
+        unsafe {
+            display_link.addToRunLoop_forMode(&NSRunLoop::mainRunLoop(), NSRunLoopCommonModes)
+        };
+        *transition_display_link = Some(display_link);
+    }
+
+    fn stop_transition_display_link(&self) {
+        if let Some(display_link) = self.ivars().transition_display_link.take() {
+            display_link.invalidate();
+        }
+    }
+
+    fn send_resize(&self, size: Option<CGSize>, phase: TransitionPhase) {
+        let entity = self.ivars().entity;
+        let mtm = self.mtm();
+        // If not yet shown, the size will be synchronized when it is.
+        let screen = self
+            .viewIfLoaded()
+            .and_then(|view| view.window())
+            .map(|window| window.screen());
+        with_world(mtm, move |world| match phase {
+            TransitionPhase::Began => {
+                begin_window_resize(world, mtm, entity, false);
+                if let (Some(size), Some(screen)) = (size, screen) {
+                    handle_resize(world, entity, size, &screen);
+                }
+            }
+            TransitionPhase::Changed => {
+                if let (Some(size), Some(screen)) = (size, screen) {
+                    handle_resize(world, entity, size, &screen);
+                }
+            }
+            TransitionPhase::Finished => {
+                if let (Some(size), Some(screen)) = (size, screen) {
+                    handle_resize(world, entity, size, &screen);
+                }
+                finish_window_resize(world, entity, false);
+            }
+        });
+    }
+}
+
+/// The phase of a view controller transition that a resize happens in.
+#[derive(Debug, Clone, Copy)]
+enum TransitionPhase {
+    Began,
+    Changed,
+    Finished,
+}
+
+#[derive(Debug)]
+pub(crate) struct ViewIvars {
+    entity: Entity,
+    touch_coordinates: TouchCoordinates,
+    /// Regions (in points) where touches are passed through to the views behind this one.
+    pub(crate) passthrough_regions: RefCell<Vec<Rect>>,
+    /// Created once the window has [`PointerRegions`](crate::PointerRegions).
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) pointer_delegate: RefCell<Option<Retained<PointerDelegate>>>,
+    /// Created once the window has [`FindResults`](crate::FindResults).
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) find: RefCell<Option<Find>>,
+    /// Created once the window has [`ScribbleFields`](crate::ScribbleFields).
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) scribble_delegate: RefCell<Option<Retained<ScribbleDelegate>>>,
+    /// Created once the window has [`ScreenEdgeSwipes`](crate::ScreenEdgeSwipes).
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) edge_swipes: RefCell<Option<EdgeSwipeRecognizers>>,
+    /// Transparent subviews for each of the window's [`HoverEffects`](crate::HoverEffects).
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) hover_views: RefCell<Vec<Retained<UIView>>>,
+    /// The actions enabled by the last [`PresentEditMenu`](crate::PresentEditMenu).
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) edit_actions: RefCell<Vec<EditAction>>,
+    /// Created once an edit menu is presented.
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) edit_menu_interaction: RefCell<Option<Retained<UIEditMenuInteraction>>>,
+    /// Created once the window has [`AutofillFields`](crate::AutofillFields).
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) autofill_delegate: RefCell<Option<Retained<AutofillDelegate>>>,
+    /// Created once the window has [`Window::ime_enabled`](bevy_window::Window::ime_enabled) set.
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) ime_field: RefCell<Option<Retained<ImeTextField>>>,
+    /// Set while the window has [`KeyboardNavigation`](crate::KeyboardNavigation).
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) navigation_commands: RefCell<Option<Retained<NSArray<UIKeyCommand>>>>,
+    /// The commands of each [`KeyShortcutsPlugin`](crate::KeyShortcutsPlugin), by the type name
+    /// of its action.
+    #[cfg(not(target_os = "tvos"))]
+    pub(crate) shortcut_commands: RefCell<Vec<(&'static str, Retained<NSArray<UIKeyCommand>>)>>,
+    /// Shown while in the background, if the window has a
+    /// [`SnapshotPlaceholder`](crate::SnapshotPlaceholder).
+    pub(crate) snapshot_placeholder: RefCell<Option<Retained<UIView>>>,
+    #[cfg(not(target_os = "tvos"))]
+    _trackpad_gestures: Option<Retained<TrackpadGestureHandler>>,
 }
 
 define_class!(
     #[unsafe(super(UIView, UIResponder))] // TODO: MTKView?
     #[name = "BevyView"]
     #[derive(Debug, PartialEq, Eq, Hash)]
-    #[ivars = Entity]
+    #[ivars = ViewIvars]
     pub(crate) struct View;
 
+    /// Overridden UIView methods.
+    impl View {
+        #[unsafe(method(pointInside:withEvent:))]
+        fn pointInside_withEvent(&self, point: CGPoint, event: Option<&UIEvent>) -> bool {
+            let position = Vec2::new(point.x as f32, point.y as f32);
+            let passthrough_regions = self.ivars().passthrough_regions.borrow();
+            if passthrough_regions
+                .iter()
+                .any(|region| region.contains(position))
+            {
+                return false;
+            }
+            unsafe { msg_send![super(self), pointInside: point, withEvent: event] }
+        }
+    }
+
     /// Overridden UIResponder methods.
     impl View {
         #[unsafe(method(canBecomeFirstResponder))]
@@ -72,45 +483,297 @@ define_class!(
             true
         }
 
-        #[unsafe(method(becomeFirstResponder))]
-        fn becomeFirstResponder(&self) -> bool {
-            let success = unsafe { msg_send![super(self), becomeFirstResponder] };
-            if success {
-                send_window_message(
-                    self.mtm(),
-                    WindowFocused {
-                        window: *self.ivars(),
-                        focused: true,
-                    },
-                );
+        #[unsafe(method(touchesBegan:withEvent:))]
+        fn touchesBegan_withEvent(&self, touches: &NSSet<UITouch>, _event: Option<&UIEvent>) {
+            self.send_touches(touches, TouchPhase::Started);
+        }
+
+        #[unsafe(method(touchesMoved:withEvent:))]
+        fn touchesMoved_withEvent(&self, touches: &NSSet<UITouch>, _event: Option<&UIEvent>) {
+            self.send_touches(touches, TouchPhase::Moved);
+        }
+
+        #[unsafe(method(touchesEnded:withEvent:))]
+        fn touchesEnded_withEvent(&self, touches: &NSSet<UITouch>, _event: Option<&UIEvent>) {
+            self.send_touches(touches, TouchPhase::Ended);
+        }
+
+        #[unsafe(method(touchesCancelled:withEvent:))]
+        fn touchesCancelled_withEvent(&self, touches: &NSSet<UITouch>, _event: Option<&UIEvent>) {
+            #[cfg(not(target_os = "tvos"))]
+            self.report_system_cancellations(touches);
+            self.send_touches(touches, TouchPhase::Canceled);
+        }
+
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(canPerformAction:withSender:))]
+        fn canPerformAction_withSender(&self, action: Sel, sender: Option<&AnyObject>) -> bool {
+            if let Some(action) = EditAction::from_selector(action) {
+                return self.ivars().edit_actions.borrow().contains(&action);
             }
-            success
-        }
-
-        #[unsafe(method(resignFirstResponder))]
-        fn resignFirstResponder(&self) -> bool {
-            let success = unsafe { msg_send![super(self), resignFirstResponder] };
-            if success {
-                send_window_message(
-                    self.mtm(),
-                    WindowFocused {
-                        window: *self.ivars(),
-                        focused: false,
-                    },
-                );
+            unsafe { msg_send![super(self), canPerformAction: action, withSender: sender] }
+        }
+
+        // Standard edit actions, see `PresentEditMenu`.
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(cut:))]
+        fn cut(&self, _sender: Option<&AnyObject>) {
+            self.send_edit_action(EditAction::Cut);
+        }
+
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(copy:))]
+        fn copy(&self, _sender: Option<&AnyObject>) {
+            self.send_edit_action(EditAction::Copy);
+        }
+
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(paste:))]
+        fn paste(&self, _sender: Option<&AnyObject>) {
+            self.send_edit_action(EditAction::Paste);
+        }
+
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(selectAll:))]
+        fn selectAll(&self, _sender: Option<&AnyObject>) {
+            self.send_edit_action(EditAction::SelectAll);
+        }
+
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method_id(keyCommands))]
+        fn keyCommands(&self) -> Option<Retained<NSArray<UIKeyCommand>>> {
+            let navigation = self.ivars().navigation_commands.borrow();
+            let shortcuts = self.ivars().shortcut_commands.borrow();
+            if navigation.is_none() && shortcuts.is_empty() {
+                return unsafe { msg_send![super(self), keyCommands] };
             }
-            success
+            let commands: Vec<_> = navigation
+                .iter()
+                .chain(shortcuts.iter().map(|(_, commands)| commands))
+                .flat_map(|commands| commands.to_vec())
+                .collect();
+            Some(NSArray::from_retained_slice(&commands))
+        }
+
+        // Commands created by `KeyboardNavigation`.
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(navigateFocus:))]
+        fn navigateFocus(&self, command: &UIKeyCommand) {
+            let Some(direction) = FocusDirection::from_key_command(command) else {
+                return;
+            };
+            trace!(?direction, "focus navigation");
+            send_input_message(
+                self.mtm(),
+                FocusNavigationRequested {
+                    window: self.ivars().entity,
+                    direction,
+                    instant: Instant::now(),
+                },
+            );
+        }
+
+        // Commands created by `KeyShortcutsPlugin`.
+        #[cfg(not(target_os = "tvos"))]
+        #[unsafe(method(performKeyShortcut:))]
+        fn performKeyShortcut(&self, command: &UIKeyCommand) {
+            let Some((set, index)) =
+                find_shortcut(&self.ivars().shortcut_commands.borrow(), command)
+            else {
+                return;
+            };
+            trace!(set, index, "key shortcut");
+            send_input_message(
+                self.mtm(),
+                KeyShortcutPressed {
+                    window: self.ivars().entity,
+                    set,
+                    index,
+                    instant: Instant::now(),
+                },
+            );
         }
     }
 );
 
 impl View {
-    fn new(mtm: MainThreadMarker, window: Entity, size: CGSize) -> Retained<Self> {
-        let this = Self::alloc(mtm).set_ivars(window);
+    #[cfg(not(target_os = "tvos"))]
+    fn send_edit_action(&self, action: EditAction) {
+        trace!(?action, "edit action");
+        send_message(
+            self.mtm(),
+            EditMenuActionPerformed {
+                window: self.ivars().entity,
+                action,
+            },
+        );
+    }
+
+    fn new(
+        mtm: MainThreadMarker,
+        window: Entity,
+        touch_coordinates: TouchCoordinates,
+        gestures: GestureSettings,
+        size: CGSize,
+    ) -> Retained<Self> {
+        #[cfg(not(target_os = "tvos"))]
+        let trackpad_gestures = TrackpadGestureHandler::new(gestures, mtm);
+        #[cfg(target_os = "tvos")]
+        let _ = gestures; // No trackpad gestures on tvOS
+        let this = Self::alloc(mtm).set_ivars(ViewIvars {
+            entity: window,
+            touch_coordinates,
+            passthrough_regions: RefCell::new(Vec::new()),
+            #[cfg(not(target_os = "tvos"))]
+            pointer_delegate: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            find: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            scribble_delegate: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            edge_swipes: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            hover_views: RefCell::new(Vec::new()),
+            #[cfg(not(target_os = "tvos"))]
+            edit_actions: RefCell::new(Vec::new()),
+            #[cfg(not(target_os = "tvos"))]
+            edit_menu_interaction: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            autofill_delegate: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            ime_field: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            navigation_commands: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            shortcut_commands: RefCell::new(Vec::new()),
+            snapshot_placeholder: RefCell::new(None),
+            #[cfg(not(target_os = "tvos"))]
+            _trackpad_gestures: trackpad_gestures.clone(),
+        });
         let frame = CGRect {
             origin: CGPoint::ZERO,
             size,
         };
-        unsafe { msg_send![super(this), initWithFrame: frame] }
+        let this: Retained<Self> = unsafe { msg_send![super(this), initWithFrame: frame] };
+        // Views only receive the first touch by default, but `Touches` should see every finger.
+        this.setMultipleTouchEnabled(true);
+        #[cfg(not(target_os = "tvos"))]
+        if let Some(trackpad_gestures) = trackpad_gestures {
+            trackpad_gestures.add_to(&this);
+        }
+        this
+    }
+
+    /// Emit [`TouchInput`] messages for the given touches.
+    ///
+    /// On visionOS, looking at the view and pinching is delivered as touches at the location that
+    /// was looked at, so this also covers basic spatial input (although without any ray data).
+    fn send_touches(&self, touches: &NSSet<UITouch>, phase: TouchPhase) {
+        let view: &UIView = self;
+        let bounds = view.bounds().size;
+        let size = Vec2::new(bounds.width as f32, bounds.height as f32);
+        let scale_factor = view.contentScaleFactor() as f32;
+        let mut inputs = Vec::with_capacity(touches.count());
+        let mut timestamps = Vec::with_capacity(touches.count());
+        for touch in touches.iter() {
+            let location = touch.locationInView(Some(view));
+            let position = self.ivars().touch_coordinates.from_uikit(
+                Vec2::new(location.x as f32, location.y as f32),
+                size,
+                scale_factor,
+            );
+            let force = (touch.maximumPossibleForce() > 0.0).then(|| ForceTouch::Calibrated {
+                force: touch.force() as f64,
+                max_possible_force: touch.maximumPossibleForce() as f64,
+                altitude_angle: (touch.r#type() == UITouchType::Pencil)
+                    .then(|| touch.altitudeAngle() as f64),
+            });
+            // The touch object is the same for the entire duration of the touch.
+            let id = Retained::as_ptr(&touch) as usize as u64;
+            inputs.push(TouchInput {
+                phase,
+                position,
+                window: self.ivars().entity,
+                force,
+                id,
+            });
+            timestamps.push(TouchTimestamp {
+                window: self.ivars().entity,
+                id,
+                instant: instant_from_timestamp(touch.timestamp()),
+            });
+        }
+        // Deliver all the touches at once, instead of updating the application for each of them,
+        // and such that the timestamps arrive in the same update as their touches.
+        send_input(self.mtm(), move |world| {
+            world.write_message_batch(inputs);
+            world.write_message_batch(timestamps);
+        });
+        run_touch_schedule(self.mtm());
+    }
+
+    /// Send [`TouchCancelledBySystem`] for the touches that were not cancelled by one of our own
+    /// gesture recognizers.
+    #[cfg(not(target_os = "tvos"))]
+    fn report_system_cancellations(&self, touches: &NSSet<UITouch>) {
+        let view: &UIView = self;
+        let bounds = view.bounds().size;
+        let size = Vec2::new(bounds.width as f32, bounds.height as f32);
+        let scale_factor = view.contentScaleFactor() as f32;
+        for touch in touches.iter() {
+            let cancelled_by_us = touch.gestureRecognizers().is_some_and(|recognizers| {
+                recognizers.iter().any(|recognizer| {
+                    recognizer.view().as_deref() == Some(view)
+                        && matches!(
+                            recognizer.state(),
+                            UIGestureRecognizerState::Began
+                                | UIGestureRecognizerState::Changed
+                                | UIGestureRecognizerState::Ended
+                        )
+                })
+            });
+            if cancelled_by_us {
+                continue;
+            }
+            let location = touch.locationInView(Some(view));
+            let edge = edge_near(location, bounds);
+            let position = self.ivars().touch_coordinates.from_uikit(
+                Vec2::new(location.x as f32, location.y as f32),
+                size,
+                scale_factor,
+            );
+            let id = Retained::as_ptr(&touch) as usize as u64;
+            trace!(id, ?edge, "touch cancelled by system");
+            send_message(
+                self.mtm(),
+                TouchCancelledBySystem {
+                    window: self.ivars().entity,
+                    id,
+                    position,
+                    edge,
+                },
+            );
+        }
+    }
+}
+
+/// Make Bevy's view in the given window the first responder, such that it receives key input and
+/// motion events.
+pub(crate) fn become_first_responder(window: &UIWindow) {
+    // Bevy's view controller may be presented on top of the root (e.g. a document browser).
+    let Some(mut view_controller) = window.rootViewController() else {
+        return;
+    };
+    while let Some(presented) = view_controller.presentedViewController() {
+        view_controller = presented;
+    }
+    let Some(view) = view_controller.viewIfLoaded() else {
+        return;
+    };
+    if !view.isFirstResponder() {
+        trace!("making view first responder");
+        if !view.becomeFirstResponder() {
+            warn!("failed making view first responder");
+        }
     }
 }
@@ -0,0 +1,31 @@
+use bevy_ecs::{
+    component::Component,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+};
+
+/// Run only the given schedule when the window's scene changes state, instead of updating the
+/// whole application.
+///
+/// By default, every scene lifecycle callback from UIKit (such as the scene entering the
+/// foreground or becoming active) runs a full [`App::update`](bevy_app::App::update). In
+/// multi-window applications where each window has its own set of systems, that means one
+/// window's heavy workload is run whenever any other window changes state. With this component,
+/// only the window's own schedule is run for its callbacks, and the rest of the application is
+/// updated on the next regular update.
+///
+/// Note that everything still runs on the main thread, so this does not let windows run
+/// concurrently. Add this to the entity of the [`Window`](bevy_window::Window).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WindowSchedule {
+    /// The schedule to run.
+    pub label: InternedScheduleLabel,
+}
+
+impl WindowSchedule {
+    /// Run the schedule with the given label for the window.
+    pub fn new(label: impl ScheduleLabel) -> Self {
+        Self {
+            label: label.intern(),
+        }
+    }
+}
@@ -1,30 +1,72 @@
 use std::mem;
 use std::ptr::NonNull;
 
+use bevy_camera::ClearColor;
+use bevy_color::{Color, Srgba};
 use bevy_ecs::{
-    entity::{hash_map::EntityHashMap, Entity},
+    change_detection::DetectChanges,
+    component::Component,
+    entity::{hash_map::EntityHashMap, hash_set::EntityHashSet, Entity},
     lifecycle::RemovedComponents,
-    message::Message,
-    query::{Added, Changed, Without},
-    system::{NonSend, NonSendMut, Query},
-    world::World,
+    message::{Message, MessageReader},
+    query::{Added, Changed, Has, Without},
+    resource::Resource,
+    system::{NonSendMarker, Query, Res, ResMut},
+    world::{Ref, World},
+};
+use bevy_window::{
+    MonitorSelection, PrimaryWindow, Window, WindowBackendScaleFactorChanged, WindowEvent,
+    WindowFocused, WindowLevel, WindowPosition, WindowResizeConstraints, WindowResized,
+    WindowScaleFactorChanged, WindowTheme,
 };
-use bevy_window::{PrimaryWindow, Window, WindowEvent, WindowTheme};
 use block2::RcBlock;
+use dispatch2::MainThreadBound;
 use objc2::{available, rc::Retained, runtime::AnyObject, MainThreadMarker, MainThreadOnly};
-use objc2::{define_class, msg_send, AllocAnyThread, Message as _};
-use objc2_core_foundation::{CGFloat, CGSize};
+use objc2::{define_class, msg_send, AllocAnyThread, DefinedClass as _, Message as _};
+use objc2_core_foundation::{CGFloat, CGPoint, CGRect, CGSize};
 use objc2_foundation::{ns_string, NSDictionary, NSError, NSNumber, NSString, NSUserActivity};
+#[cfg(not(target_os = "tvos"))]
+use objc2_ui_kit::UISceneSessionActivationRequest;
 use objc2_ui_kit::{
-    UIApplication, UISceneActivationRequestOptions, UISceneDestructionRequestOptions,
-    UIUserInterfaceStyle, UIWindow, UIWindowScene,
+    UIApplication, UIColor, UIEvent, UISceneActivationRequestOptions,
+    UISceneCollectionJoinBehavior, UIScreen, UIUserInterfaceStyle, UIViewController, UIWindow,
+    UIWindowLevelNormal, UIWindowScene, UIWindowSceneDestructionRequestOptions,
+    UIWindowSceneDismissalAnimation, UIWindowSceneGeometryPreferencesMac,
+    UIWindowSceneSessionRoleApplication,
 };
 use tracing::{error, trace};
 
-use crate::{view::ViewController, MainThread, USER_INFO_WINDOW_ENTITY_ID, WINDOW_ACTIVITY_TYPE};
+use crate::app::{buffer_message, catch_exception, with_world};
+use crate::companion_window::CompanionWindow;
+use crate::document_browser::DocumentBrowser;
+use crate::duplicate_window::WindowPayload;
+use crate::input::RawUIEvent;
+use crate::reduce_motion::ReduceMotion;
+use crate::scale::ScreenScale;
+use crate::settings::RawEventFilter;
+use crate::signpost::signpost_interval;
+use crate::{
+    main_thread_marker,
+    view::{View, ViewController},
+    DisconnectedWindow, UIKitSettings, USER_INFO_WINDOW_ENTITY_ID, USER_INFO_WINDOW_PAYLOAD,
+    WINDOW_ACTIVITY_TYPE,
+};
 
-pub(crate) trait WorldHelper {
+/// Extension methods for sending window messages from a [`World`].
+///
+/// Bevy delivers each window message twice: once as its own type, and once wrapped in a
+/// [`WindowEvent`], and applications may read either. Crates that integrate further platform
+/// features on top of this one can use these methods to do the same.
+pub trait WorldHelper {
+    /// Send a window message both as itself and as a [`WindowEvent`].
     fn send_window_message(&mut self, message: impl Into<WindowEvent> + Message + Clone);
+
+    /// Send several window messages of the same type both as themselves and as
+    /// [`WindowEvent`]s, preserving their order.
+    fn send_window_messages<M: Into<WindowEvent> + Message + Clone>(
+        &mut self,
+        messages: impl IntoIterator<Item = M>,
+    );
 }
 
 impl WorldHelper for World {
@@ -32,38 +74,120 @@ impl WorldHelper for World {
         self.write_message(message.clone());
         self.write_message(message.into());
     }
+
+    fn send_window_messages<M: Into<WindowEvent> + Message + Clone>(
+        &mut self,
+        messages: impl IntoIterator<Item = M>,
+    ) {
+        let messages: Vec<M> = messages.into_iter().collect();
+        self.write_message_batch(messages.iter().cloned());
+        self.write_message_batch(messages.into_iter().map(Into::<WindowEvent>::into));
+    }
 }
 
 /// The state specific to UIKit for each window.
 #[derive(Debug)]
 pub struct UIKitWindow {
     // Is unset if not using scenes
-    scene: Option<Retained<UIWindowScene>>,
+    pub(crate) scene: Option<Retained<UIWindowScene>>,
     pub(crate) uiwindow: Retained<BevyWindow>,
+    // Usually the root view controller, except when using a document browser.
+    pub(crate) view_controller: Retained<ViewController>,
+    pub(crate) document_browser: Option<DocumentBrowser>,
+    // Synchronized from the component, as it is gone by the time the window is despawned.
+    pub(crate) dismissal_animation: WindowDismissalAnimation,
+}
+
+impl UIKitWindow {
+    /// Bevy's view inside the window, if it has been loaded.
+    pub(crate) fn view(&self) -> Option<Retained<View>> {
+        self.view_controller.viewIfLoaded()?.downcast::<View>().ok()
+    }
 }
 
 /// A resource mapping Window entities to `UIKitWindow`.
 ///
 /// This is necessary because we cannot just add `UIKitWindow` as a component
-/// of the window, because it is non-send.
-#[derive(Debug, Default)]
+/// of the window, because it can only be accessed on the main thread.
+///
+/// Each `UIKitWindow` is stored in a [`MainThreadBound`], so the resource itself can be used from
+/// any system, but accessing the UIKit state requires a [`MainThreadMarker`] (which can be
+/// acquired in systems with a [`NonSendMarker`](bevy_ecs::system::NonSendMarker) parameter).
+#[derive(Resource, Debug, Default)]
 pub struct UIKitWindows {
-    entity_to_uikit: EntityHashMap<UIKitWindow>,
+    entity_to_uikit: EntityHashMap<MainThreadBound<UIKitWindow>>,
 }
 
 impl UIKitWindows {
-    pub(crate) fn get(&self, entity: Entity) -> Option<&UIKitWindow> {
-        self.entity_to_uikit.get(&entity)
+    /// Get the UIKit state of a window.
+    pub fn get(&self, entity: Entity, mtm: MainThreadMarker) -> Option<&UIKitWindow> {
+        Some(self.entity_to_uikit.get(&entity)?.get(mtm))
+    }
+
+    /// Whether the window has been registered with UIKit.
+    pub fn is_initialized(&self, entity: Entity) -> bool {
+        self.entity_to_uikit.contains_key(&entity)
+    }
+
+    /// The windows that have been registered with UIKit.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entity_to_uikit.keys().copied()
     }
 
-    pub(crate) fn is_initialized(&self, entity: Entity) -> bool {
-        self.get(entity).is_some()
+    /// The windows ordered from back to front.
+    ///
+    /// Windows are ordered by their [`WindowLevel`], and then by the order that UIKit shows them
+    /// in. Note that each scene is shown separately, so windows in different scenes are only
+    /// ordered relative to each other by their level.
+    pub fn stacking_order(&self, mtm: MainThreadMarker) -> Vec<Entity> {
+        #[allow(deprecated, reason = "only used when not using scenes")]
+        let application_windows = UIApplication::sharedApplication(mtm).windows();
+        let mut windows: Vec<_> = self
+            .iter(mtm)
+            .map(|(entity, uikit_window)| {
+                let uiwindow: &UIWindow = &uikit_window.uiwindow;
+                let siblings = match &uikit_window.scene {
+                    Some(scene) => scene.windows(),
+                    None => application_windows.clone(),
+                };
+                let index = siblings.iter().position(|sibling| &*sibling == uiwindow);
+                (uiwindow.windowLevel(), index, entity)
+            })
+            .collect();
+        windows.sort_by(|(level_a, index_a, _), (level_b, index_b, _)| {
+            level_a.total_cmp(level_b).then(index_a.cmp(index_b))
+        });
+        windows.into_iter().map(|(_, _, entity)| entity).collect()
+    }
+
+    pub(crate) fn iter(
+        &self,
+        mtm: MainThreadMarker,
+    ) -> impl Iterator<Item = (Entity, &UIKitWindow)> + '_ {
+        self.entity_to_uikit
+            .iter()
+            .map(move |(entity, uikit_window)| (*entity, uikit_window.get(mtm)))
     }
 
     pub(crate) fn insert(&mut self, entity: Entity, uikit_window: UIKitWindow) {
-        let prev = self.entity_to_uikit.insert(entity, uikit_window);
+        let mtm = uikit_window.uiwindow.mtm();
+        let prev = self
+            .entity_to_uikit
+            .insert(entity, MainThreadBound::new(uikit_window, mtm));
         debug_assert!(prev.is_none(), "tried to create existing window");
     }
+
+    pub(crate) fn get_mut(
+        &mut self,
+        entity: Entity,
+        mtm: MainThreadMarker,
+    ) -> Option<&mut UIKitWindow> {
+        Some(self.entity_to_uikit.get_mut(&entity)?.get_mut(mtm))
+    }
+
+    pub(crate) fn remove(&mut self, entity: Entity, mtm: MainThreadMarker) -> Option<UIKitWindow> {
+        Some(self.entity_to_uikit.remove(&entity)?.into_inner(mtm))
+    }
 }
 
 /// Create and set up a new `UIWindow` with state taken from the passed in `Window` and scene.
@@ -71,19 +195,45 @@ pub(crate) fn setup_window(
     scene: Option<&UIWindowScene>,
     entity: Entity,
     window: &Window,
+    settings: &UIKitSettings,
     mtm: MainThreadMarker,
 ) -> UIKitWindow {
-    let view_controller = ViewController::new(mtm, entity);
+    let _interval = signpost_interval!("setup_window");
+    let view_controller =
+        ViewController::new(mtm, entity, settings.touch_coordinates, settings.gestures);
 
-    let uiwindow = BevyWindow::alloc(mtm).set_ivars(entity);
+    let uiwindow = BevyWindow::alloc(mtm).set_ivars(BevyWindowIvars {
+        entity,
+        raw_events: settings.raw_events,
+        raw_event_filter: settings.raw_event_filter,
+    });
     let uiwindow: Retained<BevyWindow> = if let Some(scene) = scene {
         unsafe { msg_send![super(uiwindow), initWithWindowScene: scene] }
     } else {
         unsafe { msg_send![super(uiwindow), init] }
     };
-    uiwindow.setRootViewController(Some(&view_controller));
 
-    update_window(window, &uiwindow, scene);
+    // Only show the document browser in normal windows, not on external displays.
+    let is_application_role = scene.is_none_or(|scene| {
+        &*scene.session().role() == unsafe { UIWindowSceneSessionRoleApplication }
+    });
+    let document_browser = settings
+        .document_browser
+        .as_ref()
+        .filter(|_| is_application_role)
+        .map(|document_browser| {
+            DocumentBrowser::new(document_browser, entity, &view_controller, mtm)
+        });
+    let root_view_controller: &UIViewController = match &document_browser {
+        Some(document_browser) => document_browser.view_controller(),
+        None => &view_controller,
+    };
+    uiwindow.setRootViewController(Some(root_view_controller));
+
+    update_window(window, &uiwindow, &view_controller, scene, settings);
+    if let Some(scene) = scene {
+        request_initial_geometry(scene, root_view_controller, window);
+    }
 
     // Show the window
     uiwindow.makeKeyAndVisible();
@@ -91,16 +241,275 @@ pub(crate) fn setup_window(
     UIKitWindow {
         scene: scene.map(|scene| scene.retain()),
         uiwindow,
+        view_controller,
+        document_browser,
+        dismissal_animation: WindowDismissalAnimation::default(),
+    }
+}
+
+/// Request the scene's geometry to match the [`Window`]'s resolution and position.
+///
+/// This is done when setting up the window, so that the window is created with the requested
+/// geometry. The size is used as the preferred size on iPadOS with Stage Manager, Mac Catalyst
+/// and visionOS, while the position is only possible to request on Mac Catalyst. Elsewhere, the
+/// system decides the geometry of scenes.
+fn request_initial_geometry(
+    scene: &UIWindowScene,
+    root_view_controller: &UIViewController,
+    window: &Window,
+) {
+    let size = CGSize {
+        width: window.resolution.width() as CGFloat,
+        height: window.resolution.height() as CGFloat,
+    };
+    // Used by Stage Manager as the default size of newly requested scenes.
+    trace!(?size, "setting preferredContentSize");
+    root_view_controller.setPreferredContentSize(size);
+
+    let error_handler = RcBlock::new(|err: NonNull<NSError>| {
+        let err = unsafe { err.as_ref() };
+        error!(%err, "failed requesting initial window geometry");
+    });
+
+    // The size restrictions are set in `update_window`, but visionOS only respects them at
+    // creation when they're part of the geometry request.
+    #[cfg(target_os = "visionos")]
+    {
+        use objc2_ui_kit::{
+            UIProposedSceneSizeNoPreference, UIWindowSceneGeometryPreferencesVision,
+        };
+
+        // SAFETY: The static is a valid `CGFloat`.
+        let no_preference = unsafe { UIProposedSceneSizeNoPreference };
+        let constraint = |num: f32| {
+            if num.is_finite() && num > 0.0 {
+                num as CGFloat
+            } else {
+                no_preference
+            }
+        };
+        let constraints = window.resize_constraints;
+        let minimum_size = CGSize {
+            width: constraint(constraints.min_width),
+            height: constraint(constraints.min_height),
+        };
+        let maximum_size = CGSize {
+            width: constraint(constraints.max_width),
+            height: constraint(constraints.max_height),
+        };
+        trace!(
+            ?size,
+            ?minimum_size,
+            ?maximum_size,
+            "requesting initial scene size"
+        );
+        let preferences = UIWindowSceneGeometryPreferencesVision::initWithSize(
+            UIWindowSceneGeometryPreferencesVision::alloc(),
+            size,
+        );
+        preferences.setMinimumSize(minimum_size);
+        preferences.setMaximumSize(maximum_size);
+        scene.requestGeometryUpdateWithPreferences_errorHandler(&preferences, Some(&error_handler));
+    }
+
+    if !cfg!(target_abi = "macabi") || !available!(ios = 16.0, ..) {
+        return;
+    }
+
+    let screen = scene.screen();
+    let screen_size = screen.bounds().size;
+    let origin = match window.position {
+        WindowPosition::Automatic => scene.effectiveGeometry().systemFrame().origin,
+        WindowPosition::Centered(monitor) => {
+            if monitor != MonitorSelection::Current {
+                // UIKit does not allow choosing the screen that scenes are shown on.
+                trace!(
+                    ?monitor,
+                    "cannot select monitor, centering on current screen"
+                );
+            }
+            CGPoint {
+                x: (screen_size.width - size.width) / 2.0,
+                y: (screen_size.height - size.height) / 2.0,
+            }
+        }
+        WindowPosition::At(position) => CGPoint {
+            x: position.x as CGFloat / screen.scale(),
+            y: position.y as CGFloat / screen.scale(),
+        },
+    };
+
+    let frame = CGRect { origin, size };
+    trace!(?frame, "requesting initial scene geometry");
+    let preferences = UIWindowSceneGeometryPreferencesMac::initWithSystemFrame(
+        UIWindowSceneGeometryPreferencesMac::alloc(),
+        frame,
+    );
+    scene.requestGeometryUpdateWithPreferences_errorHandler(&preferences, Some(&error_handler));
+}
+
+/// Request the scene to be resized if it's outside of the window's resize constraints.
+///
+/// `UIWindowScene.sizeRestrictions` only limit further resizing, so a scene that was already larger
+/// or smaller than newly set constraints keeps its size. Only Mac Catalyst and visionOS allow
+/// requesting a size.
+fn fit_to_resize_constraints(scene: &UIWindowScene, constraints: &WindowResizeConstraints) {
+    #[cfg(any(target_abi = "macabi", target_os = "visionos"))]
+    {
+        #[cfg(target_os = "visionos")]
+        use objc2_ui_kit::UIWindowSceneGeometryPreferencesVision;
+
+        if !available!(ios = 16.0, visionos = 1.0) {
+            return;
+        }
+        let frame = scene.effectiveGeometry().systemFrame();
+        let size = CGSize {
+            width: (frame.size.width as f32)
+                .max(constraints.min_width)
+                .min(constraints.max_width) as CGFloat,
+            height: (frame.size.height as f32)
+                .max(constraints.min_height)
+                .min(constraints.max_height) as CGFloat,
+        };
+        if size == frame.size {
+            return;
+        }
+
+        let error_handler = RcBlock::new(|err: NonNull<NSError>| {
+            let err = unsafe { err.as_ref() };
+            error!(%err, "failed resizing window to its resize constraints");
+        });
+        trace!(?size, "requesting scene size within resize constraints");
+        #[cfg(target_os = "visionos")]
+        let preferences = UIWindowSceneGeometryPreferencesVision::initWithSize(
+            UIWindowSceneGeometryPreferencesVision::alloc(),
+            size,
+        );
+        #[cfg(not(target_os = "visionos"))]
+        let preferences = UIWindowSceneGeometryPreferencesMac::initWithSystemFrame(
+            UIWindowSceneGeometryPreferencesMac::alloc(),
+            CGRect {
+                origin: frame.origin,
+                size,
+            },
+        );
+        scene.requestGeometryUpdateWithPreferences_errorHandler(&preferences, Some(&error_handler));
+    }
+    #[cfg(not(any(target_abi = "macabi", target_os = "visionos")))]
+    let _ = (scene, constraints);
+}
+
+/// Synchronize the [`Window`]'s resolution with the size reported by UIKit and the scale factor of
+/// the screen (as selected by [`UIKitSettings::scale_mode`]), and emit the relevant resize messages if anything changed.
+pub(crate) fn handle_resize(world: &mut World, entity: Entity, size: CGSize, screen: &UIScreen) {
+    let scale_factor = world
+        .resource::<UIKitSettings>()
+        .scale_mode
+        .scale_factor(screen);
+    if let Some(view) = world
+        .resource::<UIKitWindows>()
+        .get(entity, screen.mtm())
+        .and_then(|uikit_window| uikit_window.view())
+    {
+        if view.contentScaleFactor() != scale_factor {
+            trace!(?scale_factor, "setting UIView.contentScaleFactor");
+            view.setContentScaleFactor(scale_factor);
+        }
+    }
+
+    let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+        // The window may have been despawned in the meantime.
+        return;
+    };
+    if entity_mut.contains::<DisconnectedWindow>() {
+        // The view may still be laid out while its scene is torn down.
+        return;
+    }
+    let screen_scale = ScreenScale::new(screen);
+    if entity_mut.get::<ScreenScale>() != Some(&screen_scale) {
+        trace!(?entity, ?screen_scale, "updating ScreenScale");
+        entity_mut.insert(screen_scale);
+    }
+    let Some(mut window) = entity_mut.get_mut::<Window>() else {
+        return;
+    };
+
+    let scale_factor = scale_factor as f32;
+    let scale_factor_changed = window.resolution.scale_factor() != scale_factor;
+    if scale_factor_changed {
+        trace!(?entity, ?scale_factor, "scale factor changed");
+        window.resolution.set_scale_factor(scale_factor);
+    }
+
+    // Always report the size that UIKit gave the view, even if it's outside of the resize
+    // constraints, which are instead enforced by `fit_to_resize_constraints`.
+    let physical_width = (size.width as f32 * scale_factor).round() as u32;
+    let physical_height = (size.height as f32 * scale_factor).round() as u32;
+    let size_changed = window.resolution.physical_width() != physical_width
+        || window.resolution.physical_height() != physical_height;
+    if size_changed {
+        trace!(?entity, ?size, "window resized");
+        window
+            .resolution
+            .set_physical_resolution(physical_width, physical_height);
+    }
+
+    if scale_factor_changed {
+        let scale_factor = scale_factor as f64;
+        world.send_window_message(WindowBackendScaleFactorChanged {
+            window: entity,
+            scale_factor,
+        });
+        world.send_window_message(WindowScaleFactorChanged {
+            window: entity,
+            scale_factor,
+        });
+    }
+    if scale_factor_changed || size_changed {
+        // Coalesced, since UIKit reports sizes several times per frame while resizing.
+        world
+            .resource_mut::<PendingWindowResizes>()
+            .0
+            .insert(entity);
+    }
+}
+
+/// Windows whose size changed since the last update, see [`flush_window_resizes`].
+#[derive(Resource, Debug, Default)]
+pub(crate) struct PendingWindowResizes(EntityHashSet);
+
+/// Emit a single [`WindowResized`] for each window that was resized since the last update, with
+/// its final size.
+pub(crate) fn flush_window_resizes(world: &mut World) {
+    let pending = mem::take(&mut world.resource_mut::<PendingWindowResizes>().0);
+    for entity in pending {
+        let Some(window) = world.get::<Window>(entity) else {
+            continue;
+        };
+        let (width, height) = (window.resolution.width(), window.resolution.height());
+        world.send_window_message(WindowResized {
+            window: entity,
+            width,
+            height,
+        });
     }
 }
 
 /// Request new windows to be created for each entity with a newly-added [`Window`] component.
 pub fn create_windows(
-    mut created_windows: Query<Entity, (Added<Window>, Without<PrimaryWindow>)>,
-    uikit_windows: NonSend<UIKitWindows>,
-    mtm: NonSend<MainThread>,
+    mut created_windows: Query<
+        (Entity, Option<&WindowPayload>, Has<CompanionWindow>),
+        (
+            Added<Window>,
+            Without<PrimaryWindow>,
+            Without<DisconnectedWindow>,
+        ),
+    >,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
 ) {
-    for entity in &mut created_windows {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, payload, is_companion) in &mut created_windows {
         if uikit_windows.is_initialized(entity) {
             // Don't request creation on user-created windows.
             continue;
@@ -109,76 +518,179 @@ pub fn create_windows(
         // Check for window scene support.
         if available!(ios = 13.0, tvos = 13.0, visionos = 1.0, ..) {
             trace!("requesting window creation");
-            let application = UIApplication::sharedApplication(mtm.0);
-            let options = UISceneActivationRequestOptions::new(mtm.0);
+            let application = UIApplication::sharedApplication(mtm);
+            let options = UISceneActivationRequestOptions::new(mtm);
             let user_activity = NSUserActivity::initWithActivityType(
                 NSUserActivity::alloc(),
                 ns_string!(WINDOW_ACTIVITY_TYPE),
             );
-            let dict = NSDictionary::<NSString, AnyObject>::from_slices(
-                &[ns_string!(USER_INFO_WINDOW_ENTITY_ID)],
-                &[NSNumber::new_u64(entity.to_bits()).as_ref()],
-            );
+            let entity_id = NSNumber::new_u64(entity.to_bits());
+            let payload = payload.map(|payload| payload.0.to_object());
+            let mut keys = vec![ns_string!(USER_INFO_WINDOW_ENTITY_ID)];
+            let mut values: Vec<&AnyObject> = vec![entity_id.as_ref()];
+            if let Some(payload) = &payload {
+                keys.push(ns_string!(USER_INFO_WINDOW_PAYLOAD));
+                values.push(payload);
+            }
+            let dict = NSDictionary::<NSString, AnyObject>::from_slices(&keys, &values);
             unsafe { user_activity.addUserInfoEntriesFromDictionary(dict.cast_unchecked()) };
-            // TODO: Set `options.collectionJoinBehavior` on Mac Catalyst?
+            // TODO: Set `options.collectionJoinBehavior` on Mac Catalyst for other windows?
+            if is_companion && cfg!(target_abi = "macabi") && available!(ios = 15.0, ..) {
+                options.setCollectionJoinBehavior(UISceneCollectionJoinBehavior::Disallowed);
+            }
             let error_handler = RcBlock::new(|err: NonNull<NSError>| {
                 let err = unsafe { err.as_ref() };
                 error!(%err, "failed creating window, this is not possible on single-window iOS");
             });
+            // Neither the activation request nor its options can carry geometry preferences, so
+            // the requested size, position and screen are applied in `request_initial_geometry`
+            // once the scene connects.
+            catch_exception("failed requesting window creation", || {
+                #[cfg(not(target_os = "tvos"))]
+                if available!(ios = 17.0, visionos = 1.0, ..) {
+                    let request = UISceneSessionActivationRequest::request(mtm);
+                    request.setUserActivity(Some(&user_activity));
+                    request.setOptions(Some(&options));
+                    application.activateSceneSessionForRequest_errorHandler(
+                        &request,
+                        Some(&error_handler),
+                    );
+                    return;
+                }
+                #[allow(deprecated, reason = "the replacement API requires newer OS versions")]
+                application.requestSceneSessionActivation_userActivity_options_errorHandler(
+                    None, // Create a new scene
+                    Some(&user_activity),
+                    Some(&options),
+                    Some(&error_handler),
+                );
+            });
+        } else {
+            error!("failed creating window, this is not possible on this version of iOS");
+        }
+    }
+}
+
+/// Bring a window to the front of other windows at the same [`WindowLevel`], and make it the key
+/// window.
+///
+/// Unlike [`ActivateWindow`], this does not activate the window's scene, so it is mostly useful
+/// for ordering windows that share a scene, or when not using scenes.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BringWindowToFront {
+    /// The window to bring to the front.
+    pub window: Entity,
+}
+
+/// Order windows as requested with [`BringWindowToFront`].
+pub fn bring_windows_to_front(
+    mut requests: MessageReader<BringWindowToFront>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for BringWindowToFront { window } in requests.read().copied() {
+        let Some(uikit_window) = uikit_windows.get(window, mtm) else {
+            continue;
+        };
+        trace!(?window, "bringing window to front");
+        uikit_window.uiwindow.makeKeyAndVisible();
+    }
+}
+
+/// Bring an existing window to the front, and make it active.
+///
+/// This requires multiple window support, so it does nothing on single-window iOS.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActivateWindow {
+    /// The window to activate.
+    pub window: Entity,
+}
+
+/// Request activation of the scene sessions of windows requested with [`ActivateWindow`].
+pub fn activate_windows(
+    mut requests: MessageReader<ActivateWindow>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for ActivateWindow { window } in requests.read().copied() {
+        let Some(uikit_window) = uikit_windows.get(window, mtm) else {
+            continue;
+        };
+        let Some(scene) = &uikit_window.scene else {
+            error!("tried to activate window, this is not possible without scenes");
+            continue;
+        };
+        trace!(?window, "requesting window activation");
+        let application = UIApplication::sharedApplication(mtm);
+        let options = UISceneActivationRequestOptions::new(mtm);
+        let error_handler = RcBlock::new(|err: NonNull<NSError>| {
+            let err = unsafe { err.as_ref() };
+            error!(%err, "failed activating window, this is not possible on single-window iOS");
+        });
+        catch_exception("failed requesting window activation", || {
             #[allow(deprecated, reason = "the replacement API requires newer OS versions")]
             application.requestSceneSessionActivation_userActivity_options_errorHandler(
-                None, // Create a new scene
-                Some(&user_activity),
+                Some(&scene.session()),
+                None,
                 Some(&options),
                 Some(&error_handler),
             );
-        } else {
-            error!("failed creating window, this is not possible on this version of iOS");
-        }
+        });
     }
 }
 
-/// Propagate changes by the user in [`Window`] entities to UIKit.
+/// Propagate changes by the user in [`Window`] entities (and in [`UIKitSettings`] that affect all
+/// windows) to UIKit.
 pub fn changed_windows(
-    changed_windows: Query<(Entity, &Window), Changed<Window>>,
-    uikit_windows: NonSend<UIKitWindows>,
+    windows: Query<(Entity, Ref<Window>), Without<DisconnectedWindow>>,
+    uikit_windows: Res<UIKitWindows>,
+    settings: Res<UIKitSettings>,
+    main_thread: NonSendMarker,
 ) {
-    for (entity, window) in &changed_windows {
+    let mtm = main_thread_marker(main_thread);
+    for (entity, window) in &windows {
+        if !window.is_changed() && !settings.is_changed() {
+            continue;
+        }
         trace!(?entity, "detected changes to Window");
-        let Some(uikit_window) = uikit_windows.get(entity) else {
+        let Some(uikit_window) = uikit_windows.get(entity, mtm) else {
             // Not (yet) registered with UIKit, should be when the scene connects.
             continue;
         };
 
         update_window(
-            window,
+            &window,
             &uikit_window.uiwindow,
+            &uikit_window.view_controller,
             uikit_window.scene.as_deref(),
+            &settings,
         );
     }
 }
 
 fn update_window(
     Window {
-        canvas: _,                                           // Web-specific
-        clip_children: _,                                    // Windows-specific
-        composite_alpha_mode: _,                             // Handled by `bevy_render`
-        decorations: _,                                      // TODO (usable on Mac Catalyst)
-        desired_maximum_frame_latency: _,                    // Handled by `bevy_render`
-        enabled_buttons,                                     // Handled
-        fit_canvas_to_parent: _,                             // Web-specific
-        focused: _,                        // TODO: State controlled by us (`keyWindow`)?
-        fullsize_content_view: _,          // macOS-specific
-        has_shadow: _,                     // macOS-specific
-        ime_enabled: _,                    // TODO
-        ime_position: _,                   // TODO
+        canvas: _,                                        // Web-specific
+        clip_children: _,                                 // Windows-specific
+        composite_alpha_mode: _,                          // Handled by `bevy_render`
+        decorations: _,                                   // TODO (usable on Mac Catalyst)
+        desired_maximum_frame_latency: _,                 // Handled by `bevy_render`
+        enabled_buttons,                                  // Handled
+        fit_canvas_to_parent: _,                          // Web-specific
+        focused: _,                                       // State controlled by us (`keyWindow`)
+        fullsize_content_view: _,                         // macOS-specific
+        has_shadow: _,                                    // macOS-specific
+        ime_enabled: _,                                   // Handled in `update_ime`
+        ime_position: _,                                  // Handled in `update_ime`
         internal: _,                       // TODO: Perhaps needs more exposed internals?
         mode: _,                           // TODO
         movable_by_window_background: _,   // macOS-specific
         name: _,                           // Not relevant on iOS
         position,                          // Handled
         prefers_home_indicator_hidden: _,  // TODO
-        prefers_status_bar_hidden: _,      // TODO
+        prefers_status_bar_hidden: _,      // Handled in `update_status_bars`
         present_mode: _,                   // Handled by `bevy_render`
         prevent_default_event_handling: _, // Web-specific
         recognize_doubletap_gesture: _,    // TODO
@@ -196,12 +708,14 @@ fn update_window(
         titlebar_transparent: _,           // `configureWithTransparentBackground`?
         transparent: _,                    // Unsupported
         visible: _,                        // Unsupported
-        window_level: _,                   // Unsupported
+        window_level,                      // Handled
         window_theme,                      // Handled
-        preferred_screen_edges_deferring_system_gestures: _, // TODO
+        preferred_screen_edges_deferring_system_gestures, // Handled
     }: &Window,
     window: &UIWindow,
+    view_controller: &ViewController,
     scene: Option<&UIWindowScene>,
+    settings: &UIKitSettings,
 ) {
     // Avoid infinity, which NSLayoutConstraint doesn't like.
     fn avoid_inf(num: f32) -> CGFloat {
@@ -246,6 +760,8 @@ fn update_window(
             }
         }
 
+        fit_to_resize_constraints(scene, resize_constraints);
+
         if available!(ios = 16.0, tvos = 16.0, visionos = 1.0, ..) {
             if let Some(behaviours) = scene.windowingBehaviors() {
                 let val = enabled_buttons.minimize;
@@ -282,10 +798,31 @@ fn update_window(
         }
     }
 
+    let normal = unsafe { UIWindowLevelNormal };
+    let level = match window_level {
+        WindowLevel::AlwaysOnBottom => normal - 1.0,
+        WindowLevel::Normal => normal,
+        WindowLevel::AlwaysOnTop => normal + 1.0,
+    };
+    if window.windowLevel() != level {
+        trace!(?level, "setting UIWindow.windowLevel");
+        window.setWindowLevel(level);
+    }
+
+    #[cfg(not(target_os = "tvos"))]
+    view_controller.set_deferred_screen_edges(*preferred_screen_edges_deferring_system_gestures);
+    #[cfg(target_os = "tvos")]
+    let _ = (
+        view_controller,
+        preferred_screen_edges_deferring_system_gestures,
+    );
+
     // NOTE: UIUserInterfaceStyle is available on iOS 12, it's just the override there isn't,
     // so there might be a way to select this even there? But we won't bother.
     if available!(ios = 13.0, tvos = 13.0, visionos = 1.0, ..) {
-        let style = match window_theme {
+        // The window's theme takes precedence over the application-wide one. Presented view
+        // controllers (including system ones) inherit the style from the window.
+        let style = match window_theme.or(settings.window_theme) {
             Some(WindowTheme::Light) => UIUserInterfaceStyle::Light,
             Some(WindowTheme::Dark) => UIUserInterfaceStyle::Dark,
             None => UIUserInterfaceStyle::Unspecified,
@@ -297,14 +834,127 @@ fn update_window(
     }
 }
 
+/// The background color of the native window and view, overriding [`ClearColor`] for this
+/// window.
+///
+/// Useful for giving windows different backing colors during transitions in multi-window
+/// applications. Add this to the entity of the [`Window`].
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct NativeBackgroundColor(pub Color);
+
+/// Use Bevy's [`ClearColor`], or [`NativeBackgroundColor`] if set, as the background of the native
+/// window and view.
+///
+/// This avoids flashing black or white in the brief moments where Bevy's rendering isn't shown,
+/// such as during launch, rotation and in scene snapshots.
+pub fn sync_background_color(
+    clear_color: Option<Res<ClearColor>>,
+    native_colors: Query<Ref<NativeBackgroundColor>, Without<DisconnectedWindow>>,
+    mut removed: RemovedComponents<NativeBackgroundColor>,
+    uikit_windows: Res<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    let clear_color_changed = clear_color.as_ref().is_some_and(|color| color.is_changed());
+    let removed: Vec<Entity> = removed.read().collect();
+
+    for (entity, uikit_window) in uikit_windows.iter(mtm) {
+        // Also update when windows were added.
+        let color = match native_colors.get(entity) {
+            Ok(native) if native.is_changed() || uikit_windows.is_changed() => Some(native.0),
+            Ok(_) => continue,
+            Err(_)
+                if (clear_color.is_some() && uikit_windows.is_changed())
+                    || clear_color_changed
+                    || removed.contains(&entity) =>
+            {
+                clear_color.as_ref().map(|color| color.0)
+            }
+            Err(_) => continue,
+        };
+
+        let color = color.map(|color| {
+            let Srgba {
+                red,
+                green,
+                blue,
+                alpha,
+            } = color.to_srgba();
+            UIColor::colorWithRed_green_blue_alpha(
+                red as CGFloat,
+                green as CGFloat,
+                blue as CGFloat,
+                alpha as CGFloat,
+            )
+        });
+        trace!(?entity, ?color, "setting background color");
+
+        uikit_window.uiwindow.setBackgroundColor(color.as_deref());
+        if let Some(view) = uikit_window.view() {
+            view.setBackgroundColor(color.as_deref());
+        }
+    }
+}
+
+/// The animation used when the window is closed by despawning it.
+///
+/// Only has an effect on iPadOS and Mac Catalyst, and falls back to the standard animation while
+/// [`ReduceMotion`] is enabled.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WindowDismissalAnimation {
+    /// The standard animation for closing a window.
+    #[default]
+    Standard,
+    /// An animation indicating that the window's content was committed, e.g. a message was sent.
+    Commit,
+    /// An animation indicating that the window's content was discarded, e.g. a draft was deleted.
+    Decline,
+}
+
+impl WindowDismissalAnimation {
+    fn to_uikit(self) -> UIWindowSceneDismissalAnimation {
+        match self {
+            Self::Standard => UIWindowSceneDismissalAnimation::Standard,
+            Self::Commit => UIWindowSceneDismissalAnimation::Commit,
+            Self::Decline => UIWindowSceneDismissalAnimation::Decline,
+        }
+    }
+}
+
+/// Store changes to [`WindowDismissalAnimation`], such that they are available when the window is
+/// despawned.
+pub fn update_dismissal_animations(
+    changed: Query<(Entity, &WindowDismissalAnimation), Changed<WindowDismissalAnimation>>,
+    mut removed: RemovedComponents<WindowDismissalAnimation>,
+    mut uikit_windows: ResMut<UIKitWindows>,
+    main_thread: NonSendMarker,
+) {
+    let mtm = main_thread_marker(main_thread);
+    for entity in removed.read() {
+        if let Some(uikit_window) = uikit_windows.get_mut(entity, mtm) {
+            uikit_window.dismissal_animation = WindowDismissalAnimation::default();
+        }
+    }
+    for (entity, dismissal_animation) in &changed {
+        if let Some(uikit_window) = uikit_windows.get_mut(entity, mtm) {
+            uikit_window.dismissal_animation = *dismissal_animation;
+        }
+    }
+}
+
 /// Remove windows from the scene.
+///
+/// Uses the standard dismissal animation while [`ReduceMotion`] is enabled.
 pub fn despawn_windows(
     mut removed_windows: RemovedComponents<Window>,
-    mut uikit_windows: NonSendMut<UIKitWindows>,
+    mut uikit_windows: ResMut<UIKitWindows>,
+    reduce_motion: Res<ReduceMotion>,
+    main_thread: NonSendMarker,
 ) {
+    let mtm = main_thread_marker(main_thread);
     for entity in removed_windows.read() {
         trace!(?entity, "detected removed Window");
-        let Some(uikit_window) = uikit_windows.entity_to_uikit.remove(&entity) else {
+        let Some(uikit_window) = uikit_windows.remove(entity, mtm) else {
             // Don't request removal on user-removed windows.
             continue;
         };
@@ -312,26 +962,99 @@ pub fn despawn_windows(
         // Request removal from UIKit too.
         if let Some(scene) = uikit_window.scene {
             let app = UIApplication::sharedApplication(scene.mtm());
-            let options = UISceneDestructionRequestOptions::new(scene.mtm());
+            let options = UIWindowSceneDestructionRequestOptions::new(scene.mtm());
+            let mut dismissal_animation = uikit_window.dismissal_animation;
+            if reduce_motion.enabled {
+                // The commit and decline animations move the window across the screen.
+                dismissal_animation = WindowDismissalAnimation::Standard;
+            }
+            options.setWindowDismissalAnimation(dismissal_animation.to_uikit());
             let error_handler = RcBlock::new(|err: NonNull<NSError>| {
                 let err = unsafe { err.as_ref() };
                 error!(%err, "failed removing window, this is not possible on single-window iOS");
             });
-            app.requestSceneSessionDestruction_options_errorHandler(
-                &scene.session(),
-                Some(&options),
-                Some(&error_handler),
-            );
+            catch_exception("failed requesting window removal", || {
+                app.requestSceneSessionDestruction_options_errorHandler(
+                    &scene.session(),
+                    Some(&options),
+                    Some(&error_handler),
+                );
+            });
         } else {
             error!("tried to remove main window, this is not possible on single-window iOS");
         }
     }
 }
 
+#[derive(Debug)]
+pub(crate) struct BevyWindowIvars {
+    entity: Entity,
+    raw_events: bool,
+    raw_event_filter: Option<RawEventFilter>,
+}
+
 define_class!(
     #[unsafe(super(UIWindow))]
     #[name = "BevyWindow"]
     #[derive(Debug, PartialEq, Eq, Hash)]
-    #[ivars = Entity]
+    #[ivars = BevyWindowIvars]
     pub(crate) struct BevyWindow;
+
+    /// Overridden UIWindow methods.
+    impl BevyWindow {
+        #[unsafe(method(sendEvent:))]
+        fn sendEvent(&self, event: &UIEvent) {
+            let ivars = self.ivars();
+
+            if ivars.raw_events {
+                // Every touch, press and motion passes through here, so updating the application
+                // for each event would be far too expensive.
+                buffer_message(self.mtm(), RawUIEvent::new(ivars.entity, event));
+            }
+
+            if let Some(filter) = ivars.raw_event_filter {
+                if !filter(ivars.entity, event) {
+                    trace!(?event, "raw event filter discarded event");
+                    return;
+                }
+            }
+
+            unsafe { msg_send![super(self), sendEvent: event] }
+        }
+
+        // Called alongside `UIWindowDidBecomeKeyNotification`.
+        #[unsafe(method(becomeKeyWindow))]
+        fn becomeKeyWindow(&self) {
+            trace!("becomeKeyWindow");
+            unsafe { msg_send![super(self), becomeKeyWindow] }
+            self.send_focused(true);
+        }
+
+        // Called alongside `UIWindowDidResignKeyNotification`.
+        #[unsafe(method(resignKeyWindow))]
+        fn resignKeyWindow(&self) {
+            trace!("resignKeyWindow");
+            unsafe { msg_send![super(self), resignKeyWindow] }
+            self.send_focused(false);
+        }
+    }
 );
+
+impl BevyWindow {
+    /// Track the key window as the focused window.
+    ///
+    /// In multi-window applications, only one window is key at a time, even if Bevy's view is the
+    /// first responder in several of them.
+    fn send_focused(&self, focused: bool) {
+        let window = self.ivars().entity;
+        with_world(self.mtm(), move |world| {
+            let Some(mut window_component) = world.get_mut::<Window>(window) else {
+                return;
+            };
+            if window_component.focused != focused {
+                window_component.focused = focused;
+            }
+            world.send_window_message(WindowFocused { window, focused });
+        });
+    }
+}